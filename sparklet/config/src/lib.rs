@@ -33,9 +33,27 @@ impl<const ENCODER_AMOUNT: usize> Default for Page<ENCODER_AMOUNT> {
 }
 
 /* Schema:
- *   First page: Attack, Sustain, Decay/Release
- *   Second page: Oscilator, unused, unused
- *   Third page and fourth page: Equalizer bank, from lowest to highest
+ *   First page: Attack, Sustain, Decay
+ *   Second page: Oscilator, second oscilator level, second oscilator detune
+ *   Third page: Noise color, noise level, unused
+ *   Fourth page: Second oscilator and mode, semitone offset, octave offset
+ *   Fifth to eighth page: FM operator ratio, level, decay/release
+ *   Ninth page: Pulse width, sub oscilator, sub oscilator level
+ *   Tenth page: Phase mode, analog drift depth, unused
+ *   Eleventh page: String excitation, string decay, string damping
+ *   Twelfth page: Phase distortion shape, phase distortion amount, phase distortion decay/release
+ *   Thirteenth page: Shaper type, shaper drive, shaper symmetry
+ *   Fourteenth page: Release, delay, hold
+ *   Fifteenth page: Attack curve, decay curve, release curve
+ *   Sixteenth page: LFO shape and target, LFO rate, LFO depth
+ *   Seventeenth page: LFO phase and key sync, LFO delay, LFO fade in
+ *   Eighteenth page to twenty-first page: Modulation source, destination and depth, one slot each
+ *   Twenty-second page: Modulation envelope attack, sustain, decay
+ *   Twenty-third page: Modulation envelope release, unused, unused
+ *   Twenty-fourth page: Velocity curve, velocity sensitivity, fixed velocity
+ *   Twenty-fifth page: Attack key tracking, decay/release key tracking, amplitude key tracking
+ *   Twenty-sixth page: Envelope loop count, modulation envelope loop count, unused
+ *   Twenty-seventh page and twenty-eighth page: Equalizer bank, from lowest to highest
  */
 #[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Config<const PAGE_AMOUNT: usize, const ENCODER_AMOUNT: usize> {
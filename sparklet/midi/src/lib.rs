@@ -1,38 +1,102 @@
 #![cfg_attr(not(test), no_std)]
 
-use defmt::{Format, info};
+use amity::triple::{TripleBuffer, TripleBufferProducer};
+use defmt::{Format, info, trace};
 use embassy_sync::{blocking_mutex::raw::RawMutex, channel::Sender};
-use midly::{MidiMessage, live::LiveEvent, stream::MidiStream};
+use midly::{
+    MidiMessage,
+    live::{LiveEvent, SystemCommon},
+    stream::MidiStream,
+};
 
 pub use midly::num::u7;
 
+pub mod tuning;
+
+pub use tuning::{NoteTuning, TuningTable};
+
+/// Controller number of the mod wheel, the only controller the synth engine follows
+pub const MOD_WHEEL_CONTROLLER: u8 = 1;
+
 #[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MidiEvent {
-    NoteOff { key: u8, vel: u8 },
-    NoteOn { key: u8, vel: u8 },
+    NoteOff {
+        key: u8,
+        vel: u8,
+    },
+    NoteOn {
+        key: u8,
+        vel: u8,
+    },
+    /// Pressure on a single key
+    Aftertouch {
+        key: u8,
+        vel: u8,
+    },
+    Controller {
+        controller: u8,
+        value: u8,
+    },
+    /// Pressure on the whole keyboard
+    ChannelAftertouch {
+        vel: u8,
+    },
+    /// From -8192 to 8191, with 0 being no bend
+    PitchBend {
+        bend: i16,
+    },
 }
 
-pub struct MidiListener<'ch, M: RawMutex, const N: usize> {
+pub struct MidiListener<'ch, 'buf, M: RawMutex, const N: usize> {
     sender: Sender<'ch, M, MidiEvent, N>,
     midi_stream: MidiStream<MidiListenerBuffer>,
+    tuning_producer: TripleBufferProducer<TuningTable, &'buf TripleBuffer<TuningTable>>,
+    tuning: TuningTable,
 }
 
+// Big enough to accumulate a whole MTS bulk tuning dump.
+// Longer SysEx messages overflow it and are dropped by the stream.
 midly::stack_buffer! {
-    struct MidiListenerBuffer([u8; 4]);
+    struct MidiListenerBuffer([u8; tuning::BULK_DUMP_LENGTH]);
 }
 
-impl<'ch, M: RawMutex, const N: usize> MidiListener<'ch, M, N> {
-    pub fn new(sender: Sender<'ch, M, MidiEvent, N>) -> Self {
+impl<'ch, 'buf, M: RawMutex, const N: usize> MidiListener<'ch, 'buf, M, N> {
+    pub fn new(
+        sender: Sender<'ch, M, MidiEvent, N>,
+        tuning_producer: TripleBufferProducer<TuningTable, &'buf TripleBuffer<TuningTable>>,
+    ) -> Self {
         let midi_stream = MidiStream::with_buffer(MidiListenerBuffer::new());
 
         MidiListener {
             sender,
             midi_stream,
+            tuning_producer,
+            tuning: TuningTable::default(),
         }
     }
 
-    fn handle_event(sender: &Sender<'ch, M, MidiEvent, N>, event: LiveEvent<'_>) {
-        if let LiveEvent::Midi {
+    fn handle_sysex(
+        tuning_producer: &mut TripleBufferProducer<TuningTable, &'buf TripleBuffer<TuningTable>>,
+        tuning: &mut TuningTable,
+        data: &[u7],
+    ) {
+        if tuning.apply_sysex(data) {
+            info!("Tuning table updated");
+
+            *tuning_producer.get_mut() = *tuning;
+            tuning_producer.publish();
+        }
+    }
+
+    fn handle_event(
+        sender: &Sender<'ch, M, MidiEvent, N>,
+        tuning_producer: &mut TripleBufferProducer<TuningTable, &'buf TripleBuffer<TuningTable>>,
+        tuning: &mut TuningTable,
+        event: LiveEvent<'_>,
+    ) {
+        if let LiveEvent::Common(SystemCommon::SysEx(data)) = event {
+            Self::handle_sysex(tuning_producer, tuning, data);
+        } else if let LiveEvent::Midi {
             channel: _,
             message,
         } = event
@@ -46,22 +110,51 @@ impl<'ch, M: RawMutex, const N: usize> MidiListener<'ch, M, N> {
                     key: key.into(),
                     vel: vel.into(),
                 },
+                MidiMessage::Aftertouch { key, vel } => MidiEvent::Aftertouch {
+                    key: key.into(),
+                    vel: vel.into(),
+                },
+                MidiMessage::Controller { controller, value }
+                    if controller.as_int() == MOD_WHEEL_CONTROLLER =>
+                {
+                    MidiEvent::Controller {
+                        controller: controller.into(),
+                        value: value.into(),
+                    }
+                }
+                MidiMessage::ChannelAftertouch { vel } => {
+                    MidiEvent::ChannelAftertouch { vel: vel.into() }
+                }
+                MidiMessage::PitchBend { bend } => MidiEvent::PitchBend {
+                    bend: bend.as_int(),
+                },
                 _ => return,
             };
 
-            info!("Adding event: {:#?}", event_to_add);
+            // Pressure and pitch bend come in streams, which would flood the log
+            match event_to_add {
+                MidiEvent::NoteOff { .. } | MidiEvent::NoteOn { .. } => {
+                    info!("Adding event: {:#?}", event_to_add)
+                }
+                _ => trace!("Adding event: {:#?}", event_to_add),
+            }
 
             // Only fails if full. if full, the message is discarded.
-            // In practice the channel has length 16 and is processed
-            // once a millisecond, which means 16000 events would need
-            // to be sent per second to overflow it.
+            // The channel is drained at the start of every window the synth
+            // engine renders, so it only has to hold what arrives in between.
             sender.try_send(event_to_add).ok();
         }
     }
 
     pub fn process_bytes(&mut self, bytes: &[u8]) {
-        self.midi_stream
-            .feed(bytes, |event| Self::handle_event(&self.sender, event));
+        self.midi_stream.feed(bytes, |event| {
+            Self::handle_event(
+                &self.sender,
+                &mut self.tuning_producer,
+                &mut self.tuning,
+                event,
+            )
+        });
     }
 }
 
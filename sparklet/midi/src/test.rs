@@ -1,18 +1,30 @@
+use amity::triple::TripleBuffer;
 use embassy_sync::{blocking_mutex::raw::NoopRawMutex, channel::Channel};
 use midly::{
     MidiMessage,
     live::{LiveEvent, SystemCommon},
+    num::u7,
 };
 use pretty_assertions::assert_eq;
 
-use crate::{MidiEvent, MidiListener};
+use crate::{MidiEvent, MidiListener, NoteTuning, TuningTable, tuning::BULK_DUMP_LENGTH};
 
 macro_rules! setup {
     ($receiver:ident, $midi_listener:ident) => {
+        setup!($receiver, $midi_listener, _tuning_consumer);
+    };
+    ($receiver:ident, $midi_listener:ident, $tuning_consumer:ident) => {
         let channel = Channel::<NoopRawMutex, MidiEvent, 4>::new();
         let sender = channel.sender();
         let $receiver = channel.receiver();
-        let mut $midi_listener = MidiListener::new(sender);
+        let mut tuning_buffer = TripleBuffer::new(
+            TuningTable::default(),
+            TuningTable::default(),
+            TuningTable::default(),
+        );
+        #[allow(unused_mut)]
+        let (tuning_producer, mut $tuning_consumer) = tuning_buffer.split_mut();
+        let mut $midi_listener = MidiListener::new(sender, tuning_producer);
     };
 }
 
@@ -161,3 +173,195 @@ fn when_receiving_garbage_it_processes_the_midi() {
         ]
     );
 }
+
+fn bulk_dump(tunings: &[(u8, u8, u8); 128]) -> Vec<u8> {
+    let mut data = vec![0x7E, 0x7F, 0x08, 0x01, 0x00];
+    data.extend_from_slice(b"Test tuning     ");
+    for &(xx, yy, zz) in tunings {
+        data.extend_from_slice(&[xx, yy, zz]);
+    }
+    data.push(data.iter().fold(0, |acc, byte| acc ^ byte) & 0x7F);
+
+    assert_eq!(data.len(), BULK_DUMP_LENGTH);
+
+    data
+}
+
+fn write_sysex(data: &[u8], buffer: &mut Vec<u8>) {
+    LiveEvent::Common(SystemCommon::SysEx(u7::slice_from_int(data)))
+        .write(buffer)
+        .unwrap();
+}
+
+#[test]
+fn when_receiving_a_bulk_tuning_dump_it_publishes_the_tuning() {
+    setup!(receiver, midi_listener, tuning_consumer);
+
+    let mut tunings = [(0, 0, 0); 128];
+    for (key, tuning) in tunings.iter_mut().enumerate() {
+        // Everything a quarter tone sharp
+        *tuning = (key as u8, 0x40, 0x00);
+    }
+    // Key 0 is left unchanged
+    tunings[0] = (0x7F, 0x7F, 0x7F);
+
+    let mut input_buffer: Vec<u8> = Vec::new();
+    note_on!(0, 60, 100).write(&mut input_buffer).unwrap();
+    write_sysex(&bulk_dump(&tunings), &mut input_buffer);
+    note_off!(0, 60, 0).write(&mut input_buffer).unwrap();
+
+    midi_listener.process_bytes(&input_buffer);
+
+    assert!(tuning_consumer.published());
+    tuning_consumer.consume();
+    let table = tuning_consumer.get();
+
+    assert_eq!(table.notes[0], NoteTuning::new(0, 0));
+    assert_eq!(table.notes[69], NoteTuning::new(69, 8192));
+    assert_eq!(table.notes[127], NoteTuning::new(127, 8192));
+
+    // Notes around the SysEx are still delivered
+    let mut output_buffer: Vec<MidiEvent> = Vec::new();
+    while let Ok(event) = receiver.try_receive() {
+        output_buffer.push(event);
+    }
+    assert_eq!(
+        output_buffer.as_slice(),
+        &[
+            MidiEvent::NoteOn { key: 60, vel: 100 },
+            MidiEvent::NoteOff { key: 60, vel: 0 },
+        ]
+    );
+}
+
+#[test]
+fn when_a_bulk_tuning_dump_has_a_bad_checksum_it_is_ignored() {
+    setup!(_receiver, midi_listener, tuning_consumer);
+
+    let mut dump = bulk_dump(&[(0, 0x40, 0x00); 128]);
+    let last = dump.len() - 1;
+    dump[last] ^= 0x01;
+
+    let mut input_buffer: Vec<u8> = Vec::new();
+    write_sysex(&dump, &mut input_buffer);
+
+    midi_listener.process_bytes(&input_buffer);
+
+    assert!(!tuning_consumer.published());
+}
+
+#[test]
+fn when_receiving_single_note_tuning_changes_it_updates_only_those_notes() {
+    setup!(_receiver, midi_listener, tuning_consumer);
+
+    let mut input_buffer: Vec<u8> = Vec::new();
+    // Real time single note tuning change: 69 -> 69 + 1/4 semitone, 70 -> 72
+    write_sysex(
+        &[
+            0x7F, 0x7F, 0x08, 0x02, 0x00, 0x02, 69, 69, 0x20, 0x00, 70, 72, 0x00, 0x00,
+        ],
+        &mut input_buffer,
+    );
+    // With bank: 71 -> 50
+    write_sysex(
+        &[0x7E, 0x7F, 0x08, 0x07, 0x00, 0x00, 0x01, 71, 50, 0x00, 0x00],
+        &mut input_buffer,
+    );
+
+    // Fed one byte at a time, as a UART would
+    for byte in input_buffer {
+        midi_listener.process_bytes(&[byte]);
+    }
+
+    assert!(tuning_consumer.published());
+    tuning_consumer.consume();
+
+    let mut expected = TuningTable::equal_temperament();
+    expected.notes[69] = NoteTuning::new(69, 4096);
+    expected.notes[70] = NoteTuning::new(72, 0);
+    expected.notes[71] = NoteTuning::new(50, 0);
+
+    assert_eq!(*tuning_consumer.get(), expected);
+}
+
+#[test]
+fn when_receiving_unrelated_sysex_it_does_not_publish_a_tuning() {
+    setup!(_receiver, midi_listener, tuning_consumer);
+
+    let mut input_buffer: Vec<u8> = Vec::new();
+    // GM system on
+    write_sysex(&[0x7E, 0x7F, 0x09, 0x01], &mut input_buffer);
+    // Truncated single note tuning change
+    write_sysex(&[0x7F, 0x7F, 0x08, 0x02], &mut input_buffer);
+
+    midi_listener.process_bytes(&input_buffer);
+
+    assert!(!tuning_consumer.published());
+}
+
+#[test]
+fn when_receiving_controllers_it_processes_them() {
+    setup!(receiver, midi_listener);
+
+    let sample_midi = [
+        LiveEvent::Midi {
+            channel: 0.into(),
+            message: MidiMessage::Aftertouch {
+                key: 60.into(),
+                vel: 100.into(),
+            },
+        },
+        LiveEvent::Midi {
+            channel: 1.into(),
+            message: MidiMessage::Controller {
+                controller: 1.into(),
+                value: 64.into(),
+            },
+        },
+        // Not followed by the synth engine, so not forwarded
+        LiveEvent::Midi {
+            channel: 1.into(),
+            message: MidiMessage::Controller {
+                controller: 7.into(),
+                value: 100.into(),
+            },
+        },
+        LiveEvent::Midi {
+            channel: 2.into(),
+            message: MidiMessage::ChannelAftertouch { vel: 30.into() },
+        },
+        LiveEvent::Midi {
+            channel: 3.into(),
+            message: MidiMessage::PitchBend {
+                bend: midly::PitchBend::from_int(-4096),
+            },
+        },
+    ];
+
+    let mut input_buffer: Vec<u8> = Vec::new();
+
+    sample_midi
+        .iter()
+        .for_each(|ev| ev.write(&mut input_buffer).unwrap());
+
+    midi_listener.process_bytes(&input_buffer);
+
+    let mut output_buffer: Vec<MidiEvent> = Vec::new();
+
+    while let Ok(event) = receiver.try_receive() {
+        output_buffer.push(event);
+    }
+
+    assert_eq!(
+        output_buffer.as_slice(),
+        &[
+            MidiEvent::Aftertouch { key: 60, vel: 100 },
+            MidiEvent::Controller {
+                controller: 1,
+                value: 64
+            },
+            MidiEvent::ChannelAftertouch { vel: 30 },
+            MidiEvent::PitchBend { bend: -4096 },
+        ]
+    );
+}
@@ -0,0 +1,140 @@
+use defmt::Format;
+use midly::num::u7;
+
+/// Number of data bytes (excluding `F0` and `F7`) in an MTS bulk tuning dump.
+///
+/// `7E <device> 08 01 <program> <name: 16 bytes> <128 * [xx yy zz]> <checksum>`
+pub const BULK_DUMP_LENGTH: usize = 4 + 1 + 16 + 128 * 3 + 1;
+
+const NON_REAL_TIME: u8 = 0x7E;
+const REAL_TIME: u8 = 0x7F;
+const MIDI_TUNING_STANDARD: u8 = 0x08;
+
+const BULK_DUMP_REPLY: u8 = 0x01;
+const SINGLE_NOTE_TUNING_CHANGE: u8 = 0x02;
+const SINGLE_NOTE_TUNING_CHANGE_WITH_BANK: u8 = 0x07;
+
+/// Tuning of a single key, in the MTS frequency data format.
+///
+/// The pitch is `semitone` (as a MIDI note number) plus `fraction` / 16384 semitones.
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteTuning {
+    pub semitone: u8,
+    pub fraction: u16,
+}
+
+impl NoteTuning {
+    pub const fn new(semitone: u8, fraction: u16) -> Self {
+        Self { semitone, fraction }
+    }
+
+    /// Decodes `[xx, yy, zz]`. Returns `None` for the `7F 7F 7F` "no change" marker.
+    fn from_mts_bytes(bytes: &[u7; 3]) -> Option<Self> {
+        let [semitone, msb, lsb] = bytes.map(u7::as_int);
+
+        if semitone == 0x7F && msb == 0x7F && lsb == 0x7F {
+            return None;
+        }
+
+        Some(Self {
+            semitone,
+            fraction: ((msb as u16) << 7) | lsb as u16,
+        })
+    }
+}
+
+/// RAM-resident tuning for all 128 MIDI keys.
+///
+/// MTS program and bank numbers are ignored: there is only one tuning, and any
+/// dump or change that is received is applied to it.
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TuningTable {
+    pub notes: [NoteTuning; 128],
+}
+
+impl TuningTable {
+    /// Standard 12-TET tuning, where every key plays its own note number.
+    pub const fn equal_temperament() -> Self {
+        let mut notes = [NoteTuning::new(0, 0); 128];
+        let mut key = 0;
+        while key < 128 {
+            notes[key] = NoteTuning::new(key as u8, 0);
+            key += 1;
+        }
+        Self { notes }
+    }
+
+    /// Applies a SysEx message (without the `F0` and `F7` framing bytes).
+    ///
+    /// Understands MTS bulk tuning dumps and single note tuning changes (with or
+    /// without bank). Returns `true` if the table was modified.
+    pub fn apply_sysex(&mut self, data: &[u7]) -> bool {
+        let [universal, _device, sub_id_1, sub_id_2, ..] = *data else {
+            return false;
+        };
+
+        if !matches!(universal.as_int(), NON_REAL_TIME | REAL_TIME)
+            || sub_id_1.as_int() != MIDI_TUNING_STANDARD
+        {
+            return false;
+        }
+
+        match sub_id_2.as_int() {
+            BULK_DUMP_REPLY => self.apply_bulk_dump(data),
+            // 7F <device> 08 02 <program> <count> [kk xx yy zz]*
+            SINGLE_NOTE_TUNING_CHANGE => data.len() > 5 && self.apply_note_changes(&data[5..]),
+            // 7E/7F <device> 08 07 <bank> <program> <count> [kk xx yy zz]*
+            SINGLE_NOTE_TUNING_CHANGE_WITH_BANK => {
+                data.len() > 6 && self.apply_note_changes(&data[6..])
+            }
+            _ => false,
+        }
+    }
+
+    fn apply_bulk_dump(&mut self, data: &[u7]) -> bool {
+        if data.len() != BULK_DUMP_LENGTH {
+            return false;
+        }
+
+        let (message, checksum) = data.split_at(BULK_DUMP_LENGTH - 1);
+        let expected = message.iter().fold(0, |acc, byte| acc ^ byte.as_int()) & 0x7F;
+        if checksum[0].as_int() != expected {
+            return false;
+        }
+
+        // Skip the header, program number and 16 byte name
+        for (note, bytes) in self.notes.iter_mut().zip(message[21..].as_chunks::<3>().0) {
+            if let Some(tuning) = NoteTuning::from_mts_bytes(bytes) {
+                *note = tuning;
+            }
+        }
+
+        true
+    }
+
+    /// Applies `<count> [kk xx yy zz]*`.
+    fn apply_note_changes(&mut self, data: &[u7]) -> bool {
+        let Some((count, changes)) = data.split_first() else {
+            return false;
+        };
+
+        let count = count.as_int() as usize;
+        if changes.len() < count * 4 {
+            return false;
+        }
+
+        for [key, tuning @ ..] in changes[..count * 4].as_chunks::<4>().0 {
+            if let Some(tuning) = NoteTuning::from_mts_bytes(tuning) {
+                self.notes[key.as_int() as usize] = tuning;
+            }
+        }
+
+        true
+    }
+}
+
+impl Default for TuningTable {
+    fn default() -> Self {
+        Self::equal_temperament()
+    }
+}
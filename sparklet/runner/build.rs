@@ -36,8 +36,68 @@ fn main() {
 
     let attack = get_u8("attack");
     let sustain = get_u8("sustain");
-    let decay_release = get_u8("decay_release"); // Maps TOML decay_release -> release
+    let decay = get_u8("decay");
+    let release = get_u8("release");
+    let delay = get_u8("delay");
+    let hold = get_u8("hold");
+    let attack_curve = get_u8("attack_curve");
+    let decay_curve = get_u8("decay_curve");
+    let release_curve = get_u8("release_curve");
     let osc_type = get_u8("oscilator_type");
+    let second_osc_type = get_u8("second_oscilator_type");
+    let second_osc_level = get_u8("second_oscilator_level");
+    let second_osc_detune = get_u8("second_oscilator_detune");
+    let second_osc_semitones = get_u8("second_oscilator_semitones");
+    let second_osc_octave = get_u8("second_oscilator_octave");
+    let get_u8_array = |k| -> Vec<u8> {
+        init.get(k)
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_integer().unwrap() as u8)
+            .collect()
+    };
+    let fm_ratios = get_u8_array("fm_ratios");
+    let fm_levels = get_u8_array("fm_levels");
+    let fm_decay_releases = get_u8_array("fm_decay_releases");
+    let mod_sources = get_u8_array("mod_sources");
+    let mod_destinations = get_u8_array("mod_destinations");
+    let mod_depths = get_u8_array("mod_depths");
+    let pulse_width = get_u8("pulse_width");
+    let sub_osc_type = get_u8("sub_oscilator_type");
+    let sub_osc_level = get_u8("sub_oscilator_level");
+    let phase_mode = get_u8("phase_mode");
+    let analog_drift = get_u8("analog_drift");
+    let string_excitation = get_u8("string_excitation");
+    let string_decay = get_u8("string_decay");
+    let string_damping = get_u8("string_damping");
+    let phase_distortion_shape = get_u8("phase_distortion_shape");
+    let phase_distortion_amount = get_u8("phase_distortion_amount");
+    let phase_distortion_decay_release = get_u8("phase_distortion_decay_release");
+    let shaper_type = get_u8("shaper_type");
+    let shaper_drive = get_u8("shaper_drive");
+    let shaper_symmetry = get_u8("shaper_symmetry");
+    let lfo_shape = get_u8("lfo_shape");
+    let lfo_rate = get_u8("lfo_rate");
+    let lfo_depth = get_u8("lfo_depth");
+    let lfo_phase = get_u8("lfo_phase");
+    let lfo_delay = get_u8("lfo_delay");
+    let lfo_fade_in = get_u8("lfo_fade_in");
+    let mod_envelope_attack = get_u8("mod_envelope_attack");
+    let mod_envelope_sustain = get_u8("mod_envelope_sustain");
+    let mod_envelope_decay = get_u8("mod_envelope_decay");
+    let mod_envelope_release = get_u8("mod_envelope_release");
+    let velocity_curve = get_u8("velocity_curve");
+    let velocity_sensitivity = get_u8("velocity_sensitivity");
+    let fixed_velocity = get_u8("fixed_velocity");
+    let attack_key_tracking = get_u8("attack_key_tracking");
+    let decay_release_key_tracking = get_u8("decay_release_key_tracking");
+    let amplitude_key_tracking = get_u8("amplitude_key_tracking");
+    let envelope_loop = get_u8("envelope_loop");
+    let mod_envelope_loop = get_u8("mod_envelope_loop");
+    let noise_type = get_u8("noise_type");
+    let noise_level = get_u8("noise_level");
     let f250 = get_u8("f250hz");
     let f500 = get_u8("f500hz");
     let f1000 = get_u8("f1000hz");
@@ -51,8 +111,59 @@ fn main() {
 pub struct InitialConfig {{
     pub attack: u8,
     pub sustain: u8,
-    pub decay_release: u8,
+    pub decay: u8,
+    pub release: u8,
+    pub delay: u8,
+    pub hold: u8,
+    pub attack_curve: u8,
+    pub decay_curve: u8,
+    pub release_curve: u8,
     pub oscilator_type: u8,
+    pub second_oscilator_type: u8,
+    pub second_oscilator_level: u8,
+    pub second_oscilator_detune: u8,
+    pub second_oscilator_semitones: u8,
+    pub second_oscilator_octave: u8,
+    pub fm_ratios: [u8; 4],
+    pub fm_levels: [u8; 4],
+    pub fm_decay_releases: [u8; 4],
+    pub mod_sources: [u8; 4],
+    pub mod_destinations: [u8; 4],
+    pub mod_depths: [u8; 4],
+    pub pulse_width: u8,
+    pub sub_oscilator_type: u8,
+    pub sub_oscilator_level: u8,
+    pub phase_mode: u8,
+    pub analog_drift: u8,
+    pub string_excitation: u8,
+    pub string_decay: u8,
+    pub string_damping: u8,
+    pub phase_distortion_shape: u8,
+    pub phase_distortion_amount: u8,
+    pub phase_distortion_decay_release: u8,
+    pub shaper_type: u8,
+    pub shaper_drive: u8,
+    pub shaper_symmetry: u8,
+    pub lfo_shape: u8,
+    pub lfo_rate: u8,
+    pub lfo_depth: u8,
+    pub lfo_phase: u8,
+    pub lfo_delay: u8,
+    pub lfo_fade_in: u8,
+    pub mod_envelope_attack: u8,
+    pub mod_envelope_sustain: u8,
+    pub mod_envelope_decay: u8,
+    pub mod_envelope_release: u8,
+    pub velocity_curve: u8,
+    pub velocity_sensitivity: u8,
+    pub fixed_velocity: u8,
+    pub attack_key_tracking: u8,
+    pub decay_release_key_tracking: u8,
+    pub amplitude_key_tracking: u8,
+    pub envelope_loop: u8,
+    pub mod_envelope_loop: u8,
+    pub noise_type: u8,
+    pub noise_level: u8,
     pub f250hz: u8,
     pub f500hz: u8,
     pub f1000hz: u8,
@@ -77,8 +188,59 @@ pub const BUILD_CONFIG: BuildConfig = BuildConfig {{
     initial_config: InitialConfig {{
         attack: {attack},
         sustain: {sustain},
-        decay_release: {decay_release},
+        decay: {decay},
+        release: {release},
+        delay: {delay},
+        hold: {hold},
+        attack_curve: {attack_curve},
+        decay_curve: {decay_curve},
+        release_curve: {release_curve},
         oscilator_type: {osc_type},
+        second_oscilator_type: {second_osc_type},
+        second_oscilator_level: {second_osc_level},
+        second_oscilator_detune: {second_osc_detune},
+        second_oscilator_semitones: {second_osc_semitones},
+        second_oscilator_octave: {second_osc_octave},
+        fm_ratios: {fm_ratios:?},
+        fm_levels: {fm_levels:?},
+        fm_decay_releases: {fm_decay_releases:?},
+        mod_sources: {mod_sources:?},
+        mod_destinations: {mod_destinations:?},
+        mod_depths: {mod_depths:?},
+        pulse_width: {pulse_width},
+        sub_oscilator_type: {sub_osc_type},
+        sub_oscilator_level: {sub_osc_level},
+        phase_mode: {phase_mode},
+        analog_drift: {analog_drift},
+        string_excitation: {string_excitation},
+        string_decay: {string_decay},
+        string_damping: {string_damping},
+        phase_distortion_shape: {phase_distortion_shape},
+        phase_distortion_amount: {phase_distortion_amount},
+        phase_distortion_decay_release: {phase_distortion_decay_release},
+        shaper_type: {shaper_type},
+        shaper_drive: {shaper_drive},
+        shaper_symmetry: {shaper_symmetry},
+        lfo_shape: {lfo_shape},
+        lfo_rate: {lfo_rate},
+        lfo_depth: {lfo_depth},
+        lfo_phase: {lfo_phase},
+        lfo_delay: {lfo_delay},
+        lfo_fade_in: {lfo_fade_in},
+        mod_envelope_attack: {mod_envelope_attack},
+        mod_envelope_sustain: {mod_envelope_sustain},
+        mod_envelope_decay: {mod_envelope_decay},
+        mod_envelope_release: {mod_envelope_release},
+        velocity_curve: {velocity_curve},
+        velocity_sensitivity: {velocity_sensitivity},
+        fixed_velocity: {fixed_velocity},
+        attack_key_tracking: {attack_key_tracking},
+        decay_release_key_tracking: {decay_release_key_tracking},
+        amplitude_key_tracking: {amplitude_key_tracking},
+        envelope_loop: {envelope_loop},
+        mod_envelope_loop: {mod_envelope_loop},
+        noise_type: {noise_type},
+        noise_level: {noise_level},
         f250hz: {f250},
         f500hz: {f500},
         f1000hz: {f1000},
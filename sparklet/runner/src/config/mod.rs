@@ -6,7 +6,7 @@ use static_cell::StaticCell;
 #[cfg(feature = "configurable")]
 pub mod task;
 
-const BASE_PAGE_COUNT: usize = 2;
+const BASE_PAGE_COUNT: usize = 26;
 
 #[cfg(feature = "octave-filter")]
 const OCTAVE_FILTER_PAGE_COUNT: usize = 2;
@@ -18,9 +18,129 @@ pub const INITIAL_CONFIG: [[u8; CONFIG_ENCODER_COUNT]; CONFIG_PAGE_COUNT] = [
     [
         BUILD_CONFIG.initial_config.attack,
         BUILD_CONFIG.initial_config.sustain,
-        BUILD_CONFIG.initial_config.decay_release,
+        BUILD_CONFIG.initial_config.decay,
+    ],
+    [
+        BUILD_CONFIG.initial_config.oscilator_type,
+        BUILD_CONFIG.initial_config.second_oscilator_level,
+        BUILD_CONFIG.initial_config.second_oscilator_detune,
+    ],
+    [
+        BUILD_CONFIG.initial_config.noise_type,
+        BUILD_CONFIG.initial_config.noise_level,
+        0,
+    ],
+    [
+        BUILD_CONFIG.initial_config.second_oscilator_type,
+        BUILD_CONFIG.initial_config.second_oscilator_semitones,
+        BUILD_CONFIG.initial_config.second_oscilator_octave,
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[0],
+        BUILD_CONFIG.initial_config.fm_levels[0],
+        BUILD_CONFIG.initial_config.fm_decay_releases[0],
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[1],
+        BUILD_CONFIG.initial_config.fm_levels[1],
+        BUILD_CONFIG.initial_config.fm_decay_releases[1],
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[2],
+        BUILD_CONFIG.initial_config.fm_levels[2],
+        BUILD_CONFIG.initial_config.fm_decay_releases[2],
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[3],
+        BUILD_CONFIG.initial_config.fm_levels[3],
+        BUILD_CONFIG.initial_config.fm_decay_releases[3],
+    ],
+    [
+        BUILD_CONFIG.initial_config.pulse_width,
+        BUILD_CONFIG.initial_config.sub_oscilator_type,
+        BUILD_CONFIG.initial_config.sub_oscilator_level,
+    ],
+    [
+        BUILD_CONFIG.initial_config.phase_mode,
+        BUILD_CONFIG.initial_config.analog_drift,
+        0,
+    ],
+    [
+        BUILD_CONFIG.initial_config.string_excitation,
+        BUILD_CONFIG.initial_config.string_decay,
+        BUILD_CONFIG.initial_config.string_damping,
+    ],
+    [
+        BUILD_CONFIG.initial_config.phase_distortion_shape,
+        BUILD_CONFIG.initial_config.phase_distortion_amount,
+        BUILD_CONFIG.initial_config.phase_distortion_decay_release,
+    ],
+    [
+        BUILD_CONFIG.initial_config.shaper_type,
+        BUILD_CONFIG.initial_config.shaper_drive,
+        BUILD_CONFIG.initial_config.shaper_symmetry,
+    ],
+    [
+        BUILD_CONFIG.initial_config.release,
+        BUILD_CONFIG.initial_config.delay,
+        BUILD_CONFIG.initial_config.hold,
+    ],
+    [
+        BUILD_CONFIG.initial_config.attack_curve,
+        BUILD_CONFIG.initial_config.decay_curve,
+        BUILD_CONFIG.initial_config.release_curve,
+    ],
+    [
+        BUILD_CONFIG.initial_config.lfo_shape,
+        BUILD_CONFIG.initial_config.lfo_rate,
+        BUILD_CONFIG.initial_config.lfo_depth,
+    ],
+    [
+        BUILD_CONFIG.initial_config.lfo_phase,
+        BUILD_CONFIG.initial_config.lfo_delay,
+        BUILD_CONFIG.initial_config.lfo_fade_in,
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[0],
+        BUILD_CONFIG.initial_config.mod_destinations[0],
+        BUILD_CONFIG.initial_config.mod_depths[0],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[1],
+        BUILD_CONFIG.initial_config.mod_destinations[1],
+        BUILD_CONFIG.initial_config.mod_depths[1],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[2],
+        BUILD_CONFIG.initial_config.mod_destinations[2],
+        BUILD_CONFIG.initial_config.mod_depths[2],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[3],
+        BUILD_CONFIG.initial_config.mod_destinations[3],
+        BUILD_CONFIG.initial_config.mod_depths[3],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_envelope_attack,
+        BUILD_CONFIG.initial_config.mod_envelope_sustain,
+        BUILD_CONFIG.initial_config.mod_envelope_decay,
+    ],
+    [BUILD_CONFIG.initial_config.mod_envelope_release, 0, 0],
+    [
+        BUILD_CONFIG.initial_config.velocity_curve,
+        BUILD_CONFIG.initial_config.velocity_sensitivity,
+        BUILD_CONFIG.initial_config.fixed_velocity,
+    ],
+    [
+        BUILD_CONFIG.initial_config.attack_key_tracking,
+        BUILD_CONFIG.initial_config.decay_release_key_tracking,
+        BUILD_CONFIG.initial_config.amplitude_key_tracking,
+    ],
+    [
+        BUILD_CONFIG.initial_config.envelope_loop,
+        BUILD_CONFIG.initial_config.mod_envelope_loop,
+        0,
     ],
-    [BUILD_CONFIG.initial_config.oscilator_type, 127, 127],
 ];
 
 #[cfg(feature = "octave-filter")]
@@ -28,9 +148,129 @@ pub const INITIAL_CONFIG: [[u8; CONFIG_ENCODER_COUNT]; CONFIG_PAGE_COUNT] = [
     [
         BUILD_CONFIG.initial_config.attack,
         BUILD_CONFIG.initial_config.sustain,
-        BUILD_CONFIG.initial_config.decay_release,
+        BUILD_CONFIG.initial_config.decay,
+    ],
+    [
+        BUILD_CONFIG.initial_config.oscilator_type,
+        BUILD_CONFIG.initial_config.second_oscilator_level,
+        BUILD_CONFIG.initial_config.second_oscilator_detune,
+    ],
+    [
+        BUILD_CONFIG.initial_config.noise_type,
+        BUILD_CONFIG.initial_config.noise_level,
+        0,
+    ],
+    [
+        BUILD_CONFIG.initial_config.second_oscilator_type,
+        BUILD_CONFIG.initial_config.second_oscilator_semitones,
+        BUILD_CONFIG.initial_config.second_oscilator_octave,
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[0],
+        BUILD_CONFIG.initial_config.fm_levels[0],
+        BUILD_CONFIG.initial_config.fm_decay_releases[0],
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[1],
+        BUILD_CONFIG.initial_config.fm_levels[1],
+        BUILD_CONFIG.initial_config.fm_decay_releases[1],
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[2],
+        BUILD_CONFIG.initial_config.fm_levels[2],
+        BUILD_CONFIG.initial_config.fm_decay_releases[2],
+    ],
+    [
+        BUILD_CONFIG.initial_config.fm_ratios[3],
+        BUILD_CONFIG.initial_config.fm_levels[3],
+        BUILD_CONFIG.initial_config.fm_decay_releases[3],
+    ],
+    [
+        BUILD_CONFIG.initial_config.pulse_width,
+        BUILD_CONFIG.initial_config.sub_oscilator_type,
+        BUILD_CONFIG.initial_config.sub_oscilator_level,
+    ],
+    [
+        BUILD_CONFIG.initial_config.phase_mode,
+        BUILD_CONFIG.initial_config.analog_drift,
+        0,
+    ],
+    [
+        BUILD_CONFIG.initial_config.string_excitation,
+        BUILD_CONFIG.initial_config.string_decay,
+        BUILD_CONFIG.initial_config.string_damping,
+    ],
+    [
+        BUILD_CONFIG.initial_config.phase_distortion_shape,
+        BUILD_CONFIG.initial_config.phase_distortion_amount,
+        BUILD_CONFIG.initial_config.phase_distortion_decay_release,
+    ],
+    [
+        BUILD_CONFIG.initial_config.shaper_type,
+        BUILD_CONFIG.initial_config.shaper_drive,
+        BUILD_CONFIG.initial_config.shaper_symmetry,
+    ],
+    [
+        BUILD_CONFIG.initial_config.release,
+        BUILD_CONFIG.initial_config.delay,
+        BUILD_CONFIG.initial_config.hold,
+    ],
+    [
+        BUILD_CONFIG.initial_config.attack_curve,
+        BUILD_CONFIG.initial_config.decay_curve,
+        BUILD_CONFIG.initial_config.release_curve,
+    ],
+    [
+        BUILD_CONFIG.initial_config.lfo_shape,
+        BUILD_CONFIG.initial_config.lfo_rate,
+        BUILD_CONFIG.initial_config.lfo_depth,
+    ],
+    [
+        BUILD_CONFIG.initial_config.lfo_phase,
+        BUILD_CONFIG.initial_config.lfo_delay,
+        BUILD_CONFIG.initial_config.lfo_fade_in,
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[0],
+        BUILD_CONFIG.initial_config.mod_destinations[0],
+        BUILD_CONFIG.initial_config.mod_depths[0],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[1],
+        BUILD_CONFIG.initial_config.mod_destinations[1],
+        BUILD_CONFIG.initial_config.mod_depths[1],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[2],
+        BUILD_CONFIG.initial_config.mod_destinations[2],
+        BUILD_CONFIG.initial_config.mod_depths[2],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_sources[3],
+        BUILD_CONFIG.initial_config.mod_destinations[3],
+        BUILD_CONFIG.initial_config.mod_depths[3],
+    ],
+    [
+        BUILD_CONFIG.initial_config.mod_envelope_attack,
+        BUILD_CONFIG.initial_config.mod_envelope_sustain,
+        BUILD_CONFIG.initial_config.mod_envelope_decay,
+    ],
+    [BUILD_CONFIG.initial_config.mod_envelope_release, 0, 0],
+    [
+        BUILD_CONFIG.initial_config.velocity_curve,
+        BUILD_CONFIG.initial_config.velocity_sensitivity,
+        BUILD_CONFIG.initial_config.fixed_velocity,
+    ],
+    [
+        BUILD_CONFIG.initial_config.attack_key_tracking,
+        BUILD_CONFIG.initial_config.decay_release_key_tracking,
+        BUILD_CONFIG.initial_config.amplitude_key_tracking,
+    ],
+    [
+        BUILD_CONFIG.initial_config.envelope_loop,
+        BUILD_CONFIG.initial_config.mod_envelope_loop,
+        0,
     ],
-    [BUILD_CONFIG.initial_config.oscilator_type, 127, 127],
     [
         BUILD_CONFIG.initial_config.f250hz,
         BUILD_CONFIG.initial_config.f500hz,
@@ -38,10 +38,14 @@ fn main() -> ! {
         hardware.usb_builder.build()
     };
 
+    info!("Initialising tuning transport");
+    #[allow(unused_variables)]
+    let (tuning_producer, tuning_consumer) = midi_task::init_tuning_transport();
+
     #[cfg(any(feature = "midi-din", feature = "midi-usb"))]
     let midi_task = {
         info!("Creating MIDI task");
-        midi_task::create_midi_task(hardware.midi_hardware)
+        midi_task::create_midi_task(hardware.midi_hardware, tuning_producer)
     };
 
     #[cfg(feature = "audio-usb")]
@@ -55,10 +59,11 @@ fn main() -> ! {
 
     info!("Creating synth engine task");
     #[cfg(feature = "audio-usb")]
-    let synth_engine_task = synth_engine_task::create_task(config_consumer, audio_sender);
+    let synth_engine_task =
+        synth_engine_task::create_task(config_consumer, tuning_consumer, audio_sender);
 
     #[cfg(not(feature = "audio-usb"))]
-    let synth_engine_task = synth_engine_task::create_task(config_consumer);
+    let synth_engine_task = synth_engine_task::create_task(config_consumer, tuning_consumer);
 
     info!("Setting up tasks in executors...");
     executor.run(|spawner| {
@@ -6,16 +6,16 @@ use midi::MidiListener;
 use static_cell::StaticCell;
 
 use crate::hardware::midi_din::MidiDinHardware;
-use crate::midi_task::{MIDI_CHANNEL_SIZE, MIDI_TASK_CHANNEL};
+use crate::midi_task::{MIDI_CHANNEL_SIZE, MIDI_TASK_CHANNEL, TuningProducer};
 
 pub struct MidiTaskState<'a> {
-    midi_listener: MidiListener<'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
+    midi_listener: MidiListener<'a, 'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
     midi_uart_buffered: RingBufferedUartRx<'a>,
 }
 
 impl<'a> MidiTaskState<'a> {
     pub fn new(
-        midi_listener: MidiListener<'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
+        midi_listener: MidiListener<'a, 'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
         midi_uart_buffered: RingBufferedUartRx<'a>,
     ) -> MidiTaskState<'a> {
         MidiTaskState {
@@ -27,12 +27,15 @@ impl<'a> MidiTaskState<'a> {
 
 pub static MIDI_TASK_STATE: StaticCell<MidiTaskState> = StaticCell::new();
 
-pub fn create_midi_task(midi_hardware: MidiDinHardware<'static>) -> SpawnToken<impl Sized> {
+pub fn create_midi_task(
+    midi_hardware: MidiDinHardware<'static>,
+    tuning_producer: TuningProducer,
+) -> SpawnToken<impl Sized> {
     let midi_uart_buffered = midi_hardware.midi_uart_buffered;
 
     let midi_sender = MIDI_TASK_CHANNEL.sender();
 
-    let midi_listener = MidiListener::new(midi_sender);
+    let midi_listener = MidiListener::new(midi_sender, tuning_producer);
 
     midi_task(MIDI_TASK_STATE.init(MidiTaskState::new(midi_listener, midi_uart_buffered)))
 }
@@ -9,16 +9,16 @@ use midi::MidiListener;
 use static_cell::StaticCell;
 
 use crate::hardware::midi_usb::MidiUsbHardware;
-use crate::midi_task::{MIDI_CHANNEL_SIZE, MIDI_TASK_CHANNEL};
+use crate::midi_task::{MIDI_CHANNEL_SIZE, MIDI_TASK_CHANNEL, TuningProducer};
 
 pub struct MidiTaskState<'a> {
-    midi_listener: MidiListener<'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
+    midi_listener: MidiListener<'a, 'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
     midi_class: MidiClass<'a, usb::Driver<'a, peripherals::USB_OTG_HS>>,
 }
 
 impl<'a> MidiTaskState<'a> {
     pub fn new(
-        midi_listener: MidiListener<'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
+        midi_listener: MidiListener<'a, 'a, CriticalSectionRawMutex, MIDI_CHANNEL_SIZE>,
         midi_class: MidiClass<'a, usb::Driver<'a, peripherals::USB_OTG_HS>>,
     ) -> MidiTaskState<'a> {
         MidiTaskState {
@@ -30,10 +30,13 @@ impl<'a> MidiTaskState<'a> {
 
 pub static MIDI_TASK_STATE: StaticCell<MidiTaskState> = StaticCell::new();
 
-pub fn create_midi_task(midi_hardware: MidiUsbHardware<'static>) -> SpawnToken<impl Sized> {
+pub fn create_midi_task(
+    midi_hardware: MidiUsbHardware<'static>,
+    tuning_producer: TuningProducer,
+) -> SpawnToken<impl Sized> {
     let midi_class = midi_hardware.midi_class;
     let midi_sender = MIDI_TASK_CHANNEL.sender();
-    let midi_listener = MidiListener::new(midi_sender);
+    let midi_listener = MidiListener::new(midi_sender, tuning_producer);
 
     midi_task(MIDI_TASK_STATE.init(MidiTaskState::new(midi_listener, midi_class)))
 }
@@ -57,13 +60,25 @@ async fn midi_handler(state: &mut MidiTaskState<'static>) -> Result<(), Disconne
         // USB MIDI packets are 4 bytes: [Cable/CIN][MIDI1][MIDI2][MIDI3]
         // Process in 4-byte chunks
         for chunk in buffer[..n].chunks_exact(4) {
-            // Extract the 3 MIDI bytes (skip first byte which is USB-specific)
-            let midi_bytes = &chunk[1..4];
+            // Skip the first byte, which is USB-specific, and the padding.
+            // SysEx is split across packets, so the padding must not reach the parser.
+            let midi_bytes = &chunk[1..1 + midi_bytes_for_code_index(chunk[0])];
             state.midi_listener.process_bytes(midi_bytes);
         }
     }
 }
 
+/// Number of MIDI bytes in a USB MIDI packet, from its Code Index Number
+fn midi_bytes_for_code_index(header: u8) -> usize {
+    match header & 0x0F {
+        // Single byte, SysEx ends with one byte
+        0x5 | 0xF => 1,
+        // Two byte System Common, SysEx ends with two bytes, Program Change, Channel Pressure
+        0x2 | 0x6 | 0xC | 0xD => 2,
+        _ => 3,
+    }
+}
+
 #[embassy_executor::task]
 pub async fn midi_task(state: &'static mut MidiTaskState<'static>) {
     loop {
@@ -1,5 +1,7 @@
+use amity::triple::{TripleBuffer, TripleBufferConsumer, TripleBufferProducer};
 use embassy_sync::{blocking_mutex::raw::CriticalSectionRawMutex, channel::Channel};
-use midi::MidiEvent;
+use midi::{MidiEvent, TuningTable};
+use static_cell::StaticCell;
 
 #[cfg(feature = "midi-din")]
 pub mod midi_din;
@@ -18,3 +20,22 @@ pub const MIDI_CHANNEL_SIZE: usize = 16;
 
 pub static MIDI_TASK_CHANNEL: Channel<CriticalSectionRawMutex, MidiEvent, MIDI_CHANNEL_SIZE> =
     Channel::new();
+
+// Type aliases for the triple buffer halves
+type TuningBuffer = TripleBuffer<TuningTable>;
+pub type TuningProducer = TripleBufferProducer<TuningTable, &'static TuningBuffer>;
+pub type TuningConsumer = TripleBufferConsumer<TuningTable, &'static TuningBuffer>;
+
+// Static triple buffer for the MTS tuning table
+static TUNING_TRIPLE_BUFFER: StaticCell<TuningBuffer> = StaticCell::new();
+
+/// Initialise the tuning triple buffer and return the producer/consumer halves.
+/// Must be called exactly once before creating the MIDI or synth engine tasks.
+pub fn init_tuning_transport() -> (TuningProducer, TuningConsumer) {
+    let buf = TUNING_TRIPLE_BUFFER.init(TripleBuffer::new(
+        TuningTable::default(),
+        TuningTable::default(),
+        TuningTable::default(),
+    ));
+    buf.split_mut()
+}
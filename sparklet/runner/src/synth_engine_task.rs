@@ -7,7 +7,7 @@ use synth_engine::{Q15, SynthEngine};
 
 use crate::build_config::BUILD_CONFIG;
 use crate::config::ConfigConsumer;
-use crate::midi_task::{MIDI_CHANNEL_SIZE, MIDI_TASK_CHANNEL};
+use crate::midi_task::{MIDI_CHANNEL_SIZE, MIDI_TASK_CHANNEL, TuningConsumer};
 
 #[cfg(feature = "audio-usb")]
 use crate::audio_task::{SampleBlock, USB_MAX_SAMPLE_COUNT};
@@ -28,10 +28,18 @@ const WINDOW_SIZE: usize = USB_MAX_SAMPLE_COUNT;
 const WINDOW_SIZE: usize = 48;
 
 // Config dimensions using additive pattern
-const BASE_PAGE_COUNT: usize = 2; // Pages 0-1: ADSR and Oscillator
+// Pages 0-3: ADSR, oscillator, noise and second oscillator
+// Pages 4-7: FM operators
+// Pages 8-12: Pulse and sub, phase, string, phase distortion and shaper
+// Pages 13-14: Envelope timing and curves
+// Pages 15-16: LFO and LFO sync
+// Pages 17-20: Modulation matrix slots
+// Pages 21-22: Modulation envelope
+// Pages 23-25: Velocity, key tracking and envelope loops
+const BASE_PAGE_COUNT: usize = 26;
 
 #[cfg(feature = "octave-filter")]
-const OCTAVE_FILTER_PAGE_COUNT: usize = 2; // Pages 2-3: Octave filter (6 bands)
+const OCTAVE_FILTER_PAGE_COUNT: usize = 2; // Pages 26-27: Octave filter (6 bands)
 #[cfg(not(feature = "octave-filter"))]
 const OCTAVE_FILTER_PAGE_COUNT: usize = 0;
 
@@ -84,9 +92,14 @@ pub static SYNTH_ENGINE_TASK_STATE: StaticCell<SynthEngineTaskState> = StaticCel
 #[cfg(feature = "audio-usb")]
 pub fn create_task(
     config_consumer: ConfigConsumer,
+    tuning_consumer: TuningConsumer,
     audio_sender: zerocopy_channel::Sender<'static, NoopRawMutex, SampleBlock>,
 ) -> SpawnToken<impl Sized> {
-    let synth_engine = SynthEngine::new(MIDI_TASK_CHANNEL.receiver(), config_consumer);
+    let synth_engine = SynthEngine::new(
+        MIDI_TASK_CHANNEL.receiver(),
+        config_consumer,
+        tuning_consumer,
+    );
 
     synth_engine_task(
         SYNTH_ENGINE_TASK_STATE.init(SynthEngineTaskState::new(synth_engine)),
@@ -95,8 +108,15 @@ pub fn create_task(
 }
 
 #[cfg(not(feature = "audio-usb"))]
-pub fn create_task(config_consumer: ConfigConsumer) -> SpawnToken<impl Sized> {
-    let synth_engine = SynthEngine::new(MIDI_TASK_CHANNEL.receiver(), config_consumer);
+pub fn create_task(
+    config_consumer: ConfigConsumer,
+    tuning_consumer: TuningConsumer,
+) -> SpawnToken<impl Sized> {
+    let synth_engine = SynthEngine::new(
+        MIDI_TASK_CHANNEL.receiver(),
+        config_consumer,
+        tuning_consumer,
+    );
 
     synth_engine_task(SYNTH_ENGINE_TASK_STATE.init(SynthEngineTaskState::new(synth_engine)))
 }
@@ -4,16 +4,16 @@ use cmsis_rust::CmsisRustOperations as Ops;
 use config::{Config, ConfigEvent, ConfigManager};
 use embassy_sync::{blocking_mutex::raw::NoopRawMutex, channel::Channel};
 use hound::{WavSpec, WavWriter};
-use midi::MidiEvent;
+use midi::{MidiEvent, TuningTable};
 use rand::{RngExt, SeedableRng};
 use std::f64::consts::PI;
 use std::path::PathBuf;
 use synth_engine::{Q15, SAMPLE_RATE, SynthEngine, WINDOW_SIZE};
 
 const CHANNEL_SIZE: usize = 256;
-const PAGE_AMOUNT: usize = 4; // Pages 0-1: ADSR/oscillator, Pages 2-3: Octave filter
+const PAGE_AMOUNT: usize = 28; // Pages 0-25: ADSR/oscillators/noise/FM/pulse/sub/phase/string/phase distortion/shaper/release/curves/LFO/modulation/velocity/key tracking/loops, Pages 26-27: Octave filter
 const ENCODER_AMOUNT: usize = 3;
-const OCTAVE_FILTER_FIRST_PAGE: usize = 2;
+const OCTAVE_FILTER_FIRST_PAGE: usize = 26;
 
 #[derive(Parser, Debug)]
 #[command(name = "Config Oscillation Demo")]
@@ -49,18 +49,53 @@ fn render_audio<const VOICE_COUNT: usize>(duration_sec: u32, seed: u64) -> Vec<i
                 values: [127, 127, 127],
             }, // Page 0: ADSR defaults
             config::Page { values: [0, 0, 0] }, // Page 1: Oscillator type = 0 (sine)
+            config::Page { values: [0, 0, 0] }, // Page 2: Noise off
+            config::Page { values: [0, 0, 0] }, // Page 3: Second oscillator
+            config::Page { values: [0, 0, 0] }, // Page 4: FM operator 1
+            config::Page { values: [0, 0, 0] }, // Page 5: FM operator 2
+            config::Page { values: [0, 0, 0] }, // Page 6: FM operator 3
+            config::Page { values: [0, 0, 0] }, // Page 7: FM operator 4
+            config::Page { values: [0, 0, 0] }, // Page 8: Pulse width and sub oscillator
+            config::Page { values: [0, 0, 0] }, // Page 9: Phase mode
+            config::Page { values: [0, 0, 0] }, // Page 10: String
+            config::Page { values: [0, 0, 0] }, // Page 11: Phase distortion
+            config::Page { values: [0, 0, 0] }, // Page 12: Shaper off
+            config::Page {
+                values: [127, 0, 0],
+            }, // Page 13: Release, delay and hold
+            config::Page { values: [0, 0, 0] }, // Page 14: Envelope curves
+            config::Page { values: [0, 0, 0] }, // Page 15: LFO off
+            config::Page { values: [0, 0, 0] }, // Page 16: LFO sync
+            config::Page { values: [0, 0, 0] }, // Page 17: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 18: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 19: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 20: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 21: Modulation envelope
+            config::Page { values: [0, 0, 0] }, // Page 22: Modulation envelope release
+            config::Page {
+                values: [0, 255, 0],
+            }, // Page 23: Linear velocity
+            config::Page { values: [0, 0, 0] }, // Page 24: No key tracking
+            config::Page { values: [0, 0, 0] }, // Page 25: No envelope loops
             config::Page {
                 values: [127, 127, 127],
-            }, // Page 2: Octave filter bands 0-2
+            }, // Page 10: Octave filter bands 0-2
             config::Page {
                 values: [127, 127, 127],
-            }, // Page 3: Octave filter bands 3-5
+            }, // Page 11: Octave filter bands 3-5
         ],
     };
     let mut config_buffer = TripleBuffer::new(initial_config, initial_config, initial_config);
     let (producer, consumer) = config_buffer.split_mut();
     let mut config_manager = ConfigManager::new(producer);
 
+    let mut tuning_buffer = TripleBuffer::new(
+        TuningTable::default(),
+        TuningTable::default(),
+        TuningTable::default(),
+    );
+    let (_tuning_producer, tuning_consumer) = tuning_buffer.split_mut();
+
     let mut synth_engine = SynthEngine::<
         '_,
         '_,
@@ -72,7 +107,7 @@ fn render_audio<const VOICE_COUNT: usize>(duration_sec: u32, seed: u64) -> Vec<i
         PAGE_AMOUNT,
         ENCODER_AMOUNT,
         OCTAVE_FILTER_FIRST_PAGE,
-    >::new(receiver, consumer);
+    >::new(receiver, consumer, tuning_consumer);
 
     let total_samples = duration_sec as usize * SAMPLE_RATE as usize;
     let mut output = Vec::with_capacity(total_samples);
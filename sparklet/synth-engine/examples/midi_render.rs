@@ -4,7 +4,7 @@ use cmsis_rust::CmsisRustOperations as Ops;
 use config::Config;
 use embassy_sync::{blocking_mutex::raw::NoopRawMutex, channel::Channel};
 use hound::{WavSpec, WavWriter};
-use midi::MidiEvent;
+use midi::{MidiEvent, TuningTable};
 use midly::{MetaMessage, MidiMessage, Smf, TrackEventKind};
 use std::fs;
 use std::path::PathBuf;
@@ -16,9 +16,9 @@ use synth_engine::wavetable::{
 use synth_engine::{Q15, SAMPLE_RATE, SynthEngine, WINDOW_SIZE};
 
 const CHANNEL_SIZE: usize = 256;
-const PAGE_AMOUNT: usize = 4;
+const PAGE_AMOUNT: usize = 28;
 const ENCODER_AMOUNT: usize = 3;
-const OCTAVE_FILTER_FIRST_PAGE: usize = 2;
+const OCTAVE_FILTER_FIRST_PAGE: usize = 26;
 
 #[derive(Parser, Debug)]
 #[command(name = "MIDI Renderer")]
@@ -30,8 +30,11 @@ struct Args {
     #[arg(long)]
     attack: u8,
 
-    #[arg(long, name = "decay-release")]
-    decay_release: u8,
+    #[arg(long)]
+    decay: u8,
+
+    #[arg(long)]
+    release: u8,
 
     #[arg(long)]
     sustain: u8,
@@ -61,8 +64,8 @@ fn main() {
     };
 
     println!(
-        "Rendering MIDI file with {} wavetable, attack={}, decay_release={}, sustain={}, voices={}",
-        args.wavetable, args.attack, args.decay_release, args.sustain, args.voices
+        "Rendering MIDI file with {} wavetable, attack={}, decay={}, release={}, sustain={}, voices={}",
+        args.wavetable, args.attack, args.decay, args.release, args.sustain, args.voices
     );
 
     let midi_data = fs::read(&args.midi).expect("Failed to read MIDI file");
@@ -104,7 +107,8 @@ fn main() {
             total_samples,
             wavetable,
             args.attack,
-            args.decay_release,
+            args.decay,
+            args.release,
             args.sustain,
         ),
         4 => render_audio::<4>(
@@ -112,7 +116,8 @@ fn main() {
             total_samples,
             wavetable,
             args.attack,
-            args.decay_release,
+            args.decay,
+            args.release,
             args.sustain,
         ),
         16 => render_audio::<16>(
@@ -120,7 +125,8 @@ fn main() {
             total_samples,
             wavetable,
             args.attack,
-            args.decay_release,
+            args.decay,
+            args.release,
             args.sustain,
         ),
         _ => {
@@ -214,7 +220,8 @@ fn render_audio<const VOICE_COUNT: usize>(
     total_samples: u64,
     wavetable: &'static [Q15; 256],
     attack: u8,
-    decay_release: u8,
+    decay: u8,
+    release: u8,
     sustain: u8,
 ) -> Vec<i16> {
     let channel = Channel::<NoopRawMutex, MidiEvent, CHANNEL_SIZE>::new();
@@ -233,23 +240,58 @@ fn render_audio<const VOICE_COUNT: usize>(
     let config = Config {
         pages: [
             config::Page {
-                values: [attack, sustain, decay_release],
+                values: [attack, sustain, decay],
             }, // Page 0: ADSR
             config::Page {
                 values: [osc_type, 0, 0],
             }, // Page 1: Oscillator type
+            config::Page { values: [0, 0, 0] }, // Page 2: Noise off
+            config::Page { values: [0, 0, 0] }, // Page 3: Second oscillator
+            config::Page { values: [0, 0, 0] }, // Page 4: FM operator 1
+            config::Page { values: [0, 0, 0] }, // Page 5: FM operator 2
+            config::Page { values: [0, 0, 0] }, // Page 6: FM operator 3
+            config::Page { values: [0, 0, 0] }, // Page 7: FM operator 4
+            config::Page { values: [0, 0, 0] }, // Page 8: Pulse width and sub oscillator
+            config::Page { values: [0, 0, 0] }, // Page 9: Phase mode
+            config::Page { values: [0, 0, 0] }, // Page 10: String
+            config::Page { values: [0, 0, 0] }, // Page 11: Phase distortion
+            config::Page { values: [0, 0, 0] }, // Page 12: Shaper off
+            config::Page {
+                values: [release, 0, 0],
+            }, // Page 13: Release, delay and hold
+            config::Page { values: [0, 0, 0] }, // Page 14: Envelope curves
+            config::Page { values: [0, 0, 0] }, // Page 15: LFO off
+            config::Page { values: [0, 0, 0] }, // Page 16: LFO sync
+            config::Page { values: [0, 0, 0] }, // Page 17: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 18: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 19: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 20: Modulation slot off
+            config::Page { values: [0, 0, 0] }, // Page 21: Modulation envelope
+            config::Page { values: [0, 0, 0] }, // Page 22: Modulation envelope release
+            config::Page {
+                values: [0, 255, 0],
+            }, // Page 23: Linear velocity
+            config::Page { values: [0, 0, 0] }, // Page 24: No key tracking
+            config::Page { values: [0, 0, 0] }, // Page 25: No envelope loops
             config::Page {
                 values: [200, 200, 200],
-            }, // Page 2: Octave filter bands 0-2
+            }, // Page 10: Octave filter bands 0-2
             config::Page {
                 values: [200, 200, 200],
-            }, // Page 3: Octave filter bands 3-5
+            }, // Page 11: Octave filter bands 3-5
         ],
     };
 
     let mut config_buffer = TripleBuffer::new(config, config, config);
     let (_producer, consumer) = config_buffer.split_mut();
 
+    let mut tuning_buffer = TripleBuffer::new(
+        TuningTable::default(),
+        TuningTable::default(),
+        TuningTable::default(),
+    );
+    let (_tuning_producer, tuning_consumer) = tuning_buffer.split_mut();
+
     let mut synth_engine = SynthEngine::<
         '_,
         '_,
@@ -261,7 +303,7 @@ fn render_audio<const VOICE_COUNT: usize>(
         PAGE_AMOUNT,
         ENCODER_AMOUNT,
         OCTAVE_FILTER_FIRST_PAGE,
-    >::new(receiver, consumer);
+    >::new(receiver, consumer, tuning_consumer);
 
     let mut output = Vec::with_capacity(total_samples as usize);
     let mut current_sample = 0u64;
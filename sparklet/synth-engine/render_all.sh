@@ -15,10 +15,15 @@ ATTACK[fast]=20
 ATTACK[mid]=40
 ATTACK[slow]=235
 
-declare -A DECAY_RELEASE
-DECAY_RELEASE[fast]=20
-DECAY_RELEASE[mid]=127
-DECAY_RELEASE[slow]=235
+declare -A DECAY
+DECAY[fast]=20
+DECAY[mid]=127
+DECAY[slow]=235
+
+declare -A RELEASE
+RELEASE[fast]=20
+RELEASE[mid]=127
+RELEASE[slow]=235
 
 # Arrays for iteration
 WAVETABLES=("sine" "square" "sawtooth" "triangle")
@@ -42,7 +47,8 @@ for wavetable in "${WAVETABLES[@]}"; do
             cargo run --release --example midi_render -- \
                 --wavetable "$wavetable" \
                 --attack "${ATTACK[$adsr]}" \
-                --decay-release "${DECAY_RELEASE[$adsr]}" \
+                --decay "${DECAY[$adsr]}" \
+                --release "${RELEASE[$adsr]}" \
                 --sustain "$SUSTAIN" \
                 --voices "$voices" \
                 --midi "$MIDI_FILE" \
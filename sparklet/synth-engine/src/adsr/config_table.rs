@@ -1,526 +1,544 @@
 // Autogenerated by generate_adsr_coefficient_tables
 
 use crate::adsr::BaseAndCoefficient;
+use crate::adsr::curve::CurveShape;
 
-use fixed::types::I1F31;
+use fixed::types::{I1F31, U4F28};
 
-// The first element from the fall_base_coefficient table
-pub static QUICK_FALL_BASE_COEFFICIENT: BaseAndCoefficient = BaseAndCoefficient{ base: I1F31::from_bits(0xffc8a9f4_u32 as i32), coefficient: I1F31::from_bits(0x7fe454fa_u32 as i32) };
+// Falls from full scale in ~10 ms, to free a voice whatever its release is
+pub static QUICK_FALL_BASE_COEFFICIENT: BaseAndCoefficient = BaseAndCoefficient{ base: I1F31::from_bits(0xffc8a9f4_u32 as i32), coefficient: I1F31::from_bits(0xffe454fa_u32 as i32) };
 
-pub static RISE_BASE_COEFFICIENT_TABLE: [BaseAndCoefficient; 256] = [
-    BaseAndCoefficient{ base: I1F31::from_bits(0x00b39def_u32 as i32), coefficient: I1F31::from_bits(0x7f5cb63e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00a69feb_u32 as i32), coefficient: I1F31::from_bits(0x7f6885e4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x009b2f9a_u32 as i32), coefficient: I1F31::from_bits(0x7f72ec00_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00910a1c_u32 as i32), coefficient: I1F31::from_bits(0x7f7c255b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0087fac5_u32 as i32), coefficient: I1F31::from_bits(0x7f8461d8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x007fd786_u32 as i32), coefficient: I1F31::from_bits(0x7f8bc7b4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00787e64_u32 as i32), coefficient: I1F31::from_bits(0x7f9275d4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0071d39a_u32 as i32), coefficient: I1F31::from_bits(0x7f988574_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x006bc043_u32 as i32), coefficient: I1F31::from_bits(0x7f9e0b66_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00663150_u32 as i32), coefficient: I1F31::from_bits(0x7fa318fe_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x006116c2_u32 as i32), coefficient: I1F31::from_bits(0x7fa7bcc4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x005c6316_u32 as i32), coefficient: I1F31::from_bits(0x7fac0303_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00580acc_u32 as i32), coefficient: I1F31::from_bits(0x7faff630_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0054040a_u32 as i32), coefficient: I1F31::from_bits(0x7fb39f3d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00504654_u32 as i32), coefficient: I1F31::from_bits(0x7fb705e2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x004cca52_u32 as i32), coefficient: I1F31::from_bits(0x7fba30cc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x004989a0_u32 as i32), coefficient: I1F31::from_bits(0x7fbd25cc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00467ea5_u32 as i32), coefficient: I1F31::from_bits(0x7fbfe9f6_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0043a478_u32 as i32), coefficient: I1F31::from_bits(0x7fc281c1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0040f6c7_u32 as i32), coefficient: I1F31::from_bits(0x7fc4f11d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x003e71ba_u32 as i32), coefficient: I1F31::from_bits(0x7fc73b85_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x003c11eb_u32 as i32), coefficient: I1F31::from_bits(0x7fc96413_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0039d451_u32 as i32), coefficient: I1F31::from_bits(0x7fcb6d88_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0037b634_u32 as i32), coefficient: I1F31::from_bits(0x7fcd5a5c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0035b526_u32 as i32), coefficient: I1F31::from_bits(0x7fcf2cc7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0033cef4_u32 as i32), coefficient: I1F31::from_bits(0x7fd0e6c5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x003201a6_u32 as i32), coefficient: I1F31::from_bits(0x7fd28a23_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00304b72_u32 as i32), coefficient: I1F31::from_bits(0x7fd41881_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x002eaab9_u32 as i32), coefficient: I1F31::from_bits(0x7fd59357_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x002d1e02_u32 as i32), coefficient: I1F31::from_bits(0x7fd6fbfe_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x002ba3f6_u32 as i32), coefficient: I1F31::from_bits(0x7fd853ac_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x002a3b5a_u32 as i32), coefficient: I1F31::from_bits(0x7fd99b80_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0028e311_u32 as i32), coefficient: I1F31::from_bits(0x7fdad47c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00279a13_u32 as i32), coefficient: I1F31::from_bits(0x7fdbff92_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00265f70_u32 as i32), coefficient: I1F31::from_bits(0x7fdd1d9a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00253249_u32 as i32), coefficient: I1F31::from_bits(0x7fde2f61_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x002411d2_u32 as i32), coefficient: I1F31::from_bits(0x7fdf359e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0022fd4e_u32 as i32), coefficient: I1F31::from_bits(0x7fe030ff_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0021f40f_u32 as i32), coefficient: I1F31::from_bits(0x7fe12221_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0020f574_u32 as i32), coefficient: I1F31::from_bits(0x7fe20997_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x002000e6_u32 as i32), coefficient: I1F31::from_bits(0x7fe2e7e9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x001f15db_u32 as i32), coefficient: I1F31::from_bits(0x7fe3bd96_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x001e33d1_u32 as i32), coefficient: I1F31::from_bits(0x7fe48b13_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x001d5a50_u32 as i32), coefficient: I1F31::from_bits(0x7fe550ce_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x001c88e9_u32 as i32), coefficient: I1F31::from_bits(0x7fe60f2c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x001bbf32_u32 as i32), coefficient: I1F31::from_bits(0x7fe6c68d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x001afcc9_u32 as i32), coefficient: I1F31::from_bits(0x7fe77749_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x001a4154_u32 as i32), coefficient: I1F31::from_bits(0x7fe821b4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00198c7c_u32 as i32), coefficient: I1F31::from_bits(0x7fe8c61b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0018ddf1_u32 as i32), coefficient: I1F31::from_bits(0x7fe964c8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00183569_u32 as i32), coefficient: I1F31::from_bits(0x7fe9fdfe_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0017929b_u32 as i32), coefficient: I1F31::from_bits(0x7fea91ff_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0016f546_u32 as i32), coefficient: I1F31::from_bits(0x7feb2106_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00165d2b_u32 as i32), coefficient: I1F31::from_bits(0x7febab4d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0015ca0f_u32 as i32), coefficient: I1F31::from_bits(0x7fec310a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00153bba_u32 as i32), coefficient: I1F31::from_bits(0x7fecb26e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0014b1f8_u32 as i32), coefficient: I1F31::from_bits(0x7fed2faa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00142c97_u32 as i32), coefficient: I1F31::from_bits(0x7feda8eb_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0013ab69_u32 as i32), coefficient: I1F31::from_bits(0x7fee1e5b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00132e41_u32 as i32), coefficient: I1F31::from_bits(0x7fee9022_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0012b4f5_u32 as i32), coefficient: I1F31::from_bits(0x7feefe67_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00123f5d_u32 as i32), coefficient: I1F31::from_bits(0x7fef694e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0011cd55_u32 as i32), coefficient: I1F31::from_bits(0x7fefd0f9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00115eb7_u32 as i32), coefficient: I1F31::from_bits(0x7ff03588_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0010f362_u32 as i32), coefficient: I1F31::from_bits(0x7ff0971b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00108b36_u32 as i32), coefficient: I1F31::from_bits(0x7ff0f5cf_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00102614_u32 as i32), coefficient: I1F31::from_bits(0x7ff151bf_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000fc3df_u32 as i32), coefficient: I1F31::from_bits(0x7ff1ab07_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000f647b_u32 as i32), coefficient: I1F31::from_bits(0x7ff201bf_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000f07ce_u32 as i32), coefficient: I1F31::from_bits(0x7ff255ff_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000eadbf_u32 as i32), coefficient: I1F31::from_bits(0x7ff2a7de_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000e5635_u32 as i32), coefficient: I1F31::from_bits(0x7ff2f773_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000e0119_u32 as i32), coefficient: I1F31::from_bits(0x7ff344d2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000dae57_u32 as i32), coefficient: I1F31::from_bits(0x7ff3900e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000d5dd8_u32 as i32), coefficient: I1F31::from_bits(0x7ff3d93b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000d0f89_u32 as i32), coefficient: I1F31::from_bits(0x7ff4206c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000cc357_u32 as i32), coefficient: I1F31::from_bits(0x7ff465b0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000c7930_u32 as i32), coefficient: I1F31::from_bits(0x7ff4a91a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000c3102_u32 as i32), coefficient: I1F31::from_bits(0x7ff4eab9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000beabc_u32 as i32), coefficient: I1F31::from_bits(0x7ff52a9b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000ba64e_u32 as i32), coefficient: I1F31::from_bits(0x7ff568d0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000b63a9_u32 as i32), coefficient: I1F31::from_bits(0x7ff5a566_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000b22be_u32 as i32), coefficient: I1F31::from_bits(0x7ff5e06a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000ae380_u32 as i32), coefficient: I1F31::from_bits(0x7ff619e9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000aa5e0_u32 as i32), coefficient: I1F31::from_bits(0x7ff651ef_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000a69d1_u32 as i32), coefficient: I1F31::from_bits(0x7ff68888_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000a2f48_u32 as i32), coefficient: I1F31::from_bits(0x7ff6bdbf_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0009f637_u32 as i32), coefficient: I1F31::from_bits(0x7ff6f19f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0009be94_u32 as i32), coefficient: I1F31::from_bits(0x7ff72434_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00098853_u32 as i32), coefficient: I1F31::from_bits(0x7ff75586_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0009536a_u32 as i32), coefficient: I1F31::from_bits(0x7ff785a0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00091fce_u32 as i32), coefficient: I1F31::from_bits(0x7ff7b48b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0008ed75_u32 as i32), coefficient: I1F31::from_bits(0x7ff7e24f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0008bc57_u32 as i32), coefficient: I1F31::from_bits(0x7ff80ef7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00088c69_u32 as i32), coefficient: I1F31::from_bits(0x7ff83a89_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00085da3_u32 as i32), coefficient: I1F31::from_bits(0x7ff8650e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00082ffd_u32 as i32), coefficient: I1F31::from_bits(0x7ff88e8e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0008036e_u32 as i32), coefficient: I1F31::from_bits(0x7ff8b711_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0007d7ee_u32 as i32), coefficient: I1F31::from_bits(0x7ff8de9c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0007ad75_u32 as i32), coefficient: I1F31::from_bits(0x7ff90538_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000783fd_u32 as i32), coefficient: I1F31::from_bits(0x7ff92aeb_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00075b7e_u32 as i32), coefficient: I1F31::from_bits(0x7ff94fbc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000733f1_u32 as i32), coefficient: I1F31::from_bits(0x7ff973b0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00070d50_u32 as i32), coefficient: I1F31::from_bits(0x7ff996cf_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0006e794_u32 as i32), coefficient: I1F31::from_bits(0x7ff9b91d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0006c2b6_u32 as i32), coefficient: I1F31::from_bits(0x7ff9daa0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00069eb2_u32 as i32), coefficient: I1F31::from_bits(0x7ff9fb5e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00067b81_u32 as i32), coefficient: I1F31::from_bits(0x7ffa1b5c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0006591d_u32 as i32), coefficient: I1F31::from_bits(0x7ffa3aa0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00063781_u32 as i32), coefficient: I1F31::from_bits(0x7ffa592e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000616a8_u32 as i32), coefficient: I1F31::from_bits(0x7ffa770a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0005f68d_u32 as i32), coefficient: I1F31::from_bits(0x7ffa943a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0005d72a_u32 as i32), coefficient: I1F31::from_bits(0x7ffab0c3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0005b87b_u32 as i32), coefficient: I1F31::from_bits(0x7ffacca7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00059a7c_u32 as i32), coefficient: I1F31::from_bits(0x7ffae7ed_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00057d27_u32 as i32), coefficient: I1F31::from_bits(0x7ffb0296_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00056079_u32 as i32), coefficient: I1F31::from_bits(0x7ffb1ca9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0005446e_u32 as i32), coefficient: I1F31::from_bits(0x7ffb3628_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00052900_u32 as i32), coefficient: I1F31::from_bits(0x7ffb4f17_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00050e2d_u32 as i32), coefficient: I1F31::from_bits(0x7ffb677a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0004f3f0_u32 as i32), coefficient: I1F31::from_bits(0x7ffb7f55_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0004da46_u32 as i32), coefficient: I1F31::from_bits(0x7ffb96a9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0004c12a_u32 as i32), coefficient: I1F31::from_bits(0x7ffbad7c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0004a89b_u32 as i32), coefficient: I1F31::from_bits(0x7ffbc3d0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00049093_u32 as i32), coefficient: I1F31::from_bits(0x7ffbd9a9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00047910_u32 as i32), coefficient: I1F31::from_bits(0x7ffbef08_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0004620f_u32 as i32), coefficient: I1F31::from_bits(0x7ffc03f2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00044b8c_u32 as i32), coefficient: I1F31::from_bits(0x7ffc1869_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00043585_u32 as i32), coefficient: I1F31::from_bits(0x7ffc2c70_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00041ff6_u32 as i32), coefficient: I1F31::from_bits(0x7ffc4009_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00040add_u32 as i32), coefficient: I1F31::from_bits(0x7ffc5337_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0003f636_u32 as i32), coefficient: I1F31::from_bits(0x7ffc65fd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0003e200_u32 as i32), coefficient: I1F31::from_bits(0x7ffc785d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0003ce37_u32 as i32), coefficient: I1F31::from_bits(0x7ffc8a5a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0003bad9_u32 as i32), coefficient: I1F31::from_bits(0x7ffc9bf5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0003a7e3_u32 as i32), coefficient: I1F31::from_bits(0x7ffcad32_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00039553_u32 as i32), coefficient: I1F31::from_bits(0x7ffcbe11_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00038327_u32 as i32), coefficient: I1F31::from_bits(0x7ffcce96_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0003715d_u32 as i32), coefficient: I1F31::from_bits(0x7ffcdec3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00035ff1_u32 as i32), coefficient: I1F31::from_bits(0x7ffcee99_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00034ee2_u32 as i32), coefficient: I1F31::from_bits(0x7ffcfe1b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00033e2e_u32 as i32), coefficient: I1F31::from_bits(0x7ffd0d4b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00032dd3_u32 as i32), coefficient: I1F31::from_bits(0x7ffd1c29_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00031dce_u32 as i32), coefficient: I1F31::from_bits(0x7ffd2ab9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00030e1e_u32 as i32), coefficient: I1F31::from_bits(0x7ffd38fc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002fec1_u32 as i32), coefficient: I1F31::from_bits(0x7ffd46f3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002efb5_u32 as i32), coefficient: I1F31::from_bits(0x7ffd54a1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002e0f8_u32 as i32), coefficient: I1F31::from_bits(0x7ffd6207_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002d289_u32 as i32), coefficient: I1F31::from_bits(0x7ffd6f27_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002c465_u32 as i32), coefficient: I1F31::from_bits(0x7ffd7c02_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002b68b_u32 as i32), coefficient: I1F31::from_bits(0x7ffd8899_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002a8f9_u32 as i32), coefficient: I1F31::from_bits(0x7ffd94ef_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00029baf_u32 as i32), coefficient: I1F31::from_bits(0x7ffda104_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00028ea9_u32 as i32), coefficient: I1F31::from_bits(0x7ffdacdb_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000281e7_u32 as i32), coefficient: I1F31::from_bits(0x7ffdb873_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00027568_u32 as i32), coefficient: I1F31::from_bits(0x7ffdc3d0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00026929_u32 as i32), coefficient: I1F31::from_bits(0x7ffdcef2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00025d2a_u32 as i32), coefficient: I1F31::from_bits(0x7ffdd9da_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00025168_u32 as i32), coefficient: I1F31::from_bits(0x7ffde48a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000245e3_u32 as i32), coefficient: I1F31::from_bits(0x7ffdef03_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00023a9a_u32 as i32), coefficient: I1F31::from_bits(0x7ffdf945_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00022f8b_u32 as i32), coefficient: I1F31::from_bits(0x7ffe0353_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000224b4_u32 as i32), coefficient: I1F31::from_bits(0x7ffe0d2e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00021a15_u32 as i32), coefficient: I1F31::from_bits(0x7ffe16d5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00020fad_u32 as i32), coefficient: I1F31::from_bits(0x7ffe204c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0002057a_u32 as i32), coefficient: I1F31::from_bits(0x7ffe2991_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001fb7b_u32 as i32), coefficient: I1F31::from_bits(0x7ffe32a8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001f1af_u32 as i32), coefficient: I1F31::from_bits(0x7ffe3b90_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001e815_u32 as i32), coefficient: I1F31::from_bits(0x7ffe444a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001deab_u32 as i32), coefficient: I1F31::from_bits(0x7ffe4cd8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001d572_u32 as i32), coefficient: I1F31::from_bits(0x7ffe553b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001cc68_u32 as i32), coefficient: I1F31::from_bits(0x7ffe5d73_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001c38b_u32 as i32), coefficient: I1F31::from_bits(0x7ffe6581_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001badc_u32 as i32), coefficient: I1F31::from_bits(0x7ffe6d67_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001b258_u32 as i32), coefficient: I1F31::from_bits(0x7ffe7524_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001aa00_u32 as i32), coefficient: I1F31::from_bits(0x7ffe7cbb_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001a1d1_u32 as i32), coefficient: I1F31::from_bits(0x7ffe842b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000199cc_u32 as i32), coefficient: I1F31::from_bits(0x7ffe8b75_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000191ef_u32 as i32), coefficient: I1F31::from_bits(0x7ffe929b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00018a3a_u32 as i32), coefficient: I1F31::from_bits(0x7ffe999c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000182ac_u32 as i32), coefficient: I1F31::from_bits(0x7ffea07b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00017b44_u32 as i32), coefficient: I1F31::from_bits(0x7ffea737_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00017401_u32 as i32), coefficient: I1F31::from_bits(0x7ffeadd1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00016ce2_u32 as i32), coefficient: I1F31::from_bits(0x7ffeb449_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000165e8_u32 as i32), coefficient: I1F31::from_bits(0x7ffebaa2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00015f10_u32 as i32), coefficient: I1F31::from_bits(0x7ffec0da_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001585a_u32 as i32), coefficient: I1F31::from_bits(0x7ffec6f4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000151c6_u32 as i32), coefficient: I1F31::from_bits(0x7ffeccef_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00014b53_u32 as i32), coefficient: I1F31::from_bits(0x7ffed2cc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00014500_u32 as i32), coefficient: I1F31::from_bits(0x7ffed88c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00013ecc_u32 as i32), coefficient: I1F31::from_bits(0x7ffede2f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000138b8_u32 as i32), coefficient: I1F31::from_bits(0x7ffee3b6_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000132c1_u32 as i32), coefficient: I1F31::from_bits(0x7ffee922_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00012ce9_u32 as i32), coefficient: I1F31::from_bits(0x7ffeee72_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001272d_u32 as i32), coefficient: I1F31::from_bits(0x7ffef3a8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001218e_u32 as i32), coefficient: I1F31::from_bits(0x7ffef8c4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00011c0b_u32 as i32), coefficient: I1F31::from_bits(0x7ffefdc7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000116a4_u32 as i32), coefficient: I1F31::from_bits(0x7fff02b1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00011157_u32 as i32), coefficient: I1F31::from_bits(0x7fff0783_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00010c24_u32 as i32), coefficient: I1F31::from_bits(0x7fff0c3c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001070b_u32 as i32), coefficient: I1F31::from_bits(0x7fff10df_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0001020b_u32 as i32), coefficient: I1F31::from_bits(0x7fff156a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000fd24_u32 as i32), coefficient: I1F31::from_bits(0x7fff19df_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000f856_u32 as i32), coefficient: I1F31::from_bits(0x7fff1e3e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000f39f_u32 as i32), coefficient: I1F31::from_bits(0x7fff2287_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000eeff_u32 as i32), coefficient: I1F31::from_bits(0x7fff26bb_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000ea76_u32 as i32), coefficient: I1F31::from_bits(0x7fff2ada_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000e604_u32 as i32), coefficient: I1F31::from_bits(0x7fff2ee5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000e1a7_u32 as i32), coefficient: I1F31::from_bits(0x7fff32dd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000dd60_u32 as i32), coefficient: I1F31::from_bits(0x7fff36c0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000d92e_u32 as i32), coefficient: I1F31::from_bits(0x7fff3a90_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000d510_u32 as i32), coefficient: I1F31::from_bits(0x7fff3e4e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000d107_u32 as i32), coefficient: I1F31::from_bits(0x7fff41f9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000cd12_u32 as i32), coefficient: I1F31::from_bits(0x7fff4593_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000c930_u32 as i32), coefficient: I1F31::from_bits(0x7fff491a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000c561_u32 as i32), coefficient: I1F31::from_bits(0x7fff4c90_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000c1a5_u32 as i32), coefficient: I1F31::from_bits(0x7fff4ff6_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000bdfb_u32 as i32), coefficient: I1F31::from_bits(0x7fff534a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000ba63_u32 as i32), coefficient: I1F31::from_bits(0x7fff568e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000b6dd_u32 as i32), coefficient: I1F31::from_bits(0x7fff59c3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000b368_u32 as i32), coefficient: I1F31::from_bits(0x7fff5ce7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000b004_u32 as i32), coefficient: I1F31::from_bits(0x7fff5ffc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000acb1_u32 as i32), coefficient: I1F31::from_bits(0x7fff6302_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000a96d_u32 as i32), coefficient: I1F31::from_bits(0x7fff65fa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000a63a_u32 as i32), coefficient: I1F31::from_bits(0x7fff68e2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000a317_u32 as i32), coefficient: I1F31::from_bits(0x7fff6bbd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000a002_u32 as i32), coefficient: I1F31::from_bits(0x7fff6e8a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00009cfd_u32 as i32), coefficient: I1F31::from_bits(0x7fff7148_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00009a07_u32 as i32), coefficient: I1F31::from_bits(0x7fff73fa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000971f_u32 as i32), coefficient: I1F31::from_bits(0x7fff769e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00009445_u32 as i32), coefficient: I1F31::from_bits(0x7fff7936_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00009179_u32 as i32), coefficient: I1F31::from_bits(0x7fff7bc1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00008eba_u32 as i32), coefficient: I1F31::from_bits(0x7fff7e3f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00008c0a_u32 as i32), coefficient: I1F31::from_bits(0x7fff80b1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00008966_u32 as i32), coefficient: I1F31::from_bits(0x7fff8318_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000086cf_u32 as i32), coefficient: I1F31::from_bits(0x7fff8573_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00008444_u32 as i32), coefficient: I1F31::from_bits(0x7fff87c2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000081c6_u32 as i32), coefficient: I1F31::from_bits(0x7fff8a06_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00007f54_u32 as i32), coefficient: I1F31::from_bits(0x7fff8c3f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00007cef_u32 as i32), coefficient: I1F31::from_bits(0x7fff8e6d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00007a94_u32 as i32), coefficient: I1F31::from_bits(0x7fff9091_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00007845_u32 as i32), coefficient: I1F31::from_bits(0x7fff92aa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00007602_u32 as i32), coefficient: I1F31::from_bits(0x7fff94b9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x000073c9_u32 as i32), coefficient: I1F31::from_bits(0x7fff96be_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000719b_u32 as i32), coefficient: I1F31::from_bits(0x7fff98b9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00006f78_u32 as i32), coefficient: I1F31::from_bits(0x7fff9aaa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00006d5f_u32 as i32), coefficient: I1F31::from_bits(0x7fff9c93_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00006b50_u32 as i32), coefficient: I1F31::from_bits(0x7fff9e71_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000694b_u32 as i32), coefficient: I1F31::from_bits(0x7fffa047_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00006750_u32 as i32), coefficient: I1F31::from_bits(0x7fffa214_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x0000655f_u32 as i32), coefficient: I1F31::from_bits(0x7fffa3d8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00006377_u32 as i32), coefficient: I1F31::from_bits(0x7fffa594_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00006198_u32 as i32), coefficient: I1F31::from_bits(0x7fffa747_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00005fc2_u32 as i32), coefficient: I1F31::from_bits(0x7fffa8f2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00005df6_u32 as i32), coefficient: I1F31::from_bits(0x7fffaa95_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0x00005c31_u32 as i32), coefficient: I1F31::from_bits(0x7fffac30_u32 as i32) }
+// Samples the attack takes to rise from silence to full scale
+pub static RISE_TIME_TABLE: [u32; 256] = [480, 518, 556, 595, 634, 675, 716, 758, 801, 845, 889, 934, 981, 1028, 1075, 1124, 1174, 1225, 1277, 1329, 1383, 1438, 1493, 1550, 1608, 1667, 1727, 1788, 1851, 1915, 1979, 2045, 2113, 2181, 2251, 2322, 2395, 2469, 2544, 2621, 2699, 2779, 2861, 2943, 3028, 3114, 3201, 3291, 3382, 3475, 3569, 3665, 3764, 3864, 3966, 4069, 4175, 4283, 4393, 4505, 4619, 4735, 4854, 4975, 5098, 5223, 5351, 5481, 5614, 5749, 5887, 6027, 6171, 6316, 6465, 6616, 6771, 6928, 7088, 7252, 7418, 7588, 7760, 7937, 8116, 8299, 8485, 8675, 8869, 9066, 9267, 9472, 9680, 9893, 10110, 10330, 10555, 10785, 11018, 11256, 11499, 11746, 11998, 12255, 12517, 12783, 13055, 13332, 13614, 13902, 14195, 14493, 14798, 15108, 15424, 15745, 16074, 16408, 16749, 17096, 17450, 17810, 18178, 18552, 18933, 19322, 19718, 20122, 20533, 20953, 21380, 21815, 22259, 22711, 23172, 23641, 24119, 24607, 25104, 25610, 26126, 26651, 27187, 27733, 28289, 28856, 29434, 30022, 30622, 31234, 31856, 32491, 33138, 33797, 34469, 35153, 35851, 36562, 37286, 38024, 38776, 39543, 40324, 41120, 41931, 42757, 43600, 44458, 45333, 46224, 47132, 48058, 49001, 49962, 50941, 51939, 52956, 53993, 55049, 56125, 57222, 58339, 59478, 60639, 61821, 63026, 64254, 65506, 66781, 68081, 69405, 70754, 72129, 73531, 74959, 76414, 77897, 79408, 80948, 82517, 84116, 85746, 87406, 89098, 90823, 92580, 94370, 96195, 98054, 99949, 101880, 103848, 105853, 107896, 109978, 112100, 114262, 116465, 118711, 120999, 123330, 125706, 128127, 130595, 133109, 135671, 138282, 140942, 143653, 146416, 149231, 152100, 155024, 158003, 161039, 164133, 167285, 170498, 173772, 177108, 180507, 183971, 187502, 191099, 194765, 198501, 202307, 206187, 210140, 214168, 218273, 222456, 226719, 231063, 235489, 240000];
+
+// Samples the decay and release take to fall from full scale to silence
+pub static FALL_TIME_TABLE: [u32; 256] = [480, 511, 542, 574, 607, 641, 676, 711, 747, 784, 822, 861, 901, 941, 983, 1025, 1069, 1113, 1159, 1206, 1253, 1302, 1352, 1404, 1456, 1510, 1564, 1621, 1678, 1737, 1797, 1859, 1922, 1986, 2052, 2120, 2189, 2260, 2332, 2406, 2482, 2560, 2639, 2721, 2804, 2889, 2976, 3065, 3157, 3250, 3346, 3444, 3544, 3646, 3751, 3859, 3969, 4081, 4196, 4314, 4434, 4558, 4684, 4813, 4946, 5081, 5219, 5361, 5506, 5655, 5807, 5962, 6121, 6284, 6451, 6621, 6796, 6975, 7157, 7345, 7536, 7732, 7933, 8138, 8348, 8563, 8783, 9008, 9239, 9475, 9716, 9963, 10216, 10475, 10740, 11011, 11288, 11572, 11862, 12160, 12464, 12775, 13094, 13420, 13754, 14096, 14445, 14803, 15169, 15544, 15928, 16320, 16722, 17133, 17554, 17984, 18425, 18876, 19337, 19810, 20293, 20788, 21294, 21812, 22343, 22886, 23441, 24009, 24591, 25187, 25796, 26420, 27058, 27711, 28379, 29064, 29764, 30480, 31214, 31964, 32732, 33518, 34323, 35146, 35988, 36851, 37733, 38636, 39561, 40507, 41475, 42465, 43479, 44517, 45579, 46666, 47778, 48917, 50082, 51274, 52494, 53743, 55021, 56329, 57668, 59038, 60440, 61875, 63344, 64846, 66384, 67959, 69570, 71218, 72906, 74632, 76400, 78208, 80059, 81954, 83892, 85876, 87907, 89985, 92112, 94289, 96516, 98796, 101129, 103517, 105960, 108461, 111021, 113640, 116321, 119064, 121872, 124746, 127687, 130696, 133776, 136929, 140155, 143457, 146836, 150294, 153833, 157455, 161161, 164955, 168838, 172811, 176877, 181039, 185298, 189657, 194118, 198683, 203355, 208137, 213031, 218039, 223165, 228410, 233779, 239273, 244896, 250650, 256539, 262566, 268735, 275047, 281508, 288120, 294887, 301812, 308899, 316152, 323576, 331173, 338947, 346904, 355048, 363382, 371911, 380640, 389573, 398716, 408072, 417648, 427448, 437478, 447742, 458247, 468998, 480000];
+
+// From the RC curve at 0, through a straight line at 128, to the RC curve mirrored at 255
+pub static RISE_CURVE_TABLE: [CurveShape; 256] = [
+    CurveShape{ bend: U4F28::from_bits(0x265dc76f), slow: U4F28::from_bits(0x03d62d8b) },
+CurveShape{ bend: U4F28::from_bits(0x26110be0), slow: U4F28::from_bits(0x03e2d059) },
+CurveShape{ bend: U4F28::from_bits(0x25c45051), slow: U4F28::from_bits(0x03ef96f5) },
+CurveShape{ bend: U4F28::from_bits(0x257794c2), slow: U4F28::from_bits(0x03fc81ab) },
+CurveShape{ bend: U4F28::from_bits(0x252ad934), slow: U4F28::from_bits(0x040990c4) },
+CurveShape{ bend: U4F28::from_bits(0x24de1da5), slow: U4F28::from_bits(0x0416c48a) },
+CurveShape{ bend: U4F28::from_bits(0x24916216), slow: U4F28::from_bits(0x04241d48) },
+CurveShape{ bend: U4F28::from_bits(0x2444a687), slow: U4F28::from_bits(0x04319b48) },
+CurveShape{ bend: U4F28::from_bits(0x23f7eaf8), slow: U4F28::from_bits(0x043f3ed6) },
+CurveShape{ bend: U4F28::from_bits(0x23ab2f69), slow: U4F28::from_bits(0x044d083a) },
+CurveShape{ bend: U4F28::from_bits(0x235e73da), slow: U4F28::from_bits(0x045af7c0) },
+CurveShape{ bend: U4F28::from_bits(0x2311b84b), slow: U4F28::from_bits(0x04690db3) },
+CurveShape{ bend: U4F28::from_bits(0x22c4fcbd), slow: U4F28::from_bits(0x04774a5e) },
+CurveShape{ bend: U4F28::from_bits(0x2278412e), slow: U4F28::from_bits(0x0485ae0a) },
+CurveShape{ bend: U4F28::from_bits(0x222b859f), slow: U4F28::from_bits(0x04943904) },
+CurveShape{ bend: U4F28::from_bits(0x21deca10), slow: U4F28::from_bits(0x04a2eb95) },
+CurveShape{ bend: U4F28::from_bits(0x21920e81), slow: U4F28::from_bits(0x04b1c609) },
+CurveShape{ bend: U4F28::from_bits(0x214552f2), slow: U4F28::from_bits(0x04c0c8aa) },
+CurveShape{ bend: U4F28::from_bits(0x20f89763), slow: U4F28::from_bits(0x04cff3c3) },
+CurveShape{ bend: U4F28::from_bits(0x20abdbd5), slow: U4F28::from_bits(0x04df479f) },
+CurveShape{ bend: U4F28::from_bits(0x205f2046), slow: U4F28::from_bits(0x04eec487) },
+CurveShape{ bend: U4F28::from_bits(0x201264b7), slow: U4F28::from_bits(0x04fe6ac8) },
+CurveShape{ bend: U4F28::from_bits(0x1fc5a928), slow: U4F28::from_bits(0x050e3aab) },
+CurveShape{ bend: U4F28::from_bits(0x1f78ed99), slow: U4F28::from_bits(0x051e347b) },
+CurveShape{ bend: U4F28::from_bits(0x1f2c320a), slow: U4F28::from_bits(0x052e5883) },
+CurveShape{ bend: U4F28::from_bits(0x1edf767b), slow: U4F28::from_bits(0x053ea70b) },
+CurveShape{ bend: U4F28::from_bits(0x1e92baec), slow: U4F28::from_bits(0x054f2060) },
+CurveShape{ bend: U4F28::from_bits(0x1e45ff5e), slow: U4F28::from_bits(0x055fc4c9) },
+CurveShape{ bend: U4F28::from_bits(0x1df943cf), slow: U4F28::from_bits(0x05709492) },
+CurveShape{ bend: U4F28::from_bits(0x1dac8840), slow: U4F28::from_bits(0x05819005) },
+CurveShape{ bend: U4F28::from_bits(0x1d5fccb1), slow: U4F28::from_bits(0x0592b76a) },
+CurveShape{ bend: U4F28::from_bits(0x1d131122), slow: U4F28::from_bits(0x05a40b0a) },
+CurveShape{ bend: U4F28::from_bits(0x1cc65593), slow: U4F28::from_bits(0x05b58b31) },
+CurveShape{ bend: U4F28::from_bits(0x1c799a04), slow: U4F28::from_bits(0x05c73825) },
+CurveShape{ bend: U4F28::from_bits(0x1c2cde76), slow: U4F28::from_bits(0x05d91230) },
+CurveShape{ bend: U4F28::from_bits(0x1be022e7), slow: U4F28::from_bits(0x05eb199b) },
+CurveShape{ bend: U4F28::from_bits(0x1b936758), slow: U4F28::from_bits(0x05fd4eae) },
+CurveShape{ bend: U4F28::from_bits(0x1b46abc9), slow: U4F28::from_bits(0x060fb1b0) },
+CurveShape{ bend: U4F28::from_bits(0x1af9f03a), slow: U4F28::from_bits(0x062242ea) },
+CurveShape{ bend: U4F28::from_bits(0x1aad34ab), slow: U4F28::from_bits(0x063502a4) },
+CurveShape{ bend: U4F28::from_bits(0x1a60791c), slow: U4F28::from_bits(0x0647f124) },
+CurveShape{ bend: U4F28::from_bits(0x1a13bd8d), slow: U4F28::from_bits(0x065b0eb1) },
+CurveShape{ bend: U4F28::from_bits(0x19c701ff), slow: U4F28::from_bits(0x066e5b93) },
+CurveShape{ bend: U4F28::from_bits(0x197a4670), slow: U4F28::from_bits(0x0681d80f) },
+CurveShape{ bend: U4F28::from_bits(0x192d8ae1), slow: U4F28::from_bits(0x0695846b) },
+CurveShape{ bend: U4F28::from_bits(0x18e0cf52), slow: U4F28::from_bits(0x06a960ed) },
+CurveShape{ bend: U4F28::from_bits(0x189413c3), slow: U4F28::from_bits(0x06bd6dda) },
+CurveShape{ bend: U4F28::from_bits(0x18475834), slow: U4F28::from_bits(0x06d1ab78) },
+CurveShape{ bend: U4F28::from_bits(0x17fa9ca5), slow: U4F28::from_bits(0x06e61a0a) },
+CurveShape{ bend: U4F28::from_bits(0x17ade117), slow: U4F28::from_bits(0x06fab9d5) },
+CurveShape{ bend: U4F28::from_bits(0x17612588), slow: U4F28::from_bits(0x070f8b1c) },
+CurveShape{ bend: U4F28::from_bits(0x171469f9), slow: U4F28::from_bits(0x07248e23) },
+CurveShape{ bend: U4F28::from_bits(0x16c7ae6a), slow: U4F28::from_bits(0x0739c32c) },
+CurveShape{ bend: U4F28::from_bits(0x167af2db), slow: U4F28::from_bits(0x074f2a7a) },
+CurveShape{ bend: U4F28::from_bits(0x162e374c), slow: U4F28::from_bits(0x0764c44f) },
+CurveShape{ bend: U4F28::from_bits(0x15e17bbd), slow: U4F28::from_bits(0x077a90eb) },
+CurveShape{ bend: U4F28::from_bits(0x1594c02e), slow: U4F28::from_bits(0x07909091) },
+CurveShape{ bend: U4F28::from_bits(0x154804a0), slow: U4F28::from_bits(0x07a6c380) },
+CurveShape{ bend: U4F28::from_bits(0x14fb4911), slow: U4F28::from_bits(0x07bd29f9) },
+CurveShape{ bend: U4F28::from_bits(0x14ae8d82), slow: U4F28::from_bits(0x07d3c43a) },
+CurveShape{ bend: U4F28::from_bits(0x1461d1f3), slow: U4F28::from_bits(0x07ea9283) },
+CurveShape{ bend: U4F28::from_bits(0x14151664), slow: U4F28::from_bits(0x08019511) },
+CurveShape{ bend: U4F28::from_bits(0x13c85ad5), slow: U4F28::from_bits(0x0818cc23) },
+CurveShape{ bend: U4F28::from_bits(0x137b9f46), slow: U4F28::from_bits(0x083037f5) },
+CurveShape{ bend: U4F28::from_bits(0x132ee3b7), slow: U4F28::from_bits(0x0847d8c4) },
+CurveShape{ bend: U4F28::from_bits(0x12e22829), slow: U4F28::from_bits(0x085faecc) },
+CurveShape{ bend: U4F28::from_bits(0x12956c9a), slow: U4F28::from_bits(0x0877ba49) },
+CurveShape{ bend: U4F28::from_bits(0x1248b10b), slow: U4F28::from_bits(0x088ffb74) },
+CurveShape{ bend: U4F28::from_bits(0x11fbf57c), slow: U4F28::from_bits(0x08a87288) },
+CurveShape{ bend: U4F28::from_bits(0x11af39ed), slow: U4F28::from_bits(0x08c11fbe) },
+CurveShape{ bend: U4F28::from_bits(0x11627e5e), slow: U4F28::from_bits(0x08da034f) },
+CurveShape{ bend: U4F28::from_bits(0x1115c2cf), slow: U4F28::from_bits(0x08f31d73) },
+CurveShape{ bend: U4F28::from_bits(0x10c90741), slow: U4F28::from_bits(0x090c6e60) },
+CurveShape{ bend: U4F28::from_bits(0x107c4bb2), slow: U4F28::from_bits(0x0925f64f) },
+CurveShape{ bend: U4F28::from_bits(0x102f9023), slow: U4F28::from_bits(0x093fb573) },
+CurveShape{ bend: U4F28::from_bits(0x0fe2d494), slow: U4F28::from_bits(0x0959ac04) },
+CurveShape{ bend: U4F28::from_bits(0x0f961905), slow: U4F28::from_bits(0x0973da34) },
+CurveShape{ bend: U4F28::from_bits(0x0f495d76), slow: U4F28::from_bits(0x098e4038) },
+CurveShape{ bend: U4F28::from_bits(0x0efca1e7), slow: U4F28::from_bits(0x09a8de43) },
+CurveShape{ bend: U4F28::from_bits(0x0eafe658), slow: U4F28::from_bits(0x09c3b486) },
+CurveShape{ bend: U4F28::from_bits(0x0e632aca), slow: U4F28::from_bits(0x09dec333) },
+CurveShape{ bend: U4F28::from_bits(0x0e166f3b), slow: U4F28::from_bits(0x09fa0a7a) },
+CurveShape{ bend: U4F28::from_bits(0x0dc9b3ac), slow: U4F28::from_bits(0x0a158a8b) },
+CurveShape{ bend: U4F28::from_bits(0x0d7cf81d), slow: U4F28::from_bits(0x0a314395) },
+CurveShape{ bend: U4F28::from_bits(0x0d303c8e), slow: U4F28::from_bits(0x0a4d35c6) },
+CurveShape{ bend: U4F28::from_bits(0x0ce380ff), slow: U4F28::from_bits(0x0a69614b) },
+CurveShape{ bend: U4F28::from_bits(0x0c96c570), slow: U4F28::from_bits(0x0a85c650) },
+CurveShape{ bend: U4F28::from_bits(0x0c4a09e2), slow: U4F28::from_bits(0x0aa26502) },
+CurveShape{ bend: U4F28::from_bits(0x0bfd4e53), slow: U4F28::from_bits(0x0abf3d89) },
+CurveShape{ bend: U4F28::from_bits(0x0bb092c4), slow: U4F28::from_bits(0x0adc5010) },
+CurveShape{ bend: U4F28::from_bits(0x0b63d735), slow: U4F28::from_bits(0x0af99cc1) },
+CurveShape{ bend: U4F28::from_bits(0x0b171ba6), slow: U4F28::from_bits(0x0b1723c2) },
+CurveShape{ bend: U4F28::from_bits(0x0aca6017), slow: U4F28::from_bits(0x0b34e53b) },
+CurveShape{ bend: U4F28::from_bits(0x0a7da488), slow: U4F28::from_bits(0x0b52e151) },
+CurveShape{ bend: U4F28::from_bits(0x0a30e8f9), slow: U4F28::from_bits(0x0b71182b) },
+CurveShape{ bend: U4F28::from_bits(0x09e42d6b), slow: U4F28::from_bits(0x0b8f89ec) },
+CurveShape{ bend: U4F28::from_bits(0x099771dc), slow: U4F28::from_bits(0x0bae36b7) },
+CurveShape{ bend: U4F28::from_bits(0x094ab64d), slow: U4F28::from_bits(0x0bcd1eae) },
+CurveShape{ bend: U4F28::from_bits(0x08fdfabe), slow: U4F28::from_bits(0x0bec41f4) },
+CurveShape{ bend: U4F28::from_bits(0x08b13f2f), slow: U4F28::from_bits(0x0c0ba0a7) },
+CurveShape{ bend: U4F28::from_bits(0x086483a0), slow: U4F28::from_bits(0x0c2b3ae9) },
+CurveShape{ bend: U4F28::from_bits(0x0817c811), slow: U4F28::from_bits(0x0c4b10d6) },
+CurveShape{ bend: U4F28::from_bits(0x07cb0c83), slow: U4F28::from_bits(0x0c6b228d) },
+CurveShape{ bend: U4F28::from_bits(0x077e50f4), slow: U4F28::from_bits(0x0c8b7029) },
+CurveShape{ bend: U4F28::from_bits(0x07319565), slow: U4F28::from_bits(0x0cabf9c7) },
+CurveShape{ bend: U4F28::from_bits(0x06e4d9d6), slow: U4F28::from_bits(0x0cccbf7f) },
+CurveShape{ bend: U4F28::from_bits(0x06981e47), slow: U4F28::from_bits(0x0cedc16d) },
+CurveShape{ bend: U4F28::from_bits(0x064b62b8), slow: U4F28::from_bits(0x0d0effa7) },
+CurveShape{ bend: U4F28::from_bits(0x05fea729), slow: U4F28::from_bits(0x0d307a46) },
+CurveShape{ bend: U4F28::from_bits(0x05b1eb9a), slow: U4F28::from_bits(0x0d52315f) },
+CurveShape{ bend: U4F28::from_bits(0x0565300c), slow: U4F28::from_bits(0x0d742507) },
+CurveShape{ bend: U4F28::from_bits(0x0518747d), slow: U4F28::from_bits(0x0d965554) },
+CurveShape{ bend: U4F28::from_bits(0x04cbb8ee), slow: U4F28::from_bits(0x0db8c256) },
+CurveShape{ bend: U4F28::from_bits(0x047efd5f), slow: U4F28::from_bits(0x0ddb6c22) },
+CurveShape{ bend: U4F28::from_bits(0x043241d0), slow: U4F28::from_bits(0x0dfe52c7) },
+CurveShape{ bend: U4F28::from_bits(0x03e58641), slow: U4F28::from_bits(0x0e217656) },
+CurveShape{ bend: U4F28::from_bits(0x0398cab2), slow: U4F28::from_bits(0x0e44d6dc) },
+CurveShape{ bend: U4F28::from_bits(0x034c0f24), slow: U4F28::from_bits(0x0e687469) },
+CurveShape{ bend: U4F28::from_bits(0x02ff5395), slow: U4F28::from_bits(0x0e8c4f08) },
+CurveShape{ bend: U4F28::from_bits(0x02b29806), slow: U4F28::from_bits(0x0eb066c5) },
+CurveShape{ bend: U4F28::from_bits(0x0265dc77), slow: U4F28::from_bits(0x0ed4bbab) },
+CurveShape{ bend: U4F28::from_bits(0x021920e8), slow: U4F28::from_bits(0x0ef94dc2) },
+CurveShape{ bend: U4F28::from_bits(0x01cc6559), slow: U4F28::from_bits(0x0f1e1d13) },
+CurveShape{ bend: U4F28::from_bits(0x017fa9ca), slow: U4F28::from_bits(0x0f4329a5) },
+CurveShape{ bend: U4F28::from_bits(0x0132ee3b), slow: U4F28::from_bits(0x0f68737f) },
+CurveShape{ bend: U4F28::from_bits(0x00e632ad), slow: U4F28::from_bits(0x0f8dfaa5) },
+CurveShape{ bend: U4F28::from_bits(0x0099771e), slow: U4F28::from_bits(0x0fb3bf1b) },
+CurveShape{ bend: U4F28::from_bits(0x004cbb8f), slow: U4F28::from_bits(0x0fd9c0e3) },
+CurveShape{ bend: U4F28::from_bits(0x00000000), slow: U4F28::from_bits(0x10000000) },
+CurveShape{ bend: U4F28::from_bits(0x004d563b), slow: U4F28::from_bits(0x0fd97409) },
+CurveShape{ bend: U4F28::from_bits(0x009aac77), slow: U4F28::from_bits(0x0fb3265f) },
+CurveShape{ bend: U4F28::from_bits(0x00e802b2), slow: U4F28::from_bits(0x0f8d16ff) },
+CurveShape{ bend: U4F28::from_bits(0x013558ed), slow: U4F28::from_bits(0x0f6745e8) },
+CurveShape{ bend: U4F28::from_bits(0x0182af29), slow: U4F28::from_bits(0x0f41b314) },
+CurveShape{ bend: U4F28::from_bits(0x01d00564), slow: U4F28::from_bits(0x0f1c5e80) },
+CurveShape{ bend: U4F28::from_bits(0x021d5b9f), slow: U4F28::from_bits(0x0ef74824) },
+CurveShape{ bend: U4F28::from_bits(0x026ab1db), slow: U4F28::from_bits(0x0ed26ffa) },
+CurveShape{ bend: U4F28::from_bits(0x02b80816), slow: U4F28::from_bits(0x0eadd5f9) },
+CurveShape{ bend: U4F28::from_bits(0x03055e51), slow: U4F28::from_bits(0x0e897a17) },
+CurveShape{ bend: U4F28::from_bits(0x0352b48d), slow: U4F28::from_bits(0x0e655c49) },
+CurveShape{ bend: U4F28::from_bits(0x03a00ac8), slow: U4F28::from_bits(0x0e417c84) },
+CurveShape{ bend: U4F28::from_bits(0x03ed6103), slow: U4F28::from_bits(0x0e1ddabb) },
+CurveShape{ bend: U4F28::from_bits(0x043ab73f), slow: U4F28::from_bits(0x0dfa76df) },
+CurveShape{ bend: U4F28::from_bits(0x04880d7a), slow: U4F28::from_bits(0x0dd750e1) },
+CurveShape{ bend: U4F28::from_bits(0x04d563b5), slow: U4F28::from_bits(0x0db468b1) },
+CurveShape{ bend: U4F28::from_bits(0x0522b9f1), slow: U4F28::from_bits(0x0d91be3e) },
+CurveShape{ bend: U4F28::from_bits(0x0570102c), slow: U4F28::from_bits(0x0d6f5174) },
+CurveShape{ bend: U4F28::from_bits(0x05bd6667), slow: U4F28::from_bits(0x0d4d2241) },
+CurveShape{ bend: U4F28::from_bits(0x060abca3), slow: U4F28::from_bits(0x0d2b308f) },
+CurveShape{ bend: U4F28::from_bits(0x065812de), slow: U4F28::from_bits(0x0d097c49) },
+CurveShape{ bend: U4F28::from_bits(0x06a56919), slow: U4F28::from_bits(0x0ce80558) },
+CurveShape{ bend: U4F28::from_bits(0x06f2bf55), slow: U4F28::from_bits(0x0cc6cba3) },
+CurveShape{ bend: U4F28::from_bits(0x07401590), slow: U4F28::from_bits(0x0ca5cf13) },
+CurveShape{ bend: U4F28::from_bits(0x078d6bcb), slow: U4F28::from_bits(0x0c850f8b) },
+CurveShape{ bend: U4F28::from_bits(0x07dac207), slow: U4F28::from_bits(0x0c648cf3) },
+CurveShape{ bend: U4F28::from_bits(0x08281842), slow: U4F28::from_bits(0x0c44472d) },
+CurveShape{ bend: U4F28::from_bits(0x08756e7d), slow: U4F28::from_bits(0x0c243e1c) },
+CurveShape{ bend: U4F28::from_bits(0x08c2c4b9), slow: U4F28::from_bits(0x0c0471a1) },
+CurveShape{ bend: U4F28::from_bits(0x09101af4), slow: U4F28::from_bits(0x0be4e19e) },
+CurveShape{ bend: U4F28::from_bits(0x095d712f), slow: U4F28::from_bits(0x0bc58df2) },
+CurveShape{ bend: U4F28::from_bits(0x09aac76b), slow: U4F28::from_bits(0x0ba6767c) },
+CurveShape{ bend: U4F28::from_bits(0x09f81da6), slow: U4F28::from_bits(0x0b879b1a) },
+CurveShape{ bend: U4F28::from_bits(0x0a4573e1), slow: U4F28::from_bits(0x0b68fba7) },
+CurveShape{ bend: U4F28::from_bits(0x0a92ca1d), slow: U4F28::from_bits(0x0b4a9800) },
+CurveShape{ bend: U4F28::from_bits(0x0ae02058), slow: U4F28::from_bits(0x0b2c7000) },
+CurveShape{ bend: U4F28::from_bits(0x0b2d7693), slow: U4F28::from_bits(0x0b0e8380) },
+CurveShape{ bend: U4F28::from_bits(0x0b7acccf), slow: U4F28::from_bits(0x0af0d258) },
+CurveShape{ bend: U4F28::from_bits(0x0bc8230a), slow: U4F28::from_bits(0x0ad35c62) },
+CurveShape{ bend: U4F28::from_bits(0x0c157945), slow: U4F28::from_bits(0x0ab62173) },
+CurveShape{ bend: U4F28::from_bits(0x0c62cf81), slow: U4F28::from_bits(0x0a992162) },
+CurveShape{ bend: U4F28::from_bits(0x0cb025bc), slow: U4F28::from_bits(0x0a7c5c03) },
+CurveShape{ bend: U4F28::from_bits(0x0cfd7bf7), slow: U4F28::from_bits(0x0a5fd12b) },
+CurveShape{ bend: U4F28::from_bits(0x0d4ad233), slow: U4F28::from_bits(0x0a4380ad) },
+CurveShape{ bend: U4F28::from_bits(0x0d98286e), slow: U4F28::from_bits(0x0a276a5b) },
+CurveShape{ bend: U4F28::from_bits(0x0de57ea9), slow: U4F28::from_bits(0x0a0b8e07) },
+CurveShape{ bend: U4F28::from_bits(0x0e32d4e5), slow: U4F28::from_bits(0x09efeb81) },
+CurveShape{ bend: U4F28::from_bits(0x0e802b20), slow: U4F28::from_bits(0x09d48299) },
+CurveShape{ bend: U4F28::from_bits(0x0ecd815b), slow: U4F28::from_bits(0x09b9531f) },
+CurveShape{ bend: U4F28::from_bits(0x0f1ad797), slow: U4F28::from_bits(0x099e5cdf) },
+CurveShape{ bend: U4F28::from_bits(0x0f682dd2), slow: U4F28::from_bits(0x09839fa7) },
+CurveShape{ bend: U4F28::from_bits(0x0fb5840d), slow: U4F28::from_bits(0x09691b43) },
+CurveShape{ bend: U4F28::from_bits(0x1002da49), slow: U4F28::from_bits(0x094ecf80) },
+CurveShape{ bend: U4F28::from_bits(0x10503084), slow: U4F28::from_bits(0x0934bc28) },
+CurveShape{ bend: U4F28::from_bits(0x109d86bf), slow: U4F28::from_bits(0x091ae105) },
+CurveShape{ bend: U4F28::from_bits(0x10eadcfb), slow: U4F28::from_bits(0x09013de0) },
+CurveShape{ bend: U4F28::from_bits(0x11383336), slow: U4F28::from_bits(0x08e7d283) },
+CurveShape{ bend: U4F28::from_bits(0x11858971), slow: U4F28::from_bits(0x08ce9eb4) },
+CurveShape{ bend: U4F28::from_bits(0x11d2dfad), slow: U4F28::from_bits(0x08b5a23a) },
+CurveShape{ bend: U4F28::from_bits(0x122035e8), slow: U4F28::from_bits(0x089cdcdd) },
+CurveShape{ bend: U4F28::from_bits(0x126d8c23), slow: U4F28::from_bits(0x08844e62) },
+CurveShape{ bend: U4F28::from_bits(0x12bae25f), slow: U4F28::from_bits(0x086bf68d) },
+CurveShape{ bend: U4F28::from_bits(0x1308389a), slow: U4F28::from_bits(0x0853d524) },
+CurveShape{ bend: U4F28::from_bits(0x13558ed5), slow: U4F28::from_bits(0x083be9e9) },
+CurveShape{ bend: U4F28::from_bits(0x13a2e510), slow: U4F28::from_bits(0x082434a0) },
+CurveShape{ bend: U4F28::from_bits(0x13f03b4c), slow: U4F28::from_bits(0x080cb50b) },
+CurveShape{ bend: U4F28::from_bits(0x143d9187), slow: U4F28::from_bits(0x07f56aec) },
+CurveShape{ bend: U4F28::from_bits(0x148ae7c2), slow: U4F28::from_bits(0x07de5604) },
+CurveShape{ bend: U4F28::from_bits(0x14d83dfe), slow: U4F28::from_bits(0x07c77613) },
+CurveShape{ bend: U4F28::from_bits(0x15259439), slow: U4F28::from_bits(0x07b0cada) },
+CurveShape{ bend: U4F28::from_bits(0x1572ea74), slow: U4F28::from_bits(0x079a5417) },
+CurveShape{ bend: U4F28::from_bits(0x15c040b0), slow: U4F28::from_bits(0x0784118b) },
+CurveShape{ bend: U4F28::from_bits(0x160d96eb), slow: U4F28::from_bits(0x076e02f2) },
+CurveShape{ bend: U4F28::from_bits(0x165aed26), slow: U4F28::from_bits(0x0758280b) },
+CurveShape{ bend: U4F28::from_bits(0x16a84362), slow: U4F28::from_bits(0x07428093) },
+CurveShape{ bend: U4F28::from_bits(0x16f5999d), slow: U4F28::from_bits(0x072d0c47) },
+CurveShape{ bend: U4F28::from_bits(0x1742efd8), slow: U4F28::from_bits(0x0717cae2) },
+CurveShape{ bend: U4F28::from_bits(0x17904614), slow: U4F28::from_bits(0x0702bc22) },
+CurveShape{ bend: U4F28::from_bits(0x17dd9c4f), slow: U4F28::from_bits(0x06eddfc0) },
+CurveShape{ bend: U4F28::from_bits(0x182af28a), slow: U4F28::from_bits(0x06d93578) },
+CurveShape{ bend: U4F28::from_bits(0x187848c6), slow: U4F28::from_bits(0x06c4bd05) },
+CurveShape{ bend: U4F28::from_bits(0x18c59f01), slow: U4F28::from_bits(0x06b07620) },
+CurveShape{ bend: U4F28::from_bits(0x1912f53c), slow: U4F28::from_bits(0x069c6084) },
+CurveShape{ bend: U4F28::from_bits(0x19604b78), slow: U4F28::from_bits(0x06887be8) },
+CurveShape{ bend: U4F28::from_bits(0x19ada1b3), slow: U4F28::from_bits(0x0674c807) },
+CurveShape{ bend: U4F28::from_bits(0x19faf7ee), slow: U4F28::from_bits(0x06614498) },
+CurveShape{ bend: U4F28::from_bits(0x1a484e2a), slow: U4F28::from_bits(0x064df154) },
+CurveShape{ bend: U4F28::from_bits(0x1a95a465), slow: U4F28::from_bits(0x063acdf2) },
+CurveShape{ bend: U4F28::from_bits(0x1ae2faa0), slow: U4F28::from_bits(0x0627da2b) },
+CurveShape{ bend: U4F28::from_bits(0x1b3050dc), slow: U4F28::from_bits(0x061515b4) },
+CurveShape{ bend: U4F28::from_bits(0x1b7da717), slow: U4F28::from_bits(0x06028046) },
+CurveShape{ bend: U4F28::from_bits(0x1bcafd52), slow: U4F28::from_bits(0x05f01995) },
+CurveShape{ bend: U4F28::from_bits(0x1c18538e), slow: U4F28::from_bits(0x05dde15a) },
+CurveShape{ bend: U4F28::from_bits(0x1c65a9c9), slow: U4F28::from_bits(0x05cbd74a) },
+CurveShape{ bend: U4F28::from_bits(0x1cb30004), slow: U4F28::from_bits(0x05b9fb1a) },
+CurveShape{ bend: U4F28::from_bits(0x1d005640), slow: U4F28::from_bits(0x05a84c81) },
+CurveShape{ bend: U4F28::from_bits(0x1d4dac7b), slow: U4F28::from_bits(0x0596cb33) },
+CurveShape{ bend: U4F28::from_bits(0x1d9b02b6), slow: U4F28::from_bits(0x058576e5) },
+CurveShape{ bend: U4F28::from_bits(0x1de858f2), slow: U4F28::from_bits(0x05744f4d) },
+CurveShape{ bend: U4F28::from_bits(0x1e35af2d), slow: U4F28::from_bits(0x05635420) },
+CurveShape{ bend: U4F28::from_bits(0x1e830568), slow: U4F28::from_bits(0x05528511) },
+CurveShape{ bend: U4F28::from_bits(0x1ed05ba4), slow: U4F28::from_bits(0x0541e1d6) },
+CurveShape{ bend: U4F28::from_bits(0x1f1db1df), slow: U4F28::from_bits(0x05316a22) },
+CurveShape{ bend: U4F28::from_bits(0x1f6b081a), slow: U4F28::from_bits(0x05211daa) },
+CurveShape{ bend: U4F28::from_bits(0x1fb85e56), slow: U4F28::from_bits(0x0510fc21) },
+CurveShape{ bend: U4F28::from_bits(0x2005b491), slow: U4F28::from_bits(0x0501053d) },
+CurveShape{ bend: U4F28::from_bits(0x20530acc), slow: U4F28::from_bits(0x04f138af) },
+CurveShape{ bend: U4F28::from_bits(0x20a06108), slow: U4F28::from_bits(0x04e1962d) },
+CurveShape{ bend: U4F28::from_bits(0x20edb743), slow: U4F28::from_bits(0x04d21d6a) },
+CurveShape{ bend: U4F28::from_bits(0x213b0d7e), slow: U4F28::from_bits(0x04c2ce19) },
+CurveShape{ bend: U4F28::from_bits(0x218863ba), slow: U4F28::from_bits(0x04b3a7ee) },
+CurveShape{ bend: U4F28::from_bits(0x21d5b9f5), slow: U4F28::from_bits(0x04a4aa9c) },
+CurveShape{ bend: U4F28::from_bits(0x22231030), slow: U4F28::from_bits(0x0495d5d7) },
+CurveShape{ bend: U4F28::from_bits(0x2270666c), slow: U4F28::from_bits(0x04872952) },
+CurveShape{ bend: U4F28::from_bits(0x22bdbca7), slow: U4F28::from_bits(0x0478a4c2) },
+CurveShape{ bend: U4F28::from_bits(0x230b12e2), slow: U4F28::from_bits(0x046a47d8) },
+CurveShape{ bend: U4F28::from_bits(0x2358691e), slow: U4F28::from_bits(0x045c1249) },
+CurveShape{ bend: U4F28::from_bits(0x23a5bf59), slow: U4F28::from_bits(0x044e03c9) },
+CurveShape{ bend: U4F28::from_bits(0x23f31594), slow: U4F28::from_bits(0x04401c0a) },
+CurveShape{ bend: U4F28::from_bits(0x24406bd0), slow: U4F28::from_bits(0x04325ac0) },
+CurveShape{ bend: U4F28::from_bits(0x248dc20b), slow: U4F28::from_bits(0x0424bf9f) },
+CurveShape{ bend: U4F28::from_bits(0x24db1846), slow: U4F28::from_bits(0x04174a5b) },
+CurveShape{ bend: U4F28::from_bits(0x25286e82), slow: U4F28::from_bits(0x0409faa7) },
+CurveShape{ bend: U4F28::from_bits(0x2575c4bd), slow: U4F28::from_bits(0x03fcd038) },
+CurveShape{ bend: U4F28::from_bits(0x25c31af8), slow: U4F28::from_bits(0x03efcac1) },
+CurveShape{ bend: U4F28::from_bits(0x26107134), slow: U4F28::from_bits(0x03e2e9f6) },
+CurveShape{ bend: U4F28::from_bits(0x265dc76f), slow: U4F28::from_bits(0x03d62d8b) }
 ];
 
-pub static FALL_BASE_COEFFICIENT_TABLE: [BaseAndCoefficient; 256] = [
-    BaseAndCoefficient{ base: I1F31::from_bits(0xffe79505_u32 as i32), coefficient: I1F31::from_bits(0x7f85e91b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffe90cd9_u32 as i32), coefficient: I1F31::from_bits(0x7f8d403b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffea6165_u32 as i32), coefficient: I1F31::from_bits(0x7f93e6f8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffeb9754_u32 as i32), coefficient: I1F31::from_bits(0x7f99f4a4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffecb287_u32 as i32), coefficient: I1F31::from_bits(0x7f9f7ca4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffedb63e_u32 as i32), coefficient: I1F31::from_bits(0x7fa48f37_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffeea537_u32 as i32), coefficient: I1F31::from_bits(0x7fa93a14_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffef81c7_u32 as i32), coefficient: I1F31::from_bits(0x7fad88e5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff04dee_u32 as i32), coefficient: I1F31::from_bits(0x7fb185a4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff10b62_u32 as i32), coefficient: I1F31::from_bits(0x7fb538e9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff1bba0_u32 as i32), coefficient: I1F31::from_bits(0x7fb8aa21_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff25ff4_u32 as i32), coefficient: I1F31::from_bits(0x7fbbdfc3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff2f97e_u32 as i32), coefficient: I1F31::from_bits(0x7fbedf74_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff3893b_u32 as i32), coefficient: I1F31::from_bits(0x7fc1ae29_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff4100d_u32 as i32), coefficient: I1F31::from_bits(0x7fc45041_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff48eb8_u32 as i32), coefficient: I1F31::from_bits(0x7fc6c999_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff505ed_u32 as i32), coefficient: I1F31::from_bits(0x7fc91d9f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff57647_u32 as i32), coefficient: I1F31::from_bits(0x7fcb4f62_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff5e053_u32 as i32), coefficient: I1F31::from_bits(0x7fcd619e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff6448e_u32 as i32), coefficient: I1F31::from_bits(0x7fcf56c7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff6a36a_u32 as i32), coefficient: I1F31::from_bits(0x7fd13110_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff6fd4b_u32 as i32), coefficient: I1F31::from_bits(0x7fd2f277_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff7528e_u32 as i32), coefficient: I1F31::from_bits(0x7fd49cc8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff7a387_u32 as i32), coefficient: I1F31::from_bits(0x7fd631a3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff7f081_u32 as i32), coefficient: I1F31::from_bits(0x7fd7b284_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff839c1_u32 as i32), coefficient: I1F31::from_bits(0x7fd920c3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff87f85_u32 as i32), coefficient: I1F31::from_bits(0x7fda7d9b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff8c209_u32 as i32), coefficient: I1F31::from_bits(0x7fdbca2c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff90180_u32 as i32), coefficient: I1F31::from_bits(0x7fdd077e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff93e1a_u32 as i32), coefficient: I1F31::from_bits(0x7fde3683_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff97805_u32 as i32), coefficient: I1F31::from_bits(0x7fdf5819_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff9af69_u32 as i32), coefficient: I1F31::from_bits(0x7fe06d0e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfff9e46d_u32 as i32), coefficient: I1F31::from_bits(0x7fe1761f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffa1732_u32 as i32), coefficient: I1F31::from_bits(0x7fe273fa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffa47da_u32 as i32), coefficient: I1F31::from_bits(0x7fe36742_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffa7682_u32 as i32), coefficient: I1F31::from_bits(0x7fe4508c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffaa347_u32 as i32), coefficient: I1F31::from_bits(0x7fe53065_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffface42_u32 as i32), coefficient: I1F31::from_bits(0x7fe6074c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffaf78c_u32 as i32), coefficient: I1F31::from_bits(0x7fe6d5bc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffb1f3a_u32 as i32), coefficient: I1F31::from_bits(0x7fe79c24_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffb4563_u32 as i32), coefficient: I1F31::from_bits(0x7fe85aed_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffb6a18_u32 as i32), coefficient: I1F31::from_bits(0x7fe9127a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffb8d6e_u32 as i32), coefficient: I1F31::from_bits(0x7fe9c325_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffbaf74_u32 as i32), coefficient: I1F31::from_bits(0x7fea6d44_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffbd03b_u32 as i32), coefficient: I1F31::from_bits(0x7feb1128_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffbefd2_u32 as i32), coefficient: I1F31::from_bits(0x7febaf1c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffc0e48_u32 as i32), coefficient: I1F31::from_bits(0x7fec4768_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffc2ba9_u32 as i32), coefficient: I1F31::from_bits(0x7fecda4d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffc4802_u32 as i32), coefficient: I1F31::from_bits(0x7fed680a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffc635f_u32 as i32), coefficient: I1F31::from_bits(0x7fedf0db_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffc7dcb_u32 as i32), coefficient: I1F31::from_bits(0x7fee74f7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffc9750_u32 as i32), coefficient: I1F31::from_bits(0x7feef492_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffcaff9_u32 as i32), coefficient: I1F31::from_bits(0x7fef6fdf_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffcc7cf_u32 as i32), coefficient: I1F31::from_bits(0x7fefe70c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffcdedb_u32 as i32), coefficient: I1F31::from_bits(0x7ff05a45_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffcf524_u32 as i32), coefficient: I1F31::from_bits(0x7ff0c9b4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd0ab4_u32 as i32), coefficient: I1F31::from_bits(0x7ff13582_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd1f91_u32 as i32), coefficient: I1F31::from_bits(0x7ff19dd4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd33c3_u32 as i32), coefficient: I1F31::from_bits(0x7ff202cd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd4750_u32 as i32), coefficient: I1F31::from_bits(0x7ff2648f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd5a3f_u32 as i32), coefficient: I1F31::from_bits(0x7ff2c33c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd6c97_u32 as i32), coefficient: I1F31::from_bits(0x7ff31ef1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd7e5c_u32 as i32), coefficient: I1F31::from_bits(0x7ff377cb_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffd8f95_u32 as i32), coefficient: I1F31::from_bits(0x7ff3cde7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffda046_u32 as i32), coefficient: I1F31::from_bits(0x7ff4215e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffdb075_u32 as i32), coefficient: I1F31::from_bits(0x7ff4724b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffdc027_u32 as i32), coefficient: I1F31::from_bits(0x7ff4c0c4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffdcf60_u32 as i32), coefficient: I1F31::from_bits(0x7ff50ce2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffdde25_u32 as i32), coefficient: I1F31::from_bits(0x7ff556b9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffdec79_u32 as i32), coefficient: I1F31::from_bits(0x7ff59e5e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffdfa61_u32 as i32), coefficient: I1F31::from_bits(0x7ff5e3e7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe07e1_u32 as i32), coefficient: I1F31::from_bits(0x7ff62764_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe14fc_u32 as i32), coefficient: I1F31::from_bits(0x7ff668ea_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe21b5_u32 as i32), coefficient: I1F31::from_bits(0x7ff6a889_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe2e10_u32 as i32), coefficient: I1F31::from_bits(0x7ff6e652_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe3a11_u32 as i32), coefficient: I1F31::from_bits(0x7ff72255_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe45ba_u32 as i32), coefficient: I1F31::from_bits(0x7ff75ca1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe510e_u32 as i32), coefficient: I1F31::from_bits(0x7ff79545_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe5c10_u32 as i32), coefficient: I1F31::from_bits(0x7ff7cc4f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe66c3_u32 as i32), coefficient: I1F31::from_bits(0x7ff801cd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe7129_u32 as i32), coefficient: I1F31::from_bits(0x7ff835cc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe7b45_u32 as i32), coefficient: I1F31::from_bits(0x7ff86858_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe8519_u32 as i32), coefficient: I1F31::from_bits(0x7ff8997c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe8ea7_u32 as i32), coefficient: I1F31::from_bits(0x7ff8c945_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffe97f3_u32 as i32), coefficient: I1F31::from_bits(0x7ff8f7be_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffea0fd_u32 as i32), coefficient: I1F31::from_bits(0x7ff924f1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffea9c8_u32 as i32), coefficient: I1F31::from_bits(0x7ff950e9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffeb256_u32 as i32), coefficient: I1F31::from_bits(0x7ff97bae_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffebaa9_u32 as i32), coefficient: I1F31::from_bits(0x7ff9a54c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffec2c2_u32 as i32), coefficient: I1F31::from_bits(0x7ff9cdca_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffecaa4_u32 as i32), coefficient: I1F31::from_bits(0x7ff9f532_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffed24f_u32 as i32), coefficient: I1F31::from_bits(0x7ffa1b8c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffed9c7_u32 as i32), coefficient: I1F31::from_bits(0x7ffa40e1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffee10b_u32 as i32), coefficient: I1F31::from_bits(0x7ffa6537_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffee81e_u32 as i32), coefficient: I1F31::from_bits(0x7ffa8898_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffeef02_u32 as i32), coefficient: I1F31::from_bits(0x7ffaab09_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffef5b7_u32 as i32), coefficient: I1F31::from_bits(0x7ffacc93_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffefc3f_u32 as i32), coefficient: I1F31::from_bits(0x7ffaed3b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff029b_u32 as i32), coefficient: I1F31::from_bits(0x7ffb0d09_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff08cd_u32 as i32), coefficient: I1F31::from_bits(0x7ffb2c03_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff0ed6_u32 as i32), coefficient: I1F31::from_bits(0x7ffb4a2f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff14b7_u32 as i32), coefficient: I1F31::from_bits(0x7ffb6793_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff1a71_u32 as i32), coefficient: I1F31::from_bits(0x7ffb8434_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff2005_u32 as i32), coefficient: I1F31::from_bits(0x7ffba019_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff2574_u32 as i32), coefficient: I1F31::from_bits(0x7ffbbb46_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff2ac0_u32 as i32), coefficient: I1F31::from_bits(0x7ffbd5c1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff2fe9_u32 as i32), coefficient: I1F31::from_bits(0x7ffbef8f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff34f1_u32 as i32), coefficient: I1F31::from_bits(0x7ffc08b5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff39d8_u32 as i32), coefficient: I1F31::from_bits(0x7ffc2137_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff3e9f_u32 as i32), coefficient: I1F31::from_bits(0x7ffc3919_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff4347_u32 as i32), coefficient: I1F31::from_bits(0x7ffc5062_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff47d1_u32 as i32), coefficient: I1F31::from_bits(0x7ffc6713_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff4c3d_u32 as i32), coefficient: I1F31::from_bits(0x7ffc7d32_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff508d_u32 as i32), coefficient: I1F31::from_bits(0x7ffc92c3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff54c2_u32 as i32), coefficient: I1F31::from_bits(0x7ffca7ca_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff58db_u32 as i32), coefficient: I1F31::from_bits(0x7ffcbc49_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff5cdb_u32 as i32), coefficient: I1F31::from_bits(0x7ffcd046_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff60c1_u32 as i32), coefficient: I1F31::from_bits(0x7ffce3c3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff648e_u32 as i32), coefficient: I1F31::from_bits(0x7ffcf6c4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff6843_u32 as i32), coefficient: I1F31::from_bits(0x7ffd094d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff6be0_u32 as i32), coefficient: I1F31::from_bits(0x7ffd1b5f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff6f66_u32 as i32), coefficient: I1F31::from_bits(0x7ffd2d00_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff72d6_u32 as i32), coefficient: I1F31::from_bits(0x7ffd3e30_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff7631_u32 as i32), coefficient: I1F31::from_bits(0x7ffd4ef5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff7976_u32 as i32), coefficient: I1F31::from_bits(0x7ffd5f50_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff7ca7_u32 as i32), coefficient: I1F31::from_bits(0x7ffd6f43_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff7fc4_u32 as i32), coefficient: I1F31::from_bits(0x7ffd7ed3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff82cd_u32 as i32), coefficient: I1F31::from_bits(0x7ffd8e01_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff85c3_u32 as i32), coefficient: I1F31::from_bits(0x7ffd9cd0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff88a7_u32 as i32), coefficient: I1F31::from_bits(0x7ffdab43_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff8b79_u32 as i32), coefficient: I1F31::from_bits(0x7ffdb95b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff8e39_u32 as i32), coefficient: I1F31::from_bits(0x7ffdc71c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff90e8_u32 as i32), coefficient: I1F31::from_bits(0x7ffdd487_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff9386_u32 as i32), coefficient: I1F31::from_bits(0x7ffde19f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff9614_u32 as i32), coefficient: I1F31::from_bits(0x7ffdee65_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff9892_u32 as i32), coefficient: I1F31::from_bits(0x7ffdfadc_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff9b01_u32 as i32), coefficient: I1F31::from_bits(0x7ffe0706_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff9d61_u32 as i32), coefficient: I1F31::from_bits(0x7ffe12e5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffff9fb2_u32 as i32), coefficient: I1F31::from_bits(0x7ffe1e7b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffa1f5_u32 as i32), coefficient: I1F31::from_bits(0x7ffe29c9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffa42a_u32 as i32), coefficient: I1F31::from_bits(0x7ffe34d1_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffa651_u32 as i32), coefficient: I1F31::from_bits(0x7ffe3f96_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffa86b_u32 as i32), coefficient: I1F31::from_bits(0x7ffe4a19_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffaa78_u32 as i32), coefficient: I1F31::from_bits(0x7ffe545a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffac79_u32 as i32), coefficient: I1F31::from_bits(0x7ffe5e5e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffae6d_u32 as i32), coefficient: I1F31::from_bits(0x7ffe6823_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffb056_u32 as i32), coefficient: I1F31::from_bits(0x7ffe71ad_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffb233_u32 as i32), coefficient: I1F31::from_bits(0x7ffe7afd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffb404_u32 as i32), coefficient: I1F31::from_bits(0x7ffe8413_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffb5ca_u32 as i32), coefficient: I1F31::from_bits(0x7ffe8cf3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffb786_u32 as i32), coefficient: I1F31::from_bits(0x7ffe959c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffb936_u32 as i32), coefficient: I1F31::from_bits(0x7ffe9e10_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffbadd_u32 as i32), coefficient: I1F31::from_bits(0x7ffea651_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffbc7a_u32 as i32), coefficient: I1F31::from_bits(0x7ffeae60_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffbe0c_u32 as i32), coefficient: I1F31::from_bits(0x7ffeb63e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffbf96_u32 as i32), coefficient: I1F31::from_bits(0x7ffebdec_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffc116_u32 as i32), coefficient: I1F31::from_bits(0x7ffec56c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffc28c_u32 as i32), coefficient: I1F31::from_bits(0x7ffeccbe_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffc3fa_u32 as i32), coefficient: I1F31::from_bits(0x7ffed3e4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffc560_u32 as i32), coefficient: I1F31::from_bits(0x7ffedade_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffc6bd_u32 as i32), coefficient: I1F31::from_bits(0x7ffee1af_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffc811_u32 as i32), coefficient: I1F31::from_bits(0x7ffee856_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffc95e_u32 as i32), coefficient: I1F31::from_bits(0x7ffeeed5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffcaa3_u32 as i32), coefficient: I1F31::from_bits(0x7ffef52d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffcbe0_u32 as i32), coefficient: I1F31::from_bits(0x7ffefb5e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffcd15_u32 as i32), coefficient: I1F31::from_bits(0x7fff016a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffce44_u32 as i32), coefficient: I1F31::from_bits(0x7fff0752_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffcf6b_u32 as i32), coefficient: I1F31::from_bits(0x7fff0d17_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd08b_u32 as i32), coefficient: I1F31::from_bits(0x7fff12b8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd1a5_u32 as i32), coefficient: I1F31::from_bits(0x7fff1838_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd2b8_u32 as i32), coefficient: I1F31::from_bits(0x7fff1d97_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd3c4_u32 as i32), coefficient: I1F31::from_bits(0x7fff22d5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd4ca_u32 as i32), coefficient: I1F31::from_bits(0x7fff27f4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd5cb_u32 as i32), coefficient: I1F31::from_bits(0x7fff2cf5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd6c5_u32 as i32), coefficient: I1F31::from_bits(0x7fff31d7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd7b9_u32 as i32), coefficient: I1F31::from_bits(0x7fff369c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd8a7_u32 as i32), coefficient: I1F31::from_bits(0x7fff3b45_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffd990_u32 as i32), coefficient: I1F31::from_bits(0x7fff3fd2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffda74_u32 as i32), coefficient: I1F31::from_bits(0x7fff4443_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffdb52_u32 as i32), coefficient: I1F31::from_bits(0x7fff489a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffdc2b_u32 as i32), coefficient: I1F31::from_bits(0x7fff4cd7_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffdcff_u32 as i32), coefficient: I1F31::from_bits(0x7fff50fb_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffddce_u32 as i32), coefficient: I1F31::from_bits(0x7fff5506_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffde98_u32 as i32), coefficient: I1F31::from_bits(0x7fff58f8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffdf5e_u32 as i32), coefficient: I1F31::from_bits(0x7fff5cd4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe01e_u32 as i32), coefficient: I1F31::from_bits(0x7fff6098_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe0db_u32 as i32), coefficient: I1F31::from_bits(0x7fff6446_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe193_u32 as i32), coefficient: I1F31::from_bits(0x7fff67de_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe246_u32 as i32), coefficient: I1F31::from_bits(0x7fff6b60_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe2f6_u32 as i32), coefficient: I1F31::from_bits(0x7fff6ece_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe3a1_u32 as i32), coefficient: I1F31::from_bits(0x7fff7227_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe449_u32 as i32), coefficient: I1F31::from_bits(0x7fff756c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe4ec_u32 as i32), coefficient: I1F31::from_bits(0x7fff789e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe58c_u32 as i32), coefficient: I1F31::from_bits(0x7fff7bbd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe628_u32 as i32), coefficient: I1F31::from_bits(0x7fff7ec9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe6c1_u32 as i32), coefficient: I1F31::from_bits(0x7fff81c4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe756_u32 as i32), coefficient: I1F31::from_bits(0x7fff84ac_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe7e7_u32 as i32), coefficient: I1F31::from_bits(0x7fff8783_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe875_u32 as i32), coefficient: I1F31::from_bits(0x7fff8a4a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe900_u32 as i32), coefficient: I1F31::from_bits(0x7fff8d00_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffe987_u32 as i32), coefficient: I1F31::from_bits(0x7fff8fa5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffea0c_u32 as i32), coefficient: I1F31::from_bits(0x7fff923c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffea8d_u32 as i32), coefficient: I1F31::from_bits(0x7fff94c2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffeb0c_u32 as i32), coefficient: I1F31::from_bits(0x7fff973a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffeb87_u32 as i32), coefficient: I1F31::from_bits(0x7fff99a4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffec00_u32 as i32), coefficient: I1F31::from_bits(0x7fff9bfe_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffec75_u32 as i32), coefficient: I1F31::from_bits(0x7fff9e4b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffece9_u32 as i32), coefficient: I1F31::from_bits(0x7fffa08b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffed59_u32 as i32), coefficient: I1F31::from_bits(0x7fffa2bd_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffedc7_u32 as i32), coefficient: I1F31::from_bits(0x7fffa4e2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffee32_u32 as i32), coefficient: I1F31::from_bits(0x7fffa6fa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffee9b_u32 as i32), coefficient: I1F31::from_bits(0x7fffa906_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffef01_u32 as i32), coefficient: I1F31::from_bits(0x7fffab06_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffef65_u32 as i32), coefficient: I1F31::from_bits(0x7fffacfa_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xffffefc7_u32 as i32), coefficient: I1F31::from_bits(0x7fffaee3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff026_u32 as i32), coefficient: I1F31::from_bits(0x7fffb0c0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff084_u32 as i32), coefficient: I1F31::from_bits(0x7fffb292_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff0df_u32 as i32), coefficient: I1F31::from_bits(0x7fffb45a_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff138_u32 as i32), coefficient: I1F31::from_bits(0x7fffb617_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff18f_u32 as i32), coefficient: I1F31::from_bits(0x7fffb7c9_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff1e4_u32 as i32), coefficient: I1F31::from_bits(0x7fffb972_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff237_u32 as i32), coefficient: I1F31::from_bits(0x7fffbb11_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff288_u32 as i32), coefficient: I1F31::from_bits(0x7fffbca6_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff2d7_u32 as i32), coefficient: I1F31::from_bits(0x7fffbe32_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff324_u32 as i32), coefficient: I1F31::from_bits(0x7fffbfb5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff370_u32 as i32), coefficient: I1F31::from_bits(0x7fffc12f_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff3ba_u32 as i32), coefficient: I1F31::from_bits(0x7fffc2a0_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff402_u32 as i32), coefficient: I1F31::from_bits(0x7fffc409_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff448_u32 as i32), coefficient: I1F31::from_bits(0x7fffc569_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff48d_u32 as i32), coefficient: I1F31::from_bits(0x7fffc6c2_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff4d0_u32 as i32), coefficient: I1F31::from_bits(0x7fffc812_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff512_u32 as i32), coefficient: I1F31::from_bits(0x7fffc95b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff552_u32 as i32), coefficient: I1F31::from_bits(0x7fffca9c_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff591_u32 as i32), coefficient: I1F31::from_bits(0x7fffcbd5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff5ce_u32 as i32), coefficient: I1F31::from_bits(0x7fffcd08_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff60a_u32 as i32), coefficient: I1F31::from_bits(0x7fffce33_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff645_u32 as i32), coefficient: I1F31::from_bits(0x7fffcf58_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff67e_u32 as i32), coefficient: I1F31::from_bits(0x7fffd075_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff6b6_u32 as i32), coefficient: I1F31::from_bits(0x7fffd18d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff6ec_u32 as i32), coefficient: I1F31::from_bits(0x7fffd29d_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff722_u32 as i32), coefficient: I1F31::from_bits(0x7fffd3a8_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff756_u32 as i32), coefficient: I1F31::from_bits(0x7fffd4ac_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff789_u32 as i32), coefficient: I1F31::from_bits(0x7fffd5ab_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff7ba_u32 as i32), coefficient: I1F31::from_bits(0x7fffd6a3_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff7eb_u32 as i32), coefficient: I1F31::from_bits(0x7fffd796_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff81a_u32 as i32), coefficient: I1F31::from_bits(0x7fffd883_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff849_u32 as i32), coefficient: I1F31::from_bits(0x7fffd96b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff876_u32 as i32), coefficient: I1F31::from_bits(0x7fffda4e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff8a2_u32 as i32), coefficient: I1F31::from_bits(0x7fffdb2b_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff8cd_u32 as i32), coefficient: I1F31::from_bits(0x7fffdc03_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff8f8_u32 as i32), coefficient: I1F31::from_bits(0x7fffdcd6_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff921_u32 as i32), coefficient: I1F31::from_bits(0x7fffdda5_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff949_u32 as i32), coefficient: I1F31::from_bits(0x7fffde6e_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff971_u32 as i32), coefficient: I1F31::from_bits(0x7fffdf33_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff997_u32 as i32), coefficient: I1F31::from_bits(0x7fffdff4_u32 as i32) },
-BaseAndCoefficient{ base: I1F31::from_bits(0xfffff9bd_u32 as i32), coefficient: I1F31::from_bits(0x7fffe0b0_u32 as i32) }
+pub static FALL_CURVE_TABLE: [CurveShape; 256] = [
+    CurveShape{ bend: U4F28::from_bits(0x1cab0bfa), slow: U4F28::from_bits(0x05bbcf32) },
+CurveShape{ bend: U4F28::from_bits(0x1c71b5e2), slow: U4F28::from_bits(0x05c90c0a) },
+CurveShape{ bend: U4F28::from_bits(0x1c385fca), slow: U4F28::from_bits(0x05d66214) },
+CurveShape{ bend: U4F28::from_bits(0x1bff09b2), slow: U4F28::from_bits(0x05e3d16c) },
+CurveShape{ bend: U4F28::from_bits(0x1bc5b39a), slow: U4F28::from_bits(0x05f15a33) },
+CurveShape{ bend: U4F28::from_bits(0x1b8c5d82), slow: U4F28::from_bits(0x05fefc85) },
+CurveShape{ bend: U4F28::from_bits(0x1b53076a), slow: U4F28::from_bits(0x060cb880) },
+CurveShape{ bend: U4F28::from_bits(0x1b19b152), slow: U4F28::from_bits(0x061a8e44) },
+CurveShape{ bend: U4F28::from_bits(0x1ae05b3b), slow: U4F28::from_bits(0x06287dec) },
+CurveShape{ bend: U4F28::from_bits(0x1aa70523), slow: U4F28::from_bits(0x06368799) },
+CurveShape{ bend: U4F28::from_bits(0x1a6daf0b), slow: U4F28::from_bits(0x0644ab66) },
+CurveShape{ bend: U4F28::from_bits(0x1a3458f3), slow: U4F28::from_bits(0x0652e972) },
+CurveShape{ bend: U4F28::from_bits(0x19fb02db), slow: U4F28::from_bits(0x066141da) },
+CurveShape{ bend: U4F28::from_bits(0x19c1acc3), slow: U4F28::from_bits(0x066fb4bb) },
+CurveShape{ bend: U4F28::from_bits(0x198856ab), slow: U4F28::from_bits(0x067e4234) },
+CurveShape{ bend: U4F28::from_bits(0x194f0093), slow: U4F28::from_bits(0x068cea60) },
+CurveShape{ bend: U4F28::from_bits(0x1915aa7b), slow: U4F28::from_bits(0x069bad5e) },
+CurveShape{ bend: U4F28::from_bits(0x18dc5463), slow: U4F28::from_bits(0x06aa8b4a) },
+CurveShape{ bend: U4F28::from_bits(0x18a2fe4b), slow: U4F28::from_bits(0x06b98441) },
+CurveShape{ bend: U4F28::from_bits(0x1869a833), slow: U4F28::from_bits(0x06c89860) },
+CurveShape{ bend: U4F28::from_bits(0x1830521b), slow: U4F28::from_bits(0x06d7c7c3) },
+CurveShape{ bend: U4F28::from_bits(0x17f6fc03), slow: U4F28::from_bits(0x06e71287) },
+CurveShape{ bend: U4F28::from_bits(0x17bda5eb), slow: U4F28::from_bits(0x06f678c8) },
+CurveShape{ bend: U4F28::from_bits(0x17844fd3), slow: U4F28::from_bits(0x0705faa3) },
+CurveShape{ bend: U4F28::from_bits(0x174af9bb), slow: U4F28::from_bits(0x07159833) },
+CurveShape{ bend: U4F28::from_bits(0x1711a3a3), slow: U4F28::from_bits(0x07255195) },
+CurveShape{ bend: U4F28::from_bits(0x16d84d8b), slow: U4F28::from_bits(0x073526e5) },
+CurveShape{ bend: U4F28::from_bits(0x169ef773), slow: U4F28::from_bits(0x0745183d) },
+CurveShape{ bend: U4F28::from_bits(0x1665a15b), slow: U4F28::from_bits(0x075525bb) },
+CurveShape{ bend: U4F28::from_bits(0x162c4b43), slow: U4F28::from_bits(0x07654f78) },
+CurveShape{ bend: U4F28::from_bits(0x15f2f52c), slow: U4F28::from_bits(0x07759591) },
+CurveShape{ bend: U4F28::from_bits(0x15b99f14), slow: U4F28::from_bits(0x0785f821) },
+CurveShape{ bend: U4F28::from_bits(0x158048fc), slow: U4F28::from_bits(0x07967741) },
+CurveShape{ bend: U4F28::from_bits(0x1546f2e4), slow: U4F28::from_bits(0x07a7130f) },
+CurveShape{ bend: U4F28::from_bits(0x150d9ccc), slow: U4F28::from_bits(0x07b7cba3) },
+CurveShape{ bend: U4F28::from_bits(0x14d446b4), slow: U4F28::from_bits(0x07c8a118) },
+CurveShape{ bend: U4F28::from_bits(0x149af09c), slow: U4F28::from_bits(0x07d99389) },
+CurveShape{ bend: U4F28::from_bits(0x14619a84), slow: U4F28::from_bits(0x07eaa30f) },
+CurveShape{ bend: U4F28::from_bits(0x1428446c), slow: U4F28::from_bits(0x07fbcfc6) },
+CurveShape{ bend: U4F28::from_bits(0x13eeee54), slow: U4F28::from_bits(0x080d19c5) },
+CurveShape{ bend: U4F28::from_bits(0x13b5983c), slow: U4F28::from_bits(0x081e8128) },
+CurveShape{ bend: U4F28::from_bits(0x137c4224), slow: U4F28::from_bits(0x08300607) },
+CurveShape{ bend: U4F28::from_bits(0x1342ec0c), slow: U4F28::from_bits(0x0841a87c) },
+CurveShape{ bend: U4F28::from_bits(0x130995f4), slow: U4F28::from_bits(0x0853689f) },
+CurveShape{ bend: U4F28::from_bits(0x12d03fdc), slow: U4F28::from_bits(0x0865468a) },
+CurveShape{ bend: U4F28::from_bits(0x1296e9c4), slow: U4F28::from_bits(0x08774255) },
+CurveShape{ bend: U4F28::from_bits(0x125d93ac), slow: U4F28::from_bits(0x08895c19) },
+CurveShape{ bend: U4F28::from_bits(0x12243d94), slow: U4F28::from_bits(0x089b93ed) },
+CurveShape{ bend: U4F28::from_bits(0x11eae77c), slow: U4F28::from_bits(0x08ade9ea) },
+CurveShape{ bend: U4F28::from_bits(0x11b19164), slow: U4F28::from_bits(0x08c05e28) },
+CurveShape{ bend: U4F28::from_bits(0x11783b4c), slow: U4F28::from_bits(0x08d2f0be) },
+CurveShape{ bend: U4F28::from_bits(0x113ee534), slow: U4F28::from_bits(0x08e5a1c3) },
+CurveShape{ bend: U4F28::from_bits(0x11058f1d), slow: U4F28::from_bits(0x08f87150) },
+CurveShape{ bend: U4F28::from_bits(0x10cc3905), slow: U4F28::from_bits(0x090b5f7a) },
+CurveShape{ bend: U4F28::from_bits(0x1092e2ed), slow: U4F28::from_bits(0x091e6c59) },
+CurveShape{ bend: U4F28::from_bits(0x10598cd5), slow: U4F28::from_bits(0x09319803) },
+CurveShape{ bend: U4F28::from_bits(0x102036bd), slow: U4F28::from_bits(0x0944e28e) },
+CurveShape{ bend: U4F28::from_bits(0x0fe6e0a5), slow: U4F28::from_bits(0x09584c11) },
+CurveShape{ bend: U4F28::from_bits(0x0fad8a8d), slow: U4F28::from_bits(0x096bd4a1) },
+CurveShape{ bend: U4F28::from_bits(0x0f743475), slow: U4F28::from_bits(0x097f7c54) },
+CurveShape{ bend: U4F28::from_bits(0x0f3ade5d), slow: U4F28::from_bits(0x0993433e) },
+CurveShape{ bend: U4F28::from_bits(0x0f018845), slow: U4F28::from_bits(0x09a72977) },
+CurveShape{ bend: U4F28::from_bits(0x0ec8322d), slow: U4F28::from_bits(0x09bb2f11) },
+CurveShape{ bend: U4F28::from_bits(0x0e8edc15), slow: U4F28::from_bits(0x09cf5422) },
+CurveShape{ bend: U4F28::from_bits(0x0e5585fd), slow: U4F28::from_bits(0x09e398bf) },
+CurveShape{ bend: U4F28::from_bits(0x0e1c2fe5), slow: U4F28::from_bits(0x09f7fcfa) },
+CurveShape{ bend: U4F28::from_bits(0x0de2d9cd), slow: U4F28::from_bits(0x0a0c80e9) },
+CurveShape{ bend: U4F28::from_bits(0x0da983b5), slow: U4F28::from_bits(0x0a21249f) },
+CurveShape{ bend: U4F28::from_bits(0x0d702d9d), slow: U4F28::from_bits(0x0a35e82f) },
+CurveShape{ bend: U4F28::from_bits(0x0d36d785), slow: U4F28::from_bits(0x0a4acbac) },
+CurveShape{ bend: U4F28::from_bits(0x0cfd816d), slow: U4F28::from_bits(0x0a5fcf29) },
+CurveShape{ bend: U4F28::from_bits(0x0cc42b55), slow: U4F28::from_bits(0x0a74f2b9) },
+CurveShape{ bend: U4F28::from_bits(0x0c8ad53d), slow: U4F28::from_bits(0x0a8a366e) },
+CurveShape{ bend: U4F28::from_bits(0x0c517f25), slow: U4F28::from_bits(0x0a9f9a5a) },
+CurveShape{ bend: U4F28::from_bits(0x0c18290e), slow: U4F28::from_bits(0x0ab51e8e) },
+CurveShape{ bend: U4F28::from_bits(0x0bded2f6), slow: U4F28::from_bits(0x0acac31d) },
+CurveShape{ bend: U4F28::from_bits(0x0ba57cde), slow: U4F28::from_bits(0x0ae08817) },
+CurveShape{ bend: U4F28::from_bits(0x0b6c26c6), slow: U4F28::from_bits(0x0af66d8d) },
+CurveShape{ bend: U4F28::from_bits(0x0b32d0ae), slow: U4F28::from_bits(0x0b0c7390) },
+CurveShape{ bend: U4F28::from_bits(0x0af97a96), slow: U4F28::from_bits(0x0b229a31) },
+CurveShape{ bend: U4F28::from_bits(0x0ac0247e), slow: U4F28::from_bits(0x0b38e17f) },
+CurveShape{ bend: U4F28::from_bits(0x0a86ce66), slow: U4F28::from_bits(0x0b4f498a) },
+CurveShape{ bend: U4F28::from_bits(0x0a4d784e), slow: U4F28::from_bits(0x0b65d262) },
+CurveShape{ bend: U4F28::from_bits(0x0a142236), slow: U4F28::from_bits(0x0b7c7c16) },
+CurveShape{ bend: U4F28::from_bits(0x09dacc1e), slow: U4F28::from_bits(0x0b9346b4) },
+CurveShape{ bend: U4F28::from_bits(0x09a17606), slow: U4F28::from_bits(0x0baa324c) },
+CurveShape{ bend: U4F28::from_bits(0x09681fee), slow: U4F28::from_bits(0x0bc13eeb) },
+CurveShape{ bend: U4F28::from_bits(0x092ec9d6), slow: U4F28::from_bits(0x0bd86ca0) },
+CurveShape{ bend: U4F28::from_bits(0x08f573be), slow: U4F28::from_bits(0x0befbb79) },
+CurveShape{ bend: U4F28::from_bits(0x08bc1da6), slow: U4F28::from_bits(0x0c072b82) },
+CurveShape{ bend: U4F28::from_bits(0x0882c78e), slow: U4F28::from_bits(0x0c1ebcc8) },
+CurveShape{ bend: U4F28::from_bits(0x08497176), slow: U4F28::from_bits(0x0c366f5a) },
+CurveShape{ bend: U4F28::from_bits(0x08101b5e), slow: U4F28::from_bits(0x0c4e4342) },
+CurveShape{ bend: U4F28::from_bits(0x07d6c546), slow: U4F28::from_bits(0x0c66388e) },
+CurveShape{ bend: U4F28::from_bits(0x079d6f2e), slow: U4F28::from_bits(0x0c7e4f49) },
+CurveShape{ bend: U4F28::from_bits(0x07641916), slow: U4F28::from_bits(0x0c96877e) },
+CurveShape{ bend: U4F28::from_bits(0x072ac2ff), slow: U4F28::from_bits(0x0caee139) },
+CurveShape{ bend: U4F28::from_bits(0x06f16ce7), slow: U4F28::from_bits(0x0cc75c85) },
+CurveShape{ bend: U4F28::from_bits(0x06b816cf), slow: U4F28::from_bits(0x0cdff96c) },
+CurveShape{ bend: U4F28::from_bits(0x067ec0b7), slow: U4F28::from_bits(0x0cf8b7f9) },
+CurveShape{ bend: U4F28::from_bits(0x06456a9f), slow: U4F28::from_bits(0x0d119835) },
+CurveShape{ bend: U4F28::from_bits(0x060c1487), slow: U4F28::from_bits(0x0d2a9a2a) },
+CurveShape{ bend: U4F28::from_bits(0x05d2be6f), slow: U4F28::from_bits(0x0d43bde1) },
+CurveShape{ bend: U4F28::from_bits(0x05996857), slow: U4F28::from_bits(0x0d5d0364) },
+CurveShape{ bend: U4F28::from_bits(0x0560123f), slow: U4F28::from_bits(0x0d766abb) },
+CurveShape{ bend: U4F28::from_bits(0x0526bc27), slow: U4F28::from_bits(0x0d8ff3ee) },
+CurveShape{ bend: U4F28::from_bits(0x04ed660f), slow: U4F28::from_bits(0x0da99f05) },
+CurveShape{ bend: U4F28::from_bits(0x04b40ff7), slow: U4F28::from_bits(0x0dc36c08) },
+CurveShape{ bend: U4F28::from_bits(0x047ab9df), slow: U4F28::from_bits(0x0ddd5afe) },
+CurveShape{ bend: U4F28::from_bits(0x044163c7), slow: U4F28::from_bits(0x0df76bee) },
+CurveShape{ bend: U4F28::from_bits(0x04080daf), slow: U4F28::from_bits(0x0e119edf) },
+CurveShape{ bend: U4F28::from_bits(0x03ceb797), slow: U4F28::from_bits(0x0e2bf3d7) },
+CurveShape{ bend: U4F28::from_bits(0x0395617f), slow: U4F28::from_bits(0x0e466adc) },
+CurveShape{ bend: U4F28::from_bits(0x035c0b67), slow: U4F28::from_bits(0x0e6103f4) },
+CurveShape{ bend: U4F28::from_bits(0x0322b54f), slow: U4F28::from_bits(0x0e7bbf24) },
+CurveShape{ bend: U4F28::from_bits(0x02e95f37), slow: U4F28::from_bits(0x0e969c70) },
+CurveShape{ bend: U4F28::from_bits(0x02b0091f), slow: U4F28::from_bits(0x0eb19bde) },
+CurveShape{ bend: U4F28::from_bits(0x0276b307), slow: U4F28::from_bits(0x0eccbd72) },
+CurveShape{ bend: U4F28::from_bits(0x023d5cf0), slow: U4F28::from_bits(0x0ee80130) },
+CurveShape{ bend: U4F28::from_bits(0x020406d8), slow: U4F28::from_bits(0x0f03671b) },
+CurveShape{ bend: U4F28::from_bits(0x01cab0c0), slow: U4F28::from_bits(0x0f1eef37) },
+CurveShape{ bend: U4F28::from_bits(0x01915aa8), slow: U4F28::from_bits(0x0f3a9986) },
+CurveShape{ bend: U4F28::from_bits(0x01580490), slow: U4F28::from_bits(0x0f56660b) },
+CurveShape{ bend: U4F28::from_bits(0x011eae78), slow: U4F28::from_bits(0x0f7254c9) },
+CurveShape{ bend: U4F28::from_bits(0x00e55860), slow: U4F28::from_bits(0x0f8e65c1) },
+CurveShape{ bend: U4F28::from_bits(0x00ac0248), slow: U4F28::from_bits(0x0faa98f4) },
+CurveShape{ bend: U4F28::from_bits(0x0072ac30), slow: U4F28::from_bits(0x0fc6ee65) },
+CurveShape{ bend: U4F28::from_bits(0x00395618), slow: U4F28::from_bits(0x0fe36613) },
+CurveShape{ bend: U4F28::from_bits(0x00000000), slow: U4F28::from_bits(0x10000000) },
+CurveShape{ bend: U4F28::from_bits(0x0039c9ab), slow: U4F28::from_bits(0x0fe32c8f) },
+CurveShape{ bend: U4F28::from_bits(0x00739357), slow: U4F28::from_bits(0x0fc67be7) },
+CurveShape{ bend: U4F28::from_bits(0x00ad5d02), slow: U4F28::from_bits(0x0fa9ee07) },
+CurveShape{ bend: U4F28::from_bits(0x00e726ad), slow: U4F28::from_bits(0x0f8d82ef) },
+CurveShape{ bend: U4F28::from_bits(0x0120f058), slow: U4F28::from_bits(0x0f713a9d) },
+CurveShape{ bend: U4F28::from_bits(0x015aba04), slow: U4F28::from_bits(0x0f55150f) },
+CurveShape{ bend: U4F28::from_bits(0x019483af), slow: U4F28::from_bits(0x0f391245) },
+CurveShape{ bend: U4F28::from_bits(0x01ce4d5a), slow: U4F28::from_bits(0x0f1d323b) },
+CurveShape{ bend: U4F28::from_bits(0x02081706), slow: U4F28::from_bits(0x0f0174ee) },
+CurveShape{ bend: U4F28::from_bits(0x0241e0b1), slow: U4F28::from_bits(0x0ee5da5d) },
+CurveShape{ bend: U4F28::from_bits(0x027baa5c), slow: U4F28::from_bits(0x0eca6283) },
+CurveShape{ bend: U4F28::from_bits(0x02b57408), slow: U4F28::from_bits(0x0eaf0d5c) },
+CurveShape{ bend: U4F28::from_bits(0x02ef3db3), slow: U4F28::from_bits(0x0e93dae5) },
+CurveShape{ bend: U4F28::from_bits(0x0329075e), slow: U4F28::from_bits(0x0e78cb19) },
+CurveShape{ bend: U4F28::from_bits(0x0362d109), slow: U4F28::from_bits(0x0e5dddf4) },
+CurveShape{ bend: U4F28::from_bits(0x039c9ab5), slow: U4F28::from_bits(0x0e431370) },
+CurveShape{ bend: U4F28::from_bits(0x03d66460), slow: U4F28::from_bits(0x0e286b88) },
+CurveShape{ bend: U4F28::from_bits(0x04102e0b), slow: U4F28::from_bits(0x0e0de636) },
+CurveShape{ bend: U4F28::from_bits(0x0449f7b7), slow: U4F28::from_bits(0x0df38374) },
+CurveShape{ bend: U4F28::from_bits(0x0483c162), slow: U4F28::from_bits(0x0dd9433c) },
+CurveShape{ bend: U4F28::from_bits(0x04bd8b0d), slow: U4F28::from_bits(0x0dbf2585) },
+CurveShape{ bend: U4F28::from_bits(0x04f754b8), slow: U4F28::from_bits(0x0da52a4b) },
+CurveShape{ bend: U4F28::from_bits(0x05311e64), slow: U4F28::from_bits(0x0d8b5184) },
+CurveShape{ bend: U4F28::from_bits(0x056ae80f), slow: U4F28::from_bits(0x0d719b29) },
+CurveShape{ bend: U4F28::from_bits(0x05a4b1ba), slow: U4F28::from_bits(0x0d580731) },
+CurveShape{ bend: U4F28::from_bits(0x05de7b66), slow: U4F28::from_bits(0x0d3e9595) },
+CurveShape{ bend: U4F28::from_bits(0x06184511), slow: U4F28::from_bits(0x0d25464b) },
+CurveShape{ bend: U4F28::from_bits(0x06520ebc), slow: U4F28::from_bits(0x0d0c194a) },
+CurveShape{ bend: U4F28::from_bits(0x068bd867), slow: U4F28::from_bits(0x0cf30e89) },
+CurveShape{ bend: U4F28::from_bits(0x06c5a213), slow: U4F28::from_bits(0x0cda25fc) },
+CurveShape{ bend: U4F28::from_bits(0x06ff6bbe), slow: U4F28::from_bits(0x0cc15f9c) },
+CurveShape{ bend: U4F28::from_bits(0x07393569), slow: U4F28::from_bits(0x0ca8bb5c) },
+CurveShape{ bend: U4F28::from_bits(0x0772ff15), slow: U4F28::from_bits(0x0c903931) },
+CurveShape{ bend: U4F28::from_bits(0x07acc8c0), slow: U4F28::from_bits(0x0c77d912) },
+CurveShape{ bend: U4F28::from_bits(0x07e6926b), slow: U4F28::from_bits(0x0c5f9af1) },
+CurveShape{ bend: U4F28::from_bits(0x08205c17), slow: U4F28::from_bits(0x0c477ec3) },
+CurveShape{ bend: U4F28::from_bits(0x085a25c2), slow: U4F28::from_bits(0x0c2f847c) },
+CurveShape{ bend: U4F28::from_bits(0x0893ef6d), slow: U4F28::from_bits(0x0c17ac10) },
+CurveShape{ bend: U4F28::from_bits(0x08cdb918), slow: U4F28::from_bits(0x0bfff571) },
+CurveShape{ bend: U4F28::from_bits(0x090782c4), slow: U4F28::from_bits(0x0be86092) },
+CurveShape{ bend: U4F28::from_bits(0x09414c6f), slow: U4F28::from_bits(0x0bd0ed66) },
+CurveShape{ bend: U4F28::from_bits(0x097b161a), slow: U4F28::from_bits(0x0bb99be0) },
+CurveShape{ bend: U4F28::from_bits(0x09b4dfc6), slow: U4F28::from_bits(0x0ba26bf0) },
+CurveShape{ bend: U4F28::from_bits(0x09eea971), slow: U4F28::from_bits(0x0b8b5d88) },
+CurveShape{ bend: U4F28::from_bits(0x0a28731c), slow: U4F28::from_bits(0x0b74709a) },
+CurveShape{ bend: U4F28::from_bits(0x0a623cc7), slow: U4F28::from_bits(0x0b5da517) },
+CurveShape{ bend: U4F28::from_bits(0x0a9c0673), slow: U4F28::from_bits(0x0b46faef) },
+CurveShape{ bend: U4F28::from_bits(0x0ad5d01e), slow: U4F28::from_bits(0x0b307212) },
+CurveShape{ bend: U4F28::from_bits(0x0b0f99c9), slow: U4F28::from_bits(0x0b1a0a72) },
+CurveShape{ bend: U4F28::from_bits(0x0b496375), slow: U4F28::from_bits(0x0b03c3fc) },
+CurveShape{ bend: U4F28::from_bits(0x0b832d20), slow: U4F28::from_bits(0x0aed9ea2) },
+CurveShape{ bend: U4F28::from_bits(0x0bbcf6cb), slow: U4F28::from_bits(0x0ad79a51) },
+CurveShape{ bend: U4F28::from_bits(0x0bf6c077), slow: U4F28::from_bits(0x0ac1b6f9) },
+CurveShape{ bend: U4F28::from_bits(0x0c308a22), slow: U4F28::from_bits(0x0aabf489) },
+CurveShape{ bend: U4F28::from_bits(0x0c6a53cd), slow: U4F28::from_bits(0x0a9652ee) },
+CurveShape{ bend: U4F28::from_bits(0x0ca41d78), slow: U4F28::from_bits(0x0a80d217) },
+CurveShape{ bend: U4F28::from_bits(0x0cdde724), slow: U4F28::from_bits(0x0a6b71f1) },
+CurveShape{ bend: U4F28::from_bits(0x0d17b0cf), slow: U4F28::from_bits(0x0a563269) },
+CurveShape{ bend: U4F28::from_bits(0x0d517a7a), slow: U4F28::from_bits(0x0a41136e) },
+CurveShape{ bend: U4F28::from_bits(0x0d8b4426), slow: U4F28::from_bits(0x0a2c14ec) },
+CurveShape{ bend: U4F28::from_bits(0x0dc50dd1), slow: U4F28::from_bits(0x0a1736ce) },
+CurveShape{ bend: U4F28::from_bits(0x0dfed77c), slow: U4F28::from_bits(0x0a027903) },
+CurveShape{ bend: U4F28::from_bits(0x0e38a127), slow: U4F28::from_bits(0x09eddb75) },
+CurveShape{ bend: U4F28::from_bits(0x0e726ad3), slow: U4F28::from_bits(0x09d95e11) },
+CurveShape{ bend: U4F28::from_bits(0x0eac347e), slow: U4F28::from_bits(0x09c500c1) },
+CurveShape{ bend: U4F28::from_bits(0x0ee5fe29), slow: U4F28::from_bits(0x09b0c372) },
+CurveShape{ bend: U4F28::from_bits(0x0f1fc7d5), slow: U4F28::from_bits(0x099ca60e) },
+CurveShape{ bend: U4F28::from_bits(0x0f599180), slow: U4F28::from_bits(0x0988a881) },
+CurveShape{ bend: U4F28::from_bits(0x0f935b2b), slow: U4F28::from_bits(0x0974cab4) },
+CurveShape{ bend: U4F28::from_bits(0x0fcd24d6), slow: U4F28::from_bits(0x09610c91) },
+CurveShape{ bend: U4F28::from_bits(0x1006ee82), slow: U4F28::from_bits(0x094d6e04) },
+CurveShape{ bend: U4F28::from_bits(0x1040b82d), slow: U4F28::from_bits(0x0939eef5) },
+CurveShape{ bend: U4F28::from_bits(0x107a81d8), slow: U4F28::from_bits(0x09268f4e) },
+CurveShape{ bend: U4F28::from_bits(0x10b44b84), slow: U4F28::from_bits(0x09134ef8) },
+CurveShape{ bend: U4F28::from_bits(0x10ee152f), slow: U4F28::from_bits(0x09002ddd) },
+CurveShape{ bend: U4F28::from_bits(0x1127deda), slow: U4F28::from_bits(0x08ed2be5) },
+CurveShape{ bend: U4F28::from_bits(0x1161a886), slow: U4F28::from_bits(0x08da48f9) },
+CurveShape{ bend: U4F28::from_bits(0x119b7231), slow: U4F28::from_bits(0x08c78500) },
+CurveShape{ bend: U4F28::from_bits(0x11d53bdc), slow: U4F28::from_bits(0x08b4dfe5) },
+CurveShape{ bend: U4F28::from_bits(0x120f0587), slow: U4F28::from_bits(0x08a2598d) },
+CurveShape{ bend: U4F28::from_bits(0x1248cf33), slow: U4F28::from_bits(0x088ff1e1) },
+CurveShape{ bend: U4F28::from_bits(0x128298de), slow: U4F28::from_bits(0x087da8c9) },
+CurveShape{ bend: U4F28::from_bits(0x12bc6289), slow: U4F28::from_bits(0x086b7e2b) },
+CurveShape{ bend: U4F28::from_bits(0x12f62c35), slow: U4F28::from_bits(0x085971ef) },
+CurveShape{ bend: U4F28::from_bits(0x132ff5e0), slow: U4F28::from_bits(0x084783fb) },
+CurveShape{ bend: U4F28::from_bits(0x1369bf8b), slow: U4F28::from_bits(0x0835b436) },
+CurveShape{ bend: U4F28::from_bits(0x13a38936), slow: U4F28::from_bits(0x08240287) },
+CurveShape{ bend: U4F28::from_bits(0x13dd52e2), slow: U4F28::from_bits(0x08126ed4) },
+CurveShape{ bend: U4F28::from_bits(0x14171c8d), slow: U4F28::from_bits(0x0800f903) },
+CurveShape{ bend: U4F28::from_bits(0x1450e638), slow: U4F28::from_bits(0x07efa0f9) },
+CurveShape{ bend: U4F28::from_bits(0x148aafe4), slow: U4F28::from_bits(0x07de669d) },
+CurveShape{ bend: U4F28::from_bits(0x14c4798f), slow: U4F28::from_bits(0x07cd49d4) },
+CurveShape{ bend: U4F28::from_bits(0x14fe433a), slow: U4F28::from_bits(0x07bc4a83) },
+CurveShape{ bend: U4F28::from_bits(0x15380ce6), slow: U4F28::from_bits(0x07ab6890) },
+CurveShape{ bend: U4F28::from_bits(0x1571d691), slow: U4F28::from_bits(0x079aa3df) },
+CurveShape{ bend: U4F28::from_bits(0x15aba03c), slow: U4F28::from_bits(0x0789fc54) },
+CurveShape{ bend: U4F28::from_bits(0x15e569e7), slow: U4F28::from_bits(0x077971d6) },
+CurveShape{ bend: U4F28::from_bits(0x161f3393), slow: U4F28::from_bits(0x07690448) },
+CurveShape{ bend: U4F28::from_bits(0x1658fd3e), slow: U4F28::from_bits(0x0758b38e) },
+CurveShape{ bend: U4F28::from_bits(0x1692c6e9), slow: U4F28::from_bits(0x07487f8d) },
+CurveShape{ bend: U4F28::from_bits(0x16cc9095), slow: U4F28::from_bits(0x07386829) },
+CurveShape{ bend: U4F28::from_bits(0x17065a40), slow: U4F28::from_bits(0x07286d44) },
+CurveShape{ bend: U4F28::from_bits(0x174023eb), slow: U4F28::from_bits(0x07188ec4) },
+CurveShape{ bend: U4F28::from_bits(0x1779ed96), slow: U4F28::from_bits(0x0708cc8c) },
+CurveShape{ bend: U4F28::from_bits(0x17b3b742), slow: U4F28::from_bits(0x06f9267e) },
+CurveShape{ bend: U4F28::from_bits(0x17ed80ed), slow: U4F28::from_bits(0x06e99c7f) },
+CurveShape{ bend: U4F28::from_bits(0x18274a98), slow: U4F28::from_bits(0x06da2e71) },
+CurveShape{ bend: U4F28::from_bits(0x18611444), slow: U4F28::from_bits(0x06cadc37) },
+CurveShape{ bend: U4F28::from_bits(0x189addef), slow: U4F28::from_bits(0x06bba5b4) },
+CurveShape{ bend: U4F28::from_bits(0x18d4a79a), slow: U4F28::from_bits(0x06ac8acb) },
+CurveShape{ bend: U4F28::from_bits(0x190e7145), slow: U4F28::from_bits(0x069d8b5f) },
+CurveShape{ bend: U4F28::from_bits(0x19483af1), slow: U4F28::from_bits(0x068ea751) },
+CurveShape{ bend: U4F28::from_bits(0x1982049c), slow: U4F28::from_bits(0x067fde84) },
+CurveShape{ bend: U4F28::from_bits(0x19bbce47), slow: U4F28::from_bits(0x067130db) },
+CurveShape{ bend: U4F28::from_bits(0x19f597f3), slow: U4F28::from_bits(0x06629e38) },
+CurveShape{ bend: U4F28::from_bits(0x1a2f619e), slow: U4F28::from_bits(0x0654267c) },
+CurveShape{ bend: U4F28::from_bits(0x1a692b49), slow: U4F28::from_bits(0x0645c989) },
+CurveShape{ bend: U4F28::from_bits(0x1aa2f4f5), slow: U4F28::from_bits(0x06378742) },
+CurveShape{ bend: U4F28::from_bits(0x1adcbea0), slow: U4F28::from_bits(0x06295f87) },
+CurveShape{ bend: U4F28::from_bits(0x1b16884b), slow: U4F28::from_bits(0x061b523b) },
+CurveShape{ bend: U4F28::from_bits(0x1b5051f6), slow: U4F28::from_bits(0x060d5f3f) },
+CurveShape{ bend: U4F28::from_bits(0x1b8a1ba2), slow: U4F28::from_bits(0x05ff8675) },
+CurveShape{ bend: U4F28::from_bits(0x1bc3e54d), slow: U4F28::from_bits(0x05f1c7bd) },
+CurveShape{ bend: U4F28::from_bits(0x1bfdaef8), slow: U4F28::from_bits(0x05e422fa) },
+CurveShape{ bend: U4F28::from_bits(0x1c3778a4), slow: U4F28::from_bits(0x05d6980b) },
+CurveShape{ bend: U4F28::from_bits(0x1c71424f), slow: U4F28::from_bits(0x05c926d3) },
+CurveShape{ bend: U4F28::from_bits(0x1cab0bfa), slow: U4F28::from_bits(0x05bbcf32) }
 ];
+
+// Samples spent in the delay stage, where 0 skips it
+pub static DELAY_TIME_TABLE: [u32; 256] = [0, 48, 63, 78, 94, 110, 126, 143, 160, 177, 195, 213, 231, 249, 268, 288, 307, 327, 348, 369, 390, 411, 433, 456, 479, 502, 526, 550, 575, 600, 626, 652, 678, 706, 733, 761, 790, 819, 849, 880, 911, 942, 974, 1007, 1041, 1075, 1109, 1145, 1181, 1217, 1255, 1293, 1332, 1372, 1412, 1453, 1495, 1538, 1581, 1626, 1671, 1717, 1764, 1812, 1861, 1910, 1961, 2013, 2065, 2119, 2174, 2229, 2286, 2344, 2403, 2463, 2524, 2587, 2650, 2715, 2781, 2849, 2917, 2987, 3058, 3131, 3205, 3281, 3357, 3436, 3516, 3597, 3680, 3764, 3850, 3938, 4028, 4119, 4212, 4306, 4403, 4501, 4601, 4703, 4807, 4913, 5022, 5132, 5244, 5358, 5475, 5594, 5715, 5838, 5964, 6092, 6223, 6356, 6491, 6630, 6770, 6914, 7060, 7209, 7361, 7516, 7674, 7835, 7999, 8166, 8336, 8509, 8686, 8866, 9050, 9237, 9428, 9622, 9820, 10022, 10228, 10437, 10651, 10869, 11091, 11317, 11547, 11782, 12021, 12265, 12514, 12767, 13025, 13288, 13556, 13830, 14108, 14392, 14681, 14976, 15276, 15582, 15894, 16212, 16536, 16866, 17203, 17546, 17895, 18251, 18614, 18984, 19361, 19745, 20137, 20536, 20942, 21357, 21779, 22210, 22648, 23095, 23551, 24015, 24488, 24970, 25462, 25962, 26473, 26993, 27523, 28063, 28613, 29174, 29746, 30329, 30922, 31527, 32144, 32773, 33413, 34066, 34731, 35409, 36100, 36804, 37521, 38252, 38998, 39757, 40531, 41320, 42123, 42943, 43777, 44628, 45495, 46379, 47279, 48197, 49132, 50085, 51057, 52047, 53055, 54083, 55131, 56199, 57287, 58396, 59526, 60678, 61852, 63048, 64267, 65509, 66775, 68065, 69380, 70720, 72086, 73478, 74896, 76342, 77815, 79316, 80846, 82405, 83994, 85613, 87263, 88945, 90659, 92406, 94186, 96000];
+
+// Samples spent in the hold stage, where 0 skips it
+pub static HOLD_TIME_TABLE: [u32; 256] = [0, 48, 86, 124, 163, 203, 244, 286, 328, 371, 415, 460, 505, 552, 599, 647, 696, 747, 798, 850, 903, 957, 1012, 1068, 1125, 1184, 1243, 1304, 1365, 1428, 1492, 1558, 1624, 1692, 1761, 1832, 1904, 1977, 2052, 2128, 2205, 2284, 2364, 2446, 2530, 2615, 2702, 2790, 2881, 2972, 3066, 3162, 3259, 3358, 3459, 3562, 3667, 3774, 3882, 3993, 4107, 4222, 4339, 4459, 4581, 4706, 4832, 4961, 5093, 5227, 5364, 5503, 5645, 5790, 5937, 6088, 6241, 6397, 6556, 6718, 6883, 7052, 7223, 7398, 7576, 7758, 7943, 8132, 8324, 8520, 8719, 8923, 9130, 9342, 9557, 9776, 10000, 10228, 10460, 10697, 10938, 11184, 11435, 11690, 11950, 12215, 12486, 12761, 13042, 13328, 13619, 13916, 14219, 14528, 14842, 15162, 15489, 15822, 16161, 16507, 16859, 17218, 17584, 17957, 18337, 18724, 19119, 19521, 19931, 20348, 20774, 21208, 21650, 22100, 22560, 23028, 23504, 23990, 24486, 24990, 25505, 26029, 26563, 27108, 27663, 28228, 28804, 29392, 29990, 30600, 31222, 31855, 32501, 33159, 33829, 34512, 35209, 35918, 36642, 37379, 38130, 38895, 39675, 40471, 41281, 42106, 42948, 43805, 44679, 45570, 46478, 47403, 48345, 49306, 50285, 51283, 52300, 53336, 54392, 55469, 56565, 57683, 58822, 59983, 61166, 62372, 63601, 64853, 66129, 67430, 68755, 70106, 71483, 72885, 74315, 75772, 77257, 78770, 80312, 81884, 83486, 85118, 86781, 88476, 90204, 91965, 93759, 95588, 97451, 99350, 101286, 103258, 105268, 107317, 109404, 111532, 113700, 115910, 118162, 120457, 122795, 125179, 127608, 130083, 132606, 135177, 137797, 140467, 143188, 145962, 148788, 151668, 154603, 157594, 160643, 163750, 166916, 170142, 173431, 176782, 180197, 183677, 187224, 190839, 194523, 198277, 202103, 206002, 209976, 214025, 218152, 222358, 226644, 231012, 235463, 240000];
+
+// Sustain level in tenths of a decibel below full scale, where 0 is silent
+pub static SUSTAIN_LEVEL_TABLE: [i16; 256] = [-32768, -482, -421, -386, -361, -342, -326, -313, -301, -291, -282, -273, -266, -259, -252, -246, -241, -236, -231, -226, -221, -217, -213, -209, -206, -202, -199, -195, -192, -189, -186, -183, -181, -178, -175, -173, -170, -168, -166, -163, -161, -159, -157, -155, -153, -151, -149, -147, -145, -144, -142, -140, -138, -137, -135, -134, -132, -130, -129, -127, -126, -125, -123, -122, -120, -119, -118, -116, -115, -114, -113, -111, -110, -109, -108, -107, -105, -104, -103, -102, -101, -100, -99, -98, -97, -96, -95, -94, -93, -92, -91, -90, -89, -88, -87, -86, -85, -84, -83, -83, -82, -81, -80, -79, -78, -77, -77, -76, -75, -74, -73, -73, -72, -71, -70, -70, -69, -68, -67, -67, -66, -65, -64, -64, -63, -62, -62, -61, -60, -60, -59, -58, -58, -57, -56, -56, -55, -54, -54, -53, -52, -52, -51, -51, -50, -49, -49, -48, -48, -47, -46, -46, -45, -45, -44, -44, -43, -42, -42, -41, -41, -40, -40, -39, -39, -38, -38, -37, -37, -36, -36, -35, -35, -34, -34, -33, -33, -32, -32, -31, -31, -30, -30, -29, -29, -28, -28, -27, -27, -26, -26, -25, -25, -25, -24, -24, -23, -23, -22, -22, -21, -21, -21, -20, -20, -19, -19, -18, -18, -18, -17, -17, -16, -16, -16, -15, -15, -14, -14, -14, -13, -13, -12, -12, -12, -11, -11, -10, -10, -10, -9, -9, -9, -8, -8, -7, -7, -7, -6, -6, -6, -5, -5, -5, -4, -4, -3, -3, -3, -2, -2, -2, -1, -1, -1, 0];
+
@@ -0,0 +1,209 @@
+use fixed::types::{I1F31, U4F28};
+
+use crate::adsr::BaseAndCoefficient;
+use crate::adsr::config_table::{
+    FALL_CURVE_TABLE, FALL_TIME_TABLE, RISE_CURVE_TABLE, RISE_TIME_TABLE,
+};
+
+/// Curve of a straight line. Lower curves bend towards the RC curve, which moves fast at
+/// first and slows down, and higher ones towards it mirrored, which starts slow and speeds up.
+pub const LINEAR_CURVE: u8 = 128;
+
+/// How a curve bends, over a stage that covers the full scale
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurveShape {
+    /// Natural log of how much further the asymptote is from the end of the stage than
+    /// from its start, 0 for a straight line
+    pub bend: U4F28,
+    /// Slope at the slow end of the stage, in full scales per stage length
+    pub slow: U4F28,
+}
+
+/// Rising from silence to full scale over the attack time
+pub(crate) fn rise_base_and_coefficient(time_config: u8, curve: u8) -> BaseAndCoefficient {
+    base_and_coefficient(
+        RISE_CURVE_TABLE[curve as usize],
+        RISE_TIME_TABLE[time_config as usize],
+        curve,
+        true,
+    )
+}
+
+/// Falling from full scale to silence over the decay or release time
+pub(crate) fn fall_base_and_coefficient(time_config: u8, curve: u8) -> BaseAndCoefficient {
+    base_and_coefficient(
+        FALL_CURVE_TABLE[curve as usize],
+        FALL_TIME_TABLE[time_config as usize],
+        curve,
+        false,
+    )
+}
+
+/// Divides a U4F28 by a sample count, into an I1F31
+fn per_sample(value: U4F28, samples: u32) -> i64 {
+    ((value.to_bits() as i64) << 3) / samples.max(1) as i64
+}
+
+fn base_and_coefficient(
+    shape: CurveShape,
+    samples: u32,
+    curve: u8,
+    rising: bool,
+) -> BaseAndCoefficient {
+    let towards_asymptote = curve <= LINEAR_CURVE;
+
+    /*
+     * Every sample the distance to the asymptote is multiplied by e^-x when moving towards
+     * it, or e^x when moving away from it, where x is the bend over the length of the
+     * stage. Those are approximated to the second order, which is plenty for the shortest
+     * stages.
+     */
+    let x = per_sample(shape.bend, samples);
+    let half_x_squared = (x * x) >> 32;
+    let coefficient = if towards_asymptote {
+        -x + half_x_squared
+    } else {
+        x + half_x_squared
+    };
+
+    // The step taken at silence, where a rise starts and a fall ends, corrected for the
+    // curve over that sample
+    let slow = per_sample(shape.slow, samples);
+    let fast = slow + x;
+    let slope = match (towards_asymptote, rising) {
+        (true, true) => fast,
+        (true, false) => -slow,
+        (false, true) => slow,
+        (false, false) => -fast,
+    };
+    let half_step = (slope * x) >> 32;
+    let base = if towards_asymptote {
+        slope - half_step
+    } else {
+        slope + half_step
+    };
+
+    BaseAndCoefficient {
+        base: I1F31::from_bits(base as i32),
+        coefficient: I1F31::from_bits(coefficient as i32),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capacitor::Capacitor;
+
+    const TIMES: [u8; 4] = [0, 1, 128, 255];
+    const CURVES: [u8; 5] = [0, 64, LINEAR_CURVE, 192, 255];
+
+    /// Levels of a full scale stage, sample by sample until it reaches the end
+    fn run_stage(coefficient: BaseAndCoefficient, rising: bool) -> Vec<I1F31> {
+        let (start, end) = if rising {
+            (I1F31::ZERO, I1F31::MAX)
+        } else {
+            (I1F31::MAX, I1F31::ZERO)
+        };
+
+        let mut level = start;
+        let mut levels = Vec::new();
+        while level != end && levels.len() < 1_000_000 {
+            level = Capacitor::iterate(level, coefficient);
+            level = if rising {
+                level.min(end)
+            } else {
+                level.max(end)
+            };
+            levels.push(level);
+        }
+        levels
+    }
+
+    fn assert_lasts(levels: &[I1F31], samples: u32, time: u8, curve: u8) {
+        let error = (levels.len() as f64 - samples as f64).abs() / samples as f64;
+        assert!(
+            error < 0.01,
+            "Time {} with curve {} took {} samples instead of {}",
+            time,
+            curve,
+            levels.len(),
+            samples
+        );
+    }
+
+    #[test]
+    fn test_every_curve_keeps_the_rise_time() {
+        for time in TIMES {
+            for curve in CURVES {
+                let levels = run_stage(rise_base_and_coefficient(time, curve), true);
+                assert_lasts(&levels, RISE_TIME_TABLE[time as usize], time, curve);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_curve_keeps_the_fall_time() {
+        for time in TIMES {
+            for curve in CURVES {
+                let levels = run_stage(fall_base_and_coefficient(time, curve), false);
+                assert_lasts(&levels, FALL_TIME_TABLE[time as usize], time, curve);
+            }
+        }
+    }
+
+    #[test]
+    fn test_curves_bend_from_exponential_to_logarithmic() {
+        // Halfway through the stage, from the most exponential to the most logarithmic
+        let halfway = |levels: &[I1F31]| levels[levels.len() / 2].to_num::<f64>();
+
+        let rises: Vec<f64> = CURVES
+            .iter()
+            .map(|&curve| halfway(&run_stage(rise_base_and_coefficient(128, curve), true)))
+            .collect();
+        let falls: Vec<f64> = CURVES
+            .iter()
+            .map(|&curve| halfway(&run_stage(fall_base_and_coefficient(128, curve), false)))
+            .collect();
+
+        assert!(
+            rises.windows(2).all(|pair| pair[0] > pair[1]),
+            "Rises should get slower at first as the curve goes up: {:?}",
+            rises
+        );
+        assert!(
+            falls.windows(2).all(|pair| pair[0] < pair[1]),
+            "Falls should get slower at first as the curve goes up: {:?}",
+            falls
+        );
+
+        assert!((rises[2] - 0.5).abs() < 0.01, "Linear rise: {}", rises[2]);
+        assert!((falls[2] - 0.5).abs() < 0.01, "Linear fall: {}", falls[2]);
+        // The mirrored curves are the same distance from the straight line
+        assert!((rises[0] - 0.5 - (0.5 - rises[4])).abs() < 0.01);
+        assert!((falls[0] - 0.5 - (0.5 - falls[4])).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_linear_curve_moves_in_equal_steps() {
+        for (levels, rising) in [
+            (
+                run_stage(rise_base_and_coefficient(128, LINEAR_CURVE), true),
+                true,
+            ),
+            (
+                run_stage(fall_base_and_coefficient(128, LINEAR_CURVE), false),
+                false,
+            ),
+        ] {
+            let first = levels[1] - levels[0];
+            let middle = levels[levels.len() / 2] - levels[levels.len() / 2 - 1];
+            assert!(
+                (first - middle).abs() <= I1F31::from_bits(2),
+                "Rising: {}, steps {} and {}",
+                rising,
+                first,
+                middle
+            );
+        }
+    }
+}
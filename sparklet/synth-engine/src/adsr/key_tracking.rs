@@ -0,0 +1,88 @@
+use cmsis_interface::Q15;
+
+/// Key the envelope is tracked around, middle C
+const CENTER_KEY: i16 = 60;
+
+/// Steps into the time tables a key moves at full tracking, which scales the times by
+/// close to two every octave
+const FULL_TRACKING_STEPS: i32 = 2;
+
+/// How the envelope of a voice changes across the keyboard, around middle C.
+///
+/// Higher keys get shorter times and lower keys longer ones, like the strings of an
+/// acoustic instrument. Every amount goes from 0, which tracks nothing, to 255.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyTracking {
+    pub attack: u8,
+    pub decay_release: u8,
+    /// How much quieter the keys above middle C get, leaving the ones below alone so
+    /// that nothing gets louder than it was
+    pub amplitude: u8,
+}
+
+impl KeyTracking {
+    /// Offset into the time tables for `note`, with two steps a key at full tracking
+    pub(crate) fn time_offset(amount: u8, note: u8) -> i16 {
+        let keys = note as i32 - CENTER_KEY as i32;
+
+        (-keys * amount as i32 * FULL_TRACKING_STEPS / 256) as i16
+    }
+
+    /// Gain of the envelope for `note`, falling to silence 64 keys above middle C
+    /// at full tracking
+    pub(crate) fn gain(&self, note: u8) -> Q15 {
+        let above = (note as i32 - CENTER_KEY as i32).max(0);
+        let distance = (above << 9).min(i16::MAX as i32);
+        let taken = distance * self.amplitude as i32 / 255;
+
+        Q15::from_bits((i16::MAX as i32 - taken) as i16)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_offset_is_centered_on_middle_c() {
+        for amount in [0, 1, 128, 255] {
+            assert_eq!(KeyTracking::time_offset(amount, 60), 0);
+        }
+        assert_eq!(KeyTracking::time_offset(0, 127), 0);
+
+        // Shorter above, longer below, about two steps a key at full tracking
+        assert!((-25..=-23).contains(&KeyTracking::time_offset(255, 72)));
+        assert!((23..=25).contains(&KeyTracking::time_offset(255, 48)));
+        assert!((-13..=-11).contains(&KeyTracking::time_offset(128, 72)));
+    }
+
+    #[test]
+    fn test_time_offset_is_monotonic() {
+        for amount in [0, 64, 255] {
+            let offsets: Vec<i16> = (0..=127)
+                .map(|note| KeyTracking::time_offset(amount, note))
+                .collect();
+            assert!(offsets.windows(2).all(|pair| pair[0] >= pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_gain_only_takes_level_away_above_middle_c() {
+        let tracking = KeyTracking {
+            amplitude: 255,
+            ..Default::default()
+        };
+
+        for note in 0..=60 {
+            assert_eq!(tracking.gain(note), Q15::MAX);
+        }
+        assert!((tracking.gain(92).to_num::<f64>() - 0.5).abs() < 0.01);
+        assert_eq!(tracking.gain(124), Q15::ZERO);
+        assert_eq!(tracking.gain(127), Q15::ZERO);
+
+        let gains: Vec<Q15> = (0..=127).map(|note| tracking.gain(note)).collect();
+        assert!(gains.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        assert_eq!(KeyTracking::default().gain(127), Q15::MAX);
+    }
+}
@@ -2,21 +2,54 @@ use cmsis_interface::Q15;
 use defmt::Format;
 use fixed::types::I1F31;
 
-use crate::adsr::config_table::{FALL_BASE_COEFFICIENT_TABLE, RISE_BASE_COEFFICIENT_TABLE};
+use crate::adsr::config_table::{DELAY_TIME_TABLE, HOLD_TIME_TABLE};
+use crate::adsr::curve::{fall_base_and_coefficient, rise_base_and_coefficient};
+use crate::adsr::key_tracking::KeyTracking;
 use crate::capacitor::{Capacitor, CapacitorStatus};
 
 pub mod config_table;
+pub mod curve;
+pub mod key_tracking;
+pub mod units;
 
+/// Every sample the envelope moves by `level * coefficient + base`. A negative coefficient
+/// pulls it towards an asymptote, and a positive one pushes it away.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BaseAndCoefficient {
     pub base: I1F31,
     pub coefficient: I1F31,
 }
 
+/// Whether the envelope goes back to the attack once the decay is over, while the key is held
+#[derive(Format, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeLoop {
+    /// Settles at the sustain level
+    #[default]
+    Off,
+    /// Loops this many times, and then settles at the sustain level
+    Times(u8),
+    Forever,
+}
+
+impl EnvelopeLoop {
+    /// 0 doesn't loop, 255 loops forever and anything in between is how many times to loop
+    pub fn for_config(loop_config: u8) -> Self {
+        match loop_config {
+            0 => Self::Off,
+            u8::MAX => Self::Forever,
+            times => Self::Times(times),
+        }
+    }
+}
+
 #[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ADSRStage {
     Idle,
+    /// Silence before the attack, with the samples left
+    Delay(u32),
     Attack,
+    /// Stays at the peak after the attack, with the samples left
+    Hold(u32),
     Decay,
     Sustain,
     Release,
@@ -24,7 +57,15 @@ pub(crate) enum ADSRStage {
 }
 
 impl ADSRStage {
-    pub(crate) fn play(&mut self) {
+    pub(crate) fn play(&mut self, adsr_config: &ADSRConfig) {
+        *self = match adsr_config.delay_samples {
+            0 => Self::Attack,
+            samples => Self::Delay(samples),
+        };
+    }
+
+    /// Goes straight to the attack, as the voice is already sounding
+    pub(crate) fn retrigger(&mut self) {
         *self = Self::Attack;
     }
 
@@ -46,33 +87,68 @@ impl ADSRStage {
         }
     }
 
+    /// Moves on a sample, with `loops` counting how many times the envelope has looped
     pub(crate) fn progress(
         &mut self,
         capacitor: &mut Capacitor,
         adsr_config: &ADSRConfig,
+        loops: &mut u8,
     ) -> I1F31 {
         match *self {
             Self::Idle => I1F31::ZERO,
+            Self::Delay(samples_left) => {
+                capacitor.set_target(I1F31::ZERO);
+                capacitor.step();
+                *self = match samples_left {
+                    0 | 1 => Self::Attack,
+                    samples_left => Self::Delay(samples_left - 1),
+                };
+                capacitor.get_level()
+            }
             Self::Attack => {
                 capacitor.set_target(adsr_config.velocity_amplitude);
                 let status = capacitor.step();
                 if status == CapacitorStatus::ReachedTarget {
-                    *self = Self::Decay;
+                    *self = match adsr_config.hold_samples {
+                        0 => Self::Decay,
+                        samples => Self::Hold(samples),
+                    };
                 }
                 capacitor.get_level()
             }
+            Self::Hold(samples_left) => {
+                capacitor.set_target(adsr_config.velocity_amplitude);
+                capacitor.step();
+                *self = match samples_left {
+                    0 | 1 => Self::Decay,
+                    samples_left => Self::Hold(samples_left - 1),
+                };
+                capacitor.get_level()
+            }
             Self::Decay => {
+                capacitor.set_fall_coeff(adsr_config.decay);
                 let decay_target = adsr_config
                     .sustain_level
                     .saturating_mul(adsr_config.velocity_amplitude);
                 capacitor.set_target(decay_target);
                 let status = capacitor.step();
                 if status == CapacitorStatus::ReachedTarget {
-                    *self = Self::Sustain;
+                    let looping = match adsr_config.envelope_loop {
+                        EnvelopeLoop::Off => false,
+                        EnvelopeLoop::Times(times) => *loops < times,
+                        EnvelopeLoop::Forever => true,
+                    };
+                    *self = if looping {
+                        *loops = loops.saturating_add(1);
+                        Self::Attack
+                    } else {
+                        Self::Sustain
+                    };
                 }
                 capacitor.get_level()
             }
             Self::Sustain => {
+                capacitor.set_fall_coeff(adsr_config.decay);
                 let decay_target = adsr_config
                     .sustain_level
                     .saturating_mul(adsr_config.velocity_amplitude);
@@ -81,6 +157,7 @@ impl ADSRStage {
                 capacitor.get_level()
             }
             Self::Release => {
+                capacitor.set_fall_coeff(adsr_config.release);
                 capacitor.set_target(I1F31::ZERO);
                 let status = capacitor.step();
                 if status == CapacitorStatus::ReachedTarget {
@@ -104,16 +181,77 @@ impl ADSRStage {
 pub(crate) struct ADSRConfig {
     sustain_level: I1F31,
     velocity_amplitude: I1F31,
+    attack_time: u8,
+    attack_curve: u8,
+    decay_time: u8,
+    decay_curve: u8,
+    release_time: u8,
+    release_curve: u8,
+    /// Rises to the peak, and towards the sustain level when it goes up
+    attack: BaseAndCoefficient,
+    /// Falls towards the sustain level, and follows it when it changes
+    decay: BaseAndCoefficient,
+    /// Falls to zero once the note is released
+    release: BaseAndCoefficient,
+    delay_samples: u32,
+    hold_samples: u32,
+    /// Offsets into the time tables for the key being played
+    attack_offset: i16,
+    decay_release_offset: i16,
+    /// Multiplies the velocity amplitude, for the key being played
+    key_gain: I1F31,
+    envelope_loop: EnvelopeLoop,
 }
 
 impl ADSRConfig {
-    pub(crate) fn new(sustain_config: u8, velocity: u8) -> Self {
+    pub(crate) fn new(
+        sustain_config: u8,
+        attack_config: u8,
+        decay_config: u8,
+        release_config: u8,
+        velocity: u8,
+    ) -> Self {
         Self {
             velocity_amplitude: Self::amplitude_for_velocity(velocity),
             sustain_level: Self::amplitude_for_sustain_config(sustain_config),
+            attack_time: attack_config,
+            attack_curve: 0,
+            decay_time: decay_config,
+            decay_curve: 0,
+            release_time: release_config,
+            release_curve: 0,
+            attack: rise_base_and_coefficient(attack_config, 0),
+            decay: fall_base_and_coefficient(decay_config, 0),
+            release: fall_base_and_coefficient(release_config, 0),
+            delay_samples: 0,
+            hold_samples: 0,
+            attack_offset: 0,
+            decay_release_offset: 0,
+            key_gain: I1F31::MAX,
+            envelope_loop: EnvelopeLoop::Off,
         }
     }
 
+    /// Moves the time index by `offset`, staying within the tables
+    fn tracked(time_config: u8, offset: i16) -> u8 {
+        (time_config as i16 + offset).clamp(0, u8::MAX as i16) as u8
+    }
+
+    fn update_attack(&mut self) {
+        let time = Self::tracked(self.attack_time, self.attack_offset);
+        self.attack = rise_base_and_coefficient(time, self.attack_curve);
+    }
+
+    fn update_decay(&mut self) {
+        let time = Self::tracked(self.decay_time, self.decay_release_offset);
+        self.decay = fall_base_and_coefficient(time, self.decay_curve);
+    }
+
+    fn update_release(&mut self) {
+        let time = Self::tracked(self.release_time, self.decay_release_offset);
+        self.release = fall_base_and_coefficient(time, self.release_curve);
+    }
+
     pub(crate) fn amplitude_for_velocity(velocity: u8) -> I1F31 {
         bytemuck::cast::<i32, I1F31>((velocity as i32) << 24)
     }
@@ -125,12 +263,71 @@ impl ADSRConfig {
     pub(crate) fn set_velocity(&mut self, velocity: u8) {
         // We shift 24 because velocity is actually a u7
         self.velocity_amplitude = Self::amplitude_for_velocity(velocity);
+        // Full scale leaves the velocity as it is, rather than a hair below it
+        if self.key_gain != I1F31::MAX {
+            self.velocity_amplitude = self.velocity_amplitude.saturating_mul(self.key_gain);
+        }
+    }
+
+    pub(crate) fn sustain_config(&self) -> u8 {
+        (self.sustain_level.to_bits() >> 23) as u8
     }
 
     pub(crate) fn set_sustain(&mut self, sustain_config: u8) {
         // We shift 23 because first bit is for sign
         self.sustain_level = Self::amplitude_for_sustain_config(sustain_config);
     }
+
+    pub(crate) fn set_attack(&mut self, attack_config: u8) {
+        self.attack_time = attack_config;
+        self.update_attack();
+    }
+
+    pub(crate) fn set_attack_curve(&mut self, curve: u8) {
+        self.attack_curve = curve;
+        self.update_attack();
+    }
+
+    pub(crate) fn set_decay(&mut self, decay_config: u8) {
+        self.decay_time = decay_config;
+        self.update_decay();
+    }
+
+    pub(crate) fn set_decay_curve(&mut self, curve: u8) {
+        self.decay_curve = curve;
+        self.update_decay();
+    }
+
+    pub(crate) fn set_release(&mut self, release_config: u8) {
+        self.release_time = release_config;
+        self.update_release();
+    }
+
+    pub(crate) fn set_release_curve(&mut self, curve: u8) {
+        self.release_curve = curve;
+        self.update_release();
+    }
+
+    pub(crate) fn set_delay(&mut self, delay_config: u8) {
+        self.delay_samples = DELAY_TIME_TABLE[delay_config as usize];
+    }
+
+    pub(crate) fn set_hold(&mut self, hold_config: u8) {
+        self.hold_samples = HOLD_TIME_TABLE[hold_config as usize];
+    }
+
+    pub(crate) fn set_loop(&mut self, envelope_loop: EnvelopeLoop) {
+        self.envelope_loop = envelope_loop;
+    }
+
+    pub(crate) fn set_key_tracking(&mut self, key_tracking: &KeyTracking, note: u8) {
+        self.attack_offset = KeyTracking::time_offset(key_tracking.attack, note);
+        self.decay_release_offset = KeyTracking::time_offset(key_tracking.decay_release, note);
+        self.key_gain = key_tracking.gain(note).into();
+        self.update_attack();
+        self.update_decay();
+        self.update_release();
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -138,6 +335,8 @@ pub struct ADSR {
     pub(crate) stage: ADSRStage,
     pub(crate) config: ADSRConfig,
     pub(crate) capacitor: Capacitor,
+    /// Times the envelope has looped since the note started
+    loops: u8,
 }
 
 impl Format for ADSR {
@@ -150,29 +349,37 @@ impl ADSR {
     pub fn new(
         sustain_config: u8,
         attack_config: u8,
-        decay_release_config: u8,
+        decay_config: u8,
+        release_config: u8,
         velocity: u8,
     ) -> ADSR {
-        let config = ADSRConfig::new(sustain_config, velocity);
-        let rise_base_and_coefficient = RISE_BASE_COEFFICIENT_TABLE[attack_config as usize];
-        let fall_base_and_coefficient = FALL_BASE_COEFFICIENT_TABLE[decay_release_config as usize];
+        let config = ADSRConfig::new(
+            sustain_config,
+            attack_config,
+            decay_config,
+            release_config,
+            velocity,
+        );
         ADSR {
             stage: ADSRStage::Idle,
-            capacitor: Capacitor::new(rise_base_and_coefficient, fall_base_and_coefficient),
+            capacitor: Capacitor::new(config.attack, config.decay),
             config,
+            loops: 0,
         }
     }
 
     pub fn play(&mut self, velocity: u8) {
         self.capacitor.set_level(I1F31::ZERO);
         self.config.set_velocity(velocity);
-        self.stage.play()
+        self.loops = 0;
+        self.stage.play(&self.config)
     }
 
     pub fn retrigger(&mut self, velocity: u8) {
         // Don't reset capacitor level on retrigger - continue from current
         self.config.set_velocity(velocity);
-        self.stage.play()
+        self.loops = 0;
+        self.stage.retrigger()
     }
 
     pub fn stop_playing(&mut self) {
@@ -185,7 +392,9 @@ impl ADSR {
 
     pub fn get_samples<const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
         for elem in buffer.iter_mut() {
-            let output = self.stage.progress(&mut self.capacitor, &self.config);
+            let output = self
+                .stage
+                .progress(&mut self.capacitor, &self.config, &mut self.loops);
             *elem = fixed::traits::LossyInto::lossy_into(output);
         }
     }
@@ -195,13 +404,98 @@ impl ADSR {
     }
 
     pub fn set_attack(&mut self, attack_config: u8) {
-        let rise_base_and_coefficient = RISE_BASE_COEFFICIENT_TABLE[attack_config as usize];
-        self.capacitor.set_rise_coeff(rise_base_and_coefficient);
+        self.config.set_attack(attack_config);
+        self.capacitor.set_rise_coeff(self.config.attack);
+    }
+
+    pub fn set_decay(&mut self, decay_config: u8) {
+        self.config.set_decay(decay_config);
+    }
+
+    pub fn set_release(&mut self, release_config: u8) {
+        self.config.set_release(release_config);
+    }
+
+    /// 0 is the RC curve, LINEAR_CURVE a straight line and 255 the RC curve mirrored
+    pub fn set_attack_curve(&mut self, curve: u8) {
+        self.config.set_attack_curve(curve);
+        self.capacitor.set_rise_coeff(self.config.attack);
+    }
+
+    pub fn set_decay_curve(&mut self, curve: u8) {
+        self.config.set_decay_curve(curve);
+    }
+
+    pub fn set_release_curve(&mut self, curve: u8) {
+        self.config.set_release_curve(curve);
+    }
+
+    /// 0 starts the attack as soon as the note is played
+    pub fn set_delay(&mut self, delay_config: u8) {
+        self.config.set_delay(delay_config);
     }
 
+    /// 0 starts the decay as soon as the attack reaches the peak
+    pub fn set_hold(&mut self, hold_config: u8) {
+        self.config.set_hold(hold_config);
+    }
+
+    /// Takes effect the next time the decay is over
+    pub fn set_loop(&mut self, envelope_loop: EnvelopeLoop) {
+        self.config.set_loop(envelope_loop);
+    }
+
+    /// Follows `note` with the times and, from the next time it's played, the level
+    pub fn set_key_tracking(&mut self, key_tracking: &KeyTracking, note: u8) {
+        self.config.set_key_tracking(key_tracking, note);
+        self.capacitor.set_rise_coeff(self.config.attack);
+    }
+
+    /// Sets the decay and the release to the same time, for envelopes with a single control for both
     pub fn set_decay_release(&mut self, decay_release_config: u8) {
-        let fall_base_and_coefficient = FALL_BASE_COEFFICIENT_TABLE[decay_release_config as usize];
-        self.capacitor.set_fall_coeff(fall_base_and_coefficient);
+        self.set_decay(decay_release_config);
+        self.set_release(decay_release_config);
+    }
+
+    /// Uses the attack time closest to `ms`
+    pub fn set_attack_ms(&mut self, ms: u32) {
+        self.set_attack(units::attack_config_for_ms(ms));
+    }
+
+    pub fn set_decay_ms(&mut self, ms: u32) {
+        self.set_decay(units::decay_release_config_for_ms(ms));
+    }
+
+    pub fn set_release_ms(&mut self, ms: u32) {
+        self.set_release(units::decay_release_config_for_ms(ms));
+    }
+
+    /// Uses the sustain level closest to `level`, in tenths of a decibel below full scale
+    pub fn set_sustain_decibel_tenths(&mut self, level: i16) {
+        self.set_sustain(units::sustain_config_for_decibel_tenths(level));
+    }
+
+    /// Milliseconds the attack takes, before key tracking
+    pub fn attack_ms(&self) -> u32 {
+        units::attack_ms(self.config.attack_time)
+    }
+
+    pub fn decay_ms(&self) -> u32 {
+        units::decay_release_ms(self.config.decay_time)
+    }
+
+    pub fn release_ms(&self) -> u32 {
+        units::decay_release_ms(self.config.release_time)
+    }
+
+    /// Sustain level in tenths of a decibel below full scale
+    pub fn sustain_decibel_tenths(&self) -> i16 {
+        units::sustain_decibel_tenths(self.config.sustain_config())
+    }
+
+    /// Where the envelope is, velocity included
+    pub fn get_level(&self) -> Q15 {
+        fixed::traits::LossyInto::lossy_into(self.capacitor.get_level())
     }
 
     pub fn is_idle(&self) -> bool {
@@ -223,7 +517,7 @@ mod tests {
 
     // Helper functions
     fn create_test_adsr() -> ADSR {
-        ADSR::new(200, 50, 100, 100) // Medium sustain, fast attack/decay
+        ADSR::new(200, 50, 100, 100, 100) // Medium sustain, fast attack/decay
     }
 
     fn advance_to_stage(adsr: &mut ADSR, target_stage: ADSRStage, max_iterations: usize) -> bool {
@@ -245,7 +539,7 @@ mod tests {
 
     #[test]
     fn test_attack_to_decay_transition() {
-        let mut adsr = ADSR::new(200, 10, 100, 100); // Fast attack
+        let mut adsr = ADSR::new(200, 10, 100, 100, 100); // Fast attack
         adsr.play(100);
 
         assert_eq!(
@@ -279,7 +573,7 @@ mod tests {
 
     #[test]
     fn test_decay_to_sustain_transition() {
-        let mut adsr = ADSR::new(200, 10, 10, 100); // Fast attack and decay
+        let mut adsr = ADSR::new(200, 10, 10, 10, 100); // Fast attack and decay
         adsr.play(100);
 
         // Advance through attack
@@ -367,8 +661,8 @@ mod tests {
     #[test]
     fn test_quick_release_faster_than_normal() {
         // Create two ADSRs with slow release but fast attack/decay
-        let mut adsr_normal = ADSR::new(200, 10, 200, 100); // Fast attack, slow release (index 200)
-        let mut adsr_quick = ADSR::new(200, 10, 200, 100);
+        let mut adsr_normal = ADSR::new(200, 10, 200, 200, 100); // Fast attack, slow release (index 200)
+        let mut adsr_quick = ADSR::new(200, 10, 200, 200, 100);
 
         // Advance both to sustain
         adsr_normal.play(100);
@@ -415,7 +709,7 @@ mod tests {
 
     #[test]
     fn test_attack_envelope_increases_monotonically() {
-        let mut adsr = ADSR::new(200, 50, 100, 100); // Medium attack speed
+        let mut adsr = ADSR::new(200, 50, 100, 100, 100); // Medium attack speed
         adsr.play(100);
 
         let mut levels = Vec::new();
@@ -451,7 +745,7 @@ mod tests {
 
     #[test]
     fn test_decay_envelope_decreases_monotonically() {
-        let mut adsr = ADSR::new(100, 10, 100, 100); // Low sustain, fast attack
+        let mut adsr = ADSR::new(100, 10, 100, 100, 100); // Low sustain, fast attack
         adsr.play(100);
 
         // Advance to decay
@@ -549,8 +843,8 @@ mod tests {
 
     #[test]
     fn test_velocity_affects_attack_peak() {
-        let mut adsr_low = ADSR::new(200, 50, 100, 64); // Lower velocity
-        let mut adsr_high = ADSR::new(200, 50, 100, 127); // Higher velocity
+        let mut adsr_low = ADSR::new(200, 50, 100, 100, 64); // Lower velocity
+        let mut adsr_high = ADSR::new(200, 50, 100, 100, 127); // Higher velocity
 
         adsr_low.play(64);
         adsr_high.play(127);
@@ -578,8 +872,8 @@ mod tests {
 
     #[test]
     fn test_velocity_affects_sustain_amplitude() {
-        let mut adsr_low = ADSR::new(200, 10, 10, 64); // Faster attack and decay
-        let mut adsr_high = ADSR::new(200, 10, 10, 127);
+        let mut adsr_low = ADSR::new(200, 10, 10, 10, 64); // Faster attack and decay
+        let mut adsr_high = ADSR::new(200, 10, 10, 10, 127);
 
         adsr_low.play(64);
         adsr_high.play(127);
@@ -607,7 +901,7 @@ mod tests {
 
     #[test]
     fn test_velocity_zero_produces_zero() {
-        let mut adsr = ADSR::new(200, 50, 100, 1); // Minimum velocity
+        let mut adsr = ADSR::new(200, 50, 100, 100, 1); // Minimum velocity
         adsr.play(1);
 
         // Get samples through full envelope
@@ -636,8 +930,8 @@ mod tests {
 
     #[test]
     fn test_sustain_level_affects_decay_target() {
-        let mut adsr_high_sustain = ADSR::new(255, 50, 50, 100); // High sustain
-        let mut adsr_low_sustain = ADSR::new(50, 50, 50, 100); // Low sustain
+        let mut adsr_high_sustain = ADSR::new(255, 50, 50, 50, 100); // High sustain
+        let mut adsr_low_sustain = ADSR::new(50, 50, 50, 50, 100); // Low sustain
 
         adsr_high_sustain.play(100);
         adsr_low_sustain.play(100);
@@ -665,7 +959,7 @@ mod tests {
 
     #[test]
     fn test_sustain_zero_decays_to_zero() {
-        let mut adsr = ADSR::new(0, 50, 50, 100); // Zero sustain
+        let mut adsr = ADSR::new(0, 50, 50, 50, 100); // Zero sustain
         adsr.play(100);
 
         // Advance to sustain
@@ -688,7 +982,7 @@ mod tests {
 
     #[test]
     fn test_set_attack_while_playing() {
-        let mut adsr = ADSR::new(200, 200, 100, 100); // Slow attack
+        let mut adsr = ADSR::new(200, 200, 100, 100, 100); // Slow attack
         adsr.play(100);
 
         // Advance partway through attack
@@ -711,7 +1005,7 @@ mod tests {
 
     #[test]
     fn test_set_sustain_in_decay() {
-        let mut adsr = ADSR::new(200, 10, 100, 100);
+        let mut adsr = ADSR::new(200, 10, 100, 100, 100);
         adsr.play(100);
 
         // Advance to decay
@@ -752,8 +1046,8 @@ mod tests {
     }
 
     #[test]
-    fn test_set_decay_release_while_in_release() {
-        let mut adsr = ADSR::new(200, 10, 200, 100); // Fast attack, slow release
+    fn test_set_release_while_in_release() {
+        let mut adsr = ADSR::new(200, 10, 200, 200, 100); // Fast attack, slow release
         adsr.play(100);
 
         // Advance to sustain
@@ -772,7 +1066,7 @@ mod tests {
         }
 
         // Change to fast release
-        adsr.set_decay_release(10);
+        adsr.set_release(10);
 
         // Count iterations to idle with fast release
         let mut iterations = 0;
@@ -793,6 +1087,76 @@ mod tests {
         );
     }
 
+    /// Samples spent in decay and in release, playing until sustain and then releasing
+    fn decay_and_release_samples(adsr: &mut ADSR) -> (usize, usize) {
+        adsr.play(100);
+        assert!(advance_to_stage(adsr, ADSRStage::Decay, 50000));
+
+        let mut buffer = [Q15::ZERO; 1];
+        let mut decay_samples = 0;
+        while adsr.stage == ADSRStage::Decay && decay_samples < 500000 {
+            adsr.get_samples(&mut buffer);
+            decay_samples += 1;
+        }
+        assert_eq!(adsr.stage, ADSRStage::Sustain);
+
+        adsr.stop_playing();
+        let mut release_samples = 0;
+        while !adsr.is_idle() && release_samples < 500000 {
+            adsr.get_samples(&mut buffer);
+            release_samples += 1;
+        }
+        assert!(adsr.is_idle());
+
+        (decay_samples, release_samples)
+    }
+
+    #[test]
+    fn test_short_decay_long_release() {
+        let mut adsr = ADSR::new(100, 10, 10, 200, 100);
+        let (decay_samples, release_samples) = decay_and_release_samples(&mut adsr);
+
+        assert!(
+            release_samples > decay_samples * 10,
+            "Release should take much longer than decay, decay took {} and release {}",
+            decay_samples,
+            release_samples
+        );
+    }
+
+    #[test]
+    fn test_long_decay_short_release() {
+        let mut adsr = ADSR::new(100, 10, 200, 10, 100);
+        let (decay_samples, release_samples) = decay_and_release_samples(&mut adsr);
+
+        assert!(
+            decay_samples > release_samples * 10,
+            "Decay should take much longer than release, decay took {} and release {}",
+            decay_samples,
+            release_samples
+        );
+    }
+
+    #[test]
+    fn test_set_decay_does_not_change_release() {
+        let mut reference = ADSR::new(100, 10, 10, 100, 100);
+        let mut changed = ADSR::new(100, 10, 10, 100, 100);
+        let (_, reference_release) = decay_and_release_samples(&mut reference);
+
+        changed.set_decay(10);
+        changed.set_decay(250);
+        changed.set_decay(10);
+        changed.set_release(100);
+        let (_, changed_release) = decay_and_release_samples(&mut changed);
+        assert_eq!(reference_release, changed_release);
+
+        // Only the decay of a fresh envelope changes, the release stays the same
+        let mut slow_decay = ADSR::new(100, 10, 10, 100, 100);
+        slow_decay.set_decay(200);
+        let (_, slow_decay_release) = decay_and_release_samples(&mut slow_decay);
+        assert_eq!(reference_release, slow_decay_release);
+    }
+
     // 6. Retrigger Tests
 
     #[test]
@@ -1075,4 +1439,372 @@ mod tests {
             level_after_second_play
         );
     }
+
+    // 8. Delay and Hold Tests
+
+    #[test]
+    fn test_delay_is_silent_before_attack() {
+        let mut adsr = create_test_adsr();
+        adsr.set_delay(10);
+        let delay_samples = DELAY_TIME_TABLE[10] as usize;
+        adsr.play(100);
+
+        assert_eq!(adsr.stage, ADSRStage::Delay(delay_samples as u32));
+
+        let mut buffer = [Q15::ZERO; 1];
+        for _ in 0..delay_samples {
+            adsr.get_samples(&mut buffer);
+            assert_eq!(buffer[0], Q15::ZERO, "Should be silent during the delay");
+        }
+        assert_eq!(adsr.stage, ADSRStage::Attack);
+
+        adsr.get_samples(&mut buffer);
+        assert!(
+            buffer[0] > Q15::ZERO,
+            "Should start the attack after the delay"
+        );
+    }
+
+    #[test]
+    fn test_no_delay_starts_attack_right_away() {
+        let mut adsr = create_test_adsr();
+        adsr.set_delay(0);
+        adsr.play(100);
+
+        assert_eq!(adsr.stage, ADSRStage::Attack);
+    }
+
+    #[test]
+    fn test_release_during_delay() {
+        let mut adsr = create_test_adsr();
+        adsr.set_delay(200);
+        adsr.play(100);
+
+        let mut buffer = [Q15::ZERO; 1];
+        adsr.get_samples(&mut buffer);
+        adsr.stop_playing();
+
+        adsr.get_samples(&mut buffer);
+        assert_eq!(buffer[0], Q15::ZERO);
+        assert!(
+            adsr.is_idle(),
+            "Releasing before the attack should end the note"
+        );
+    }
+
+    #[test]
+    fn test_retrigger_skips_delay() {
+        let mut adsr = create_test_adsr();
+        adsr.set_delay(200);
+        adsr.play(100);
+        adsr.retrigger(100);
+
+        assert_eq!(adsr.stage, ADSRStage::Attack);
+    }
+
+    #[test]
+    fn test_hold_stays_at_peak() {
+        let mut adsr = create_test_adsr();
+        adsr.set_hold(20);
+        let hold_samples = HOLD_TIME_TABLE[20];
+        adsr.play(100);
+
+        assert!(
+            advance_to_stage(&mut adsr, ADSRStage::Hold(hold_samples), 50000),
+            "Should hold after the attack"
+        );
+        let peak = get_envelope_level(&adsr);
+        assert_eq!(peak, ADSRConfig::amplitude_for_velocity(100));
+
+        let mut buffer = [Q15::ZERO; 1];
+        for _ in 0..hold_samples {
+            assert_eq!(get_envelope_level(&adsr), peak, "Should stay at the peak");
+            adsr.get_samples(&mut buffer);
+        }
+        assert_eq!(adsr.stage, ADSRStage::Decay);
+
+        adsr.get_samples(&mut buffer);
+        assert!(
+            get_envelope_level(&adsr) < peak,
+            "Should decay after the hold"
+        );
+    }
+
+    #[test]
+    fn test_delay_and_hold_lengthen_the_envelope() {
+        let samples_to_sustain = |adsr: &mut ADSR| {
+            adsr.play(100);
+            let mut buffer = [Q15::ZERO; 1];
+            let mut samples: usize = 0;
+            while adsr.stage != ADSRStage::Sustain && samples < 500000 {
+                adsr.get_samples(&mut buffer);
+                samples += 1;
+            }
+            samples
+        };
+
+        let mut plain = create_test_adsr();
+        let mut delayed_and_held = create_test_adsr();
+        delayed_and_held.set_delay(50);
+        delayed_and_held.set_hold(50);
+
+        let plain_samples = samples_to_sustain(&mut plain);
+        let delayed_and_held_samples = samples_to_sustain(&mut delayed_and_held);
+
+        // The attack reaches the peak the same way, so only the stages in between differ
+        let extra = (DELAY_TIME_TABLE[50] + HOLD_TIME_TABLE[50]) as usize;
+        assert!(
+            delayed_and_held_samples.abs_diff(plain_samples + extra) <= 1,
+            "Expected {} more samples, got {} and {}",
+            extra,
+            plain_samples,
+            delayed_and_held_samples
+        );
+    }
+
+    // 9. Curve Tests
+
+    /// Level halfway through the time the stage would take over the full scale
+    fn halfway_through_attack(adsr: &mut ADSR, attack_config: u8) -> I1F31 {
+        let samples = crate::adsr::config_table::RISE_TIME_TABLE[attack_config as usize];
+        adsr.play(127);
+
+        let mut buffer = [Q15::ZERO; 1];
+        for _ in 0..samples / 2 {
+            adsr.get_samples(&mut buffer);
+        }
+        get_envelope_level(adsr)
+    }
+
+    #[test]
+    fn test_attack_curve() {
+        let mut exponential = ADSR::new(200, 100, 100, 100, 127);
+        let mut linear = ADSR::new(200, 100, 100, 100, 127);
+        let mut logarithmic = ADSR::new(200, 100, 100, 100, 127);
+        linear.set_attack_curve(curve::LINEAR_CURVE);
+        logarithmic.set_attack_curve(255);
+
+        let exponential = halfway_through_attack(&mut exponential, 100);
+        let linear = halfway_through_attack(&mut linear, 100);
+        let logarithmic = halfway_through_attack(&mut logarithmic, 100);
+
+        assert!(exponential > linear, "{} > {}", exponential, linear);
+        assert!(linear > logarithmic, "{} > {}", linear, logarithmic);
+    }
+
+    #[test]
+    fn test_release_curve_only_changes_the_release() {
+        // Curves are shaped over the full scale, so this releases from the top
+        let mut reference = ADSR::new(255, 10, 100, 100, 127);
+        let mut curved = ADSR::new(255, 10, 100, 100, 127);
+        curved.set_release_curve(255);
+
+        // Same until released
+        reference.play(127);
+        curved.play(127);
+        let mut reference_buffer = [Q15::ZERO; 128];
+        let mut curved_buffer = [Q15::ZERO; 128];
+        for _ in 0..100 {
+            reference.get_samples(&mut reference_buffer);
+            curved.get_samples(&mut curved_buffer);
+            assert_eq!(reference_buffer, curved_buffer);
+        }
+
+        // The logarithmic release starts slower
+        reference.stop_playing();
+        curved.stop_playing();
+        reference.get_samples(&mut reference_buffer);
+        curved.get_samples(&mut curved_buffer);
+        assert!(get_envelope_level(&curved) > get_envelope_level(&reference));
+    }
+
+    #[test]
+    fn test_set_attack_curve_while_in_attack() {
+        let mut adsr = ADSR::new(200, 200, 100, 100, 127);
+        let mut reference = ADSR::new(200, 200, 100, 100, 127);
+        adsr.play(127);
+        reference.play(127);
+
+        let mut buffer = [Q15::ZERO; 64];
+        adsr.get_samples(&mut buffer);
+        reference.get_samples(&mut buffer);
+        assert_eq!(get_envelope_level(&adsr), get_envelope_level(&reference));
+
+        // Switching to a straight line slows down the start of the RC curve
+        adsr.set_attack_curve(curve::LINEAR_CURVE);
+        adsr.get_samples(&mut buffer);
+        reference.get_samples(&mut buffer);
+        assert_eq!(adsr.stage, ADSRStage::Attack);
+        assert!(get_envelope_level(&adsr) < get_envelope_level(&reference));
+    }
+
+    // 10. Key Tracking Tests
+
+    #[test]
+    fn test_key_tracking_times() {
+        let samples_to_sustain = |key_tracking: &KeyTracking, note: u8| {
+            let mut adsr = create_test_adsr();
+            adsr.set_key_tracking(key_tracking, note);
+            adsr.play(100);
+
+            let mut buffer = [Q15::ZERO; 1];
+            let mut samples: usize = 0;
+            while adsr.stage != ADSRStage::Sustain && samples < 500000 {
+                adsr.get_samples(&mut buffer);
+                samples += 1;
+            }
+            samples
+        };
+        let tracking = KeyTracking {
+            attack: 255,
+            decay_release: 255,
+            amplitude: 0,
+        };
+
+        // Middle C, or no tracking, leaves the times alone
+        let untracked = samples_to_sustain(&KeyTracking::default(), 96);
+        assert_eq!(untracked, samples_to_sustain(&tracking, 60));
+
+        let high = samples_to_sustain(&tracking, 84);
+        let low = samples_to_sustain(&tracking, 36);
+        assert!(high * 2 < untracked, "{} vs {}", high, untracked);
+        assert!(low > untracked * 2, "{} vs {}", low, untracked);
+    }
+
+    #[test]
+    fn test_times_in_milliseconds() {
+        let mut adsr = create_test_adsr();
+        adsr.set_attack_ms(100);
+        adsr.set_decay_ms(2000);
+        adsr.set_release_ms(10);
+        adsr.set_sustain_decibel_tenths(-60);
+
+        let mut expected = create_test_adsr();
+        expected.set_attack(units::attack_config_for_ms(100));
+        expected.set_decay(units::decay_release_config_for_ms(2000));
+        expected.set_release(0);
+        expected.set_sustain(128);
+        assert_eq!(adsr, expected);
+
+        // The times read back are the ones of the table entries picked
+        assert!(adsr.attack_ms().abs_diff(100) <= 2);
+        assert!(adsr.decay_ms().abs_diff(2000) <= 40);
+        assert_eq!(adsr.release_ms(), 10);
+        assert_eq!(adsr.sustain_decibel_tenths(), -60);
+    }
+
+    #[test]
+    fn test_key_tracking_level() {
+        let peak = |note: u8, amplitude: u8| {
+            let mut adsr = ADSR::new(255, 10, 100, 100, 127);
+            adsr.set_key_tracking(
+                &KeyTracking {
+                    amplitude,
+                    ..Default::default()
+                },
+                note,
+            );
+            adsr.play(127);
+
+            advance_to_stage(&mut adsr, ADSRStage::Decay, 500000);
+            get_envelope_level(&adsr)
+        };
+
+        // Nothing changes up to middle C
+        let untracked = peak(60, 0);
+        assert_eq!(peak(48, 255), untracked);
+        assert_eq!(peak(60, 255), untracked);
+        assert_eq!(peak(96, 0), untracked);
+
+        assert!(peak(72, 255) < untracked);
+        assert!(peak(96, 255) < peak(72, 255));
+        assert!(peak(96, 255) < peak(96, 128));
+    }
+
+    // 11. Loop Tests
+
+    /// Times the envelope starts an attack after the first one, over `samples`
+    fn count_attacks(adsr: &mut ADSR, samples: usize) -> usize {
+        let mut buffer = [Q15::ZERO; 1];
+        let mut attacks = 0;
+        let mut previous = adsr.stage;
+        for _ in 0..samples {
+            adsr.get_samples(&mut buffer);
+            if adsr.stage == ADSRStage::Attack && previous != ADSRStage::Attack {
+                attacks += 1;
+            }
+            previous = adsr.stage;
+        }
+        attacks
+    }
+
+    #[test]
+    fn test_loop_config() {
+        assert_eq!(EnvelopeLoop::for_config(0), EnvelopeLoop::Off);
+        assert_eq!(EnvelopeLoop::for_config(1), EnvelopeLoop::Times(1));
+        assert_eq!(EnvelopeLoop::for_config(254), EnvelopeLoop::Times(254));
+        assert_eq!(EnvelopeLoop::for_config(255), EnvelopeLoop::Forever);
+    }
+
+    #[test]
+    fn test_loop_goes_back_to_the_attack() {
+        let samples = 200000;
+
+        let mut settling = ADSR::new(50, 10, 30, 100, 127);
+        settling.play(127);
+        assert_eq!(count_attacks(&mut settling, samples), 0);
+        assert_eq!(settling.stage, ADSRStage::Sustain);
+
+        let mut looping = ADSR::new(50, 10, 30, 100, 127);
+        looping.set_loop(EnvelopeLoop::Times(3));
+        looping.play(127);
+        assert_eq!(count_attacks(&mut looping, samples), 3);
+        assert_eq!(looping.stage, ADSRStage::Sustain);
+
+        let mut forever = ADSR::new(50, 10, 30, 100, 127);
+        forever.set_loop(EnvelopeLoop::Forever);
+        forever.play(127);
+        assert!(count_attacks(&mut forever, samples) > 10);
+        assert_ne!(forever.stage, ADSRStage::Sustain);
+    }
+
+    #[test]
+    fn test_loop_rises_back_to_the_peak() {
+        let mut adsr = ADSR::new(0, 10, 30, 100, 127);
+        adsr.set_loop(EnvelopeLoop::Forever);
+        adsr.play(127);
+
+        // Down to the sustain level, and back up to the peak
+        assert!(advance_to_stage(&mut adsr, ADSRStage::Decay, 100000));
+        let peak = get_envelope_level(&adsr);
+        assert!(advance_to_stage(&mut adsr, ADSRStage::Attack, 100000));
+        assert!(get_envelope_level(&adsr) < peak / 100);
+        assert!(advance_to_stage(&mut adsr, ADSRStage::Decay, 100000));
+        assert_eq!(get_envelope_level(&adsr), peak);
+    }
+
+    #[test]
+    fn test_loop_count_restarts_with_every_note() {
+        let mut adsr = ADSR::new(50, 10, 30, 100, 127);
+        adsr.set_loop(EnvelopeLoop::Times(2));
+
+        adsr.play(127);
+        assert_eq!(count_attacks(&mut adsr, 200000), 2);
+        adsr.retrigger(127);
+        assert_eq!(count_attacks(&mut adsr, 200000), 2);
+        adsr.play(127);
+        assert_eq!(count_attacks(&mut adsr, 200000), 2);
+    }
+
+    #[test]
+    fn test_release_stops_the_loop() {
+        let mut adsr = ADSR::new(50, 10, 30, 10, 127);
+        adsr.set_loop(EnvelopeLoop::Forever);
+        adsr.play(127);
+        count_attacks(&mut adsr, 5000);
+
+        adsr.stop_playing();
+        assert_eq!(count_attacks(&mut adsr, 200000), 0);
+        assert!(adsr.is_idle());
+    }
 }
@@ -0,0 +1,129 @@
+use crate::SAMPLE_RATE;
+use crate::adsr::config_table::{FALL_TIME_TABLE, RISE_TIME_TABLE, SUSTAIN_LEVEL_TABLE};
+
+/// Sustain level of sustain 0, which is silent
+pub const SILENT_SUSTAIN_LEVEL: i16 = i16::MIN;
+
+const SAMPLES_PER_MS: u32 = SAMPLE_RATE / 1000;
+
+/// Index of the entry of the ascending `table` closest to `value`
+fn nearest_index<T: Copy + Ord + Into<i64>>(table: &[T; 256], value: T) -> u8 {
+    let above = table.partition_point(|&entry| entry < value);
+    if above == 0 {
+        return 0;
+    }
+    if above == table.len() {
+        return u8::MAX;
+    }
+
+    let distance_below = value.into() - table[above - 1].into();
+    let distance_above = table[above].into() - value.into();
+    if distance_below <= distance_above {
+        (above - 1) as u8
+    } else {
+        above as u8
+    }
+}
+
+fn ms_for_samples(samples: u32) -> u32 {
+    (samples + SAMPLES_PER_MS / 2) / SAMPLES_PER_MS
+}
+
+/// Attack config whose time is closest to `ms`, from 10 ms to 5 s
+pub fn attack_config_for_ms(ms: u32) -> u8 {
+    nearest_index(&RISE_TIME_TABLE, ms.saturating_mul(SAMPLES_PER_MS))
+}
+
+/// Milliseconds the attack takes to rise from silence to full scale
+pub fn attack_ms(attack_config: u8) -> u32 {
+    ms_for_samples(RISE_TIME_TABLE[attack_config as usize])
+}
+
+/// Decay or release config whose time is closest to `ms`, from 10 ms to 10 s
+pub fn decay_release_config_for_ms(ms: u32) -> u8 {
+    nearest_index(&FALL_TIME_TABLE, ms.saturating_mul(SAMPLES_PER_MS))
+}
+
+/// Milliseconds the decay or release take to fall from full scale to silence
+pub fn decay_release_ms(decay_release_config: u8) -> u32 {
+    ms_for_samples(FALL_TIME_TABLE[decay_release_config as usize])
+}
+
+/// Sustain config whose level is closest to `level`, in tenths of a decibel below full
+/// scale. Goes from -48.2 dB to 0 dB, with `SILENT_SUSTAIN_LEVEL` being silent
+pub fn sustain_config_for_decibel_tenths(level: i16) -> u8 {
+    nearest_index(&SUSTAIN_LEVEL_TABLE, level)
+}
+
+/// Level of the sustain in tenths of a decibel below full scale, or `SILENT_SUSTAIN_LEVEL`
+pub fn sustain_decibel_tenths(sustain_config: u8) -> i16 {
+    SUSTAIN_LEVEL_TABLE[sustain_config as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_times_round_trip() {
+        // The shortest entries are less than a millisecond apart, so the config may
+        // change but the time it reads back doesn't
+        for config in 0..=u8::MAX {
+            let ms = attack_ms(config);
+            assert_eq!(attack_ms(attack_config_for_ms(ms)), ms);
+            let ms = decay_release_ms(config);
+            assert_eq!(decay_release_ms(decay_release_config_for_ms(ms)), ms);
+        }
+        // From a few hundred milliseconds on they don't collide
+        for config in 100..=u8::MAX {
+            assert_eq!(attack_config_for_ms(attack_ms(config)), config);
+            assert_eq!(
+                decay_release_config_for_ms(decay_release_ms(config)),
+                config
+            );
+        }
+    }
+
+    #[test]
+    fn test_times_are_in_milliseconds() {
+        assert_eq!(attack_ms(0), 10);
+        assert_eq!(attack_ms(u8::MAX), 5000);
+        assert_eq!(decay_release_ms(0), 10);
+        assert_eq!(decay_release_ms(u8::MAX), 10000);
+
+        // Half a second falls between two entries, and picks the closest one
+        let config = decay_release_config_for_ms(500);
+        for neighbour in [config - 1, config + 1] {
+            assert!(
+                decay_release_ms(neighbour).abs_diff(500) >= decay_release_ms(config).abs_diff(500)
+            );
+        }
+    }
+
+    #[test]
+    fn test_times_out_of_range_stay_at_the_ends() {
+        assert_eq!(attack_config_for_ms(0), 0);
+        assert_eq!(attack_config_for_ms(u32::MAX), u8::MAX);
+        assert_eq!(decay_release_config_for_ms(0), 0);
+        assert_eq!(decay_release_config_for_ms(60_000), u8::MAX);
+    }
+
+    #[test]
+    fn test_sustain_levels() {
+        assert_eq!(sustain_decibel_tenths(0), SILENT_SUSTAIN_LEVEL);
+        assert_eq!(sustain_decibel_tenths(128), -60);
+        assert_eq!(sustain_decibel_tenths(64), -120);
+
+        assert_eq!(sustain_config_for_decibel_tenths(0), u8::MAX);
+        assert_eq!(sustain_config_for_decibel_tenths(-60), 128);
+        assert_eq!(sustain_config_for_decibel_tenths(SILENT_SUSTAIN_LEVEL), 0);
+        // Above full scale stays at full scale
+        assert_eq!(sustain_config_for_decibel_tenths(60), u8::MAX);
+    }
+
+    #[test]
+    fn test_sustain_is_monotonic() {
+        let configs: Vec<u8> = (-500..=0).map(sustain_config_for_decibel_tenths).collect();
+        assert!(configs.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
@@ -64,7 +64,9 @@ impl Capacitor {
     }
 
     pub(crate) fn iterate(value: I1F31, coeff: BaseAndCoefficient) -> I1F31 {
-        value.saturating_mul_add(coeff.coefficient, coeff.base)
+        value
+            .saturating_add(value.saturating_mul(coeff.coefficient))
+            .saturating_add(coeff.base)
     }
 
     pub fn step(&mut self) -> CapacitorStatus {
@@ -125,18 +127,18 @@ impl Default for Capacitor {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::adsr::config_table::FALL_BASE_COEFFICIENT_TABLE;
+    use crate::adsr::curve::fall_base_and_coefficient;
 
     #[test]
     fn test_capacitor_rises_to_target() {
-        // Use coefficients that work with I1F31 (coefficient < 1.0)
+        // Loses 1/128 of the level every sample
         let rise_coeff = BaseAndCoefficient {
             base: I1F31::from_num(0.1),
-            coefficient: I1F31::from_bits(0x7f000000), // ~0.996
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
         let fall_coeff = BaseAndCoefficient {
             base: I1F31::ZERO,
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
 
         let mut cap = Capacitor::new(rise_coeff, fall_coeff);
@@ -162,12 +164,12 @@ mod tests {
     fn test_capacitor_falls_to_target() {
         let rise_coeff = BaseAndCoefficient {
             base: I1F31::ZERO,
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
         // Subtract 0.1 each step
         let fall_coeff = BaseAndCoefficient {
             base: I1F31::from_num(-0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
 
         let mut cap = Capacitor::new(rise_coeff, fall_coeff);
@@ -195,11 +197,11 @@ mod tests {
         // Large step that would overshoot
         let rise_coeff = BaseAndCoefficient {
             base: I1F31::from_num(0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
         let fall_coeff = BaseAndCoefficient {
             base: I1F31::ZERO,
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
 
         let mut cap = Capacitor::new(rise_coeff, fall_coeff);
@@ -215,12 +217,12 @@ mod tests {
     fn test_capacitor_does_not_overshoot_falling() {
         let rise_coeff = BaseAndCoefficient {
             base: I1F31::ZERO,
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
         // Large step that would overshoot
         let fall_coeff = BaseAndCoefficient {
             base: I1F31::from_num(-0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
 
         let mut cap = Capacitor::new(rise_coeff, fall_coeff);
@@ -236,11 +238,11 @@ mod tests {
     fn test_capacitor_already_at_target() {
         let rise_coeff = BaseAndCoefficient {
             base: I1F31::from_num(0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
         let fall_coeff = BaseAndCoefficient {
             base: I1F31::from_num(-0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
 
         let mut cap = Capacitor::new(rise_coeff, fall_coeff);
@@ -256,11 +258,11 @@ mod tests {
     fn test_capacitor_target_change_mid_flight() {
         let rise_coeff = BaseAndCoefficient {
             base: I1F31::from_num(0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
         let fall_coeff = BaseAndCoefficient {
             base: I1F31::from_num(-0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
 
         let mut cap = Capacitor::new(rise_coeff, fall_coeff);
@@ -288,10 +290,10 @@ mod tests {
     fn test_capacitor_quick_discharge() {
         let rise_coeff = BaseAndCoefficient {
             base: I1F31::from_num(0.1),
-            coefficient: I1F31::from_bits(0x7f000000),
+            coefficient: I1F31::from_bits(-0x0100_0000),
         };
-        // Use fall coefficient at index 1 (slower than index 0 which is the quick fall)
-        let slow_fall_coeff = FALL_BASE_COEFFICIENT_TABLE[1];
+        // The second shortest fall, as the shortest one is as long as the quick fall
+        let slow_fall_coeff = fall_base_and_coefficient(1, 0);
 
         // Test 1: Quick discharge from MAX to zero
         let mut cap_quick = Capacitor::new(rise_coeff, slow_fall_coeff);
@@ -0,0 +1,120 @@
+use cmsis_interface::Q15;
+use defmt::Format;
+use fixed::types::U8F24;
+
+use crate::{rng::Rng, tuning::scale_by_semitone_fraction};
+
+#[cfg(test)]
+mod tests;
+
+/// How far the pitch wanders at full depth, in cents either way
+const MAX_PITCH_DRIFT_CENTS: i32 = 20;
+
+/// Most of the level taken off at full depth, 0.2 in Q15 (about -2 dB).
+/// The level only goes down, so drifting never clips.
+const MAX_LEVEL_DRIFT: i32 = 6554;
+
+/// Windows between two random targets, ~2.7 s with 128 sample windows at 48 kHz
+const TARGET_INTERVAL: u16 = 1024;
+
+/// Every window moves 2^-GLIDE_SHIFT of the way towards the target,
+/// so it takes ~1.4 s to get most of the way there
+const GLIDE_SHIFT: u32 = 9;
+
+/// Fractions of a semitone are expressed in 1/16384ths, like the tuning does
+const SEMITONE_FRACTION_BITS: u32 = 14;
+
+/// Seed used when none is given
+const DEFAULT_SEED: u32 = 0x0D21_F700;
+
+/// Slow random wander of the pitch and level of a voice, like the components of
+/// an analog synth warming up and aging.
+///
+/// Every so often new pitch and level targets are picked at random, and the drift glides
+/// towards them. It's meant to be updated once a window, which is plenty for how slowly
+/// it moves.
+#[derive(Debug, Clone, Copy)]
+pub struct Drift {
+    rng: Rng,
+    depth: Q15,
+    /// Where the pitch and level are between their extremes, from -1 to 1 in Q15
+    pitch: i32,
+    level: i32,
+    pitch_target: i32,
+    level_target: i32,
+    windows_to_target: u16,
+}
+
+impl Format for Drift {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "Drift {{ pitch: {}, level: {} }}",
+            self.pitch,
+            self.level
+        )
+    }
+}
+
+impl Drift {
+    /// Every voice should get its own seed, or they all drift the same way
+    pub fn new(seed: u32) -> Self {
+        let mut rng = Rng::new(seed);
+        let pitch = rng.next_q15().to_bits() as i32;
+        let level = rng.next_q15().to_bits() as i32;
+
+        // Starts somewhere at random, so the voices are already apart from the first note
+        Self {
+            rng,
+            depth: Q15::ZERO,
+            pitch,
+            level,
+            pitch_target: pitch,
+            level_target: level,
+            windows_to_target: 0,
+        }
+    }
+
+    /// 0 doesn't drift at all, and the maximum drifts MAX_PITCH_DRIFT_CENTS and MAX_LEVEL_DRIFT
+    pub fn set_depth(&mut self, depth: Q15) {
+        self.depth = depth.max(Q15::ZERO);
+    }
+
+    /// Moves the drift on by a window, returning the ratio to multiply the pitch by
+    /// and the gain to multiply the level by
+    pub fn next_window(&mut self) -> (U8F24, Q15) {
+        if self.windows_to_target == 0 {
+            self.pitch_target = self.rng.next_q15().to_bits() as i32;
+            self.level_target = self.rng.next_q15().to_bits() as i32;
+            self.windows_to_target = TARGET_INTERVAL;
+        }
+        self.windows_to_target -= 1;
+
+        self.pitch += (self.pitch_target - self.pitch) >> GLIDE_SHIFT;
+        self.level += (self.level_target - self.level) >> GLIDE_SHIFT;
+
+        (self.pitch_ratio(), self.gain())
+    }
+
+    fn pitch_ratio(&self) -> U8F24 {
+        let pitch = (self.pitch * self.depth.to_bits() as i32) >> 15;
+        // Cents to 1/2^SEMITONE_FRACTION_BITS of a semitone, and out of Q15
+        let fraction = (pitch * MAX_PITCH_DRIFT_CENTS) / (100 << (15 - SEMITONE_FRACTION_BITS));
+
+        scale_by_semitone_fraction(U8F24::ONE, fraction)
+    }
+
+    fn gain(&self) -> Q15 {
+        // From 0 at the bottom of the range to 1 at the top
+        let level = (self.level + (1 << 15)) >> 1;
+        let loss = (((level * self.depth.to_bits() as i32) >> 15) * MAX_LEVEL_DRIFT) >> 15;
+
+        Q15::from_bits((i16::MAX as i32 - loss) as i16)
+    }
+}
+
+impl Default for Drift {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
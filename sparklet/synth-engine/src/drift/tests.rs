@@ -0,0 +1,91 @@
+use super::*;
+
+fn cents(ratio: U8F24) -> f64 {
+    1200. * f64::log2(ratio.to_num::<f64>())
+}
+
+/// Pitch in cents and gain of the next `windows` windows
+fn run(drift: &mut Drift, windows: usize) -> Vec<(f64, f64)> {
+    (0..windows)
+        .map(|_| {
+            let (ratio, gain) = drift.next_window();
+            (cents(ratio), gain.to_num::<f64>())
+        })
+        .collect()
+}
+
+fn drift_with_depth(seed: u32, depth: Q15) -> Drift {
+    let mut drift = Drift::new(seed);
+    drift.set_depth(depth);
+    drift
+}
+
+#[test]
+fn test_no_depth_no_drift() {
+    let mut drift = drift_with_depth(1, Q15::ZERO);
+    for _ in 0..4096 {
+        assert_eq!(drift.next_window(), (U8F24::ONE, Q15::MAX));
+    }
+}
+
+#[test]
+fn test_drift_is_reproducible() {
+    let first = run(&mut drift_with_depth(7, Q15::MAX), 2048);
+    let second = run(&mut drift_with_depth(7, Q15::MAX), 2048);
+    assert_eq!(first, second);
+
+    let other_seed = run(&mut drift_with_depth(8, Q15::MAX), 2048);
+    assert_ne!(first, other_seed);
+}
+
+#[test]
+fn test_drift_stays_in_range() {
+    for seed in 1..8 {
+        for (pitch, gain) in run(&mut drift_with_depth(seed, Q15::MAX), 16384) {
+            assert!(
+                pitch.abs() <= MAX_PITCH_DRIFT_CENTS as f64 + 0.01,
+                "Drifted {} cents",
+                pitch
+            );
+            assert!((0.79..=1.).contains(&gain), "Drifted to a gain of {}", gain);
+        }
+    }
+}
+
+#[test]
+fn test_drift_wanders_slowly() {
+    let windows = run(&mut drift_with_depth(3, Q15::MAX), 16384);
+
+    for pair in windows.windows(2) {
+        assert!(
+            (pair[1].0 - pair[0].0).abs() < 0.1,
+            "Jumped from {} to {} cents in a window",
+            pair[0].0,
+            pair[1].0
+        );
+        assert!((pair[1].1 - pair[0].1).abs() < 0.001);
+    }
+
+    let (lowest, highest) = windows
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(low, high), (pitch, _)| {
+            (low.min(*pitch), high.max(*pitch))
+        });
+    assert!(
+        highest - lowest > 10.,
+        "Should wander around, only went from {} to {} cents",
+        lowest,
+        highest
+    );
+}
+
+#[test]
+fn test_depth_scales_the_drift() {
+    let full = run(&mut drift_with_depth(5, Q15::MAX), 4096);
+    let half = run(&mut drift_with_depth(5, Q15::from_num(0.5)), 4096);
+
+    for ((full_pitch, full_gain), (half_pitch, half_gain)) in full.iter().zip(half.iter()) {
+        assert!((full_pitch / 2. - half_pitch).abs() < 0.05);
+        assert!(((1. - full_gain) / 2. - (1. - half_gain)).abs() < 0.001);
+    }
+}
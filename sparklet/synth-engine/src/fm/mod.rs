@@ -0,0 +1,305 @@
+use cmsis_interface::{CmsisOperations, Q15};
+use defmt::Format;
+use fixed::types::U8F24;
+
+use crate::{
+    Note, SAMPLE_RATE,
+    adsr::ADSR,
+    rng::Rng,
+    tuning::Tuning,
+    wavetable::{PhaseMode, WavetableOscillator, sine_wavetable::SINE_WAVETABLE},
+};
+
+#[cfg(test)]
+mod tests;
+
+pub const FM_OPERATOR_AMOUNT: usize = 4;
+
+/// Operator envelopes ignore velocity, it's already applied by the voice envelope
+const OPERATOR_ENVELOPE_VELOCITY: u8 = 127;
+
+/// Frequency ratios an operator can run at, relative to the note being played
+const FM_RATIOS: [U8F24; 16] = [
+    U8F24::lit("1"),
+    U8F24::lit("2"),
+    U8F24::lit("3"),
+    U8F24::lit("4"),
+    U8F24::lit("5"),
+    U8F24::lit("6"),
+    U8F24::lit("7"),
+    U8F24::lit("8"),
+    U8F24::lit("9"),
+    U8F24::lit("10"),
+    U8F24::lit("11"),
+    U8F24::lit("12"),
+    U8F24::lit("13"),
+    U8F24::lit("14"),
+    U8F24::lit("15"),
+    U8F24::lit("0.5"),
+];
+
+/// How the operators are connected.
+///
+/// Operators are numbered from 1, and `a -> b` means that `a` modulates the phase of `b`.
+/// Carriers (the operators that are heard) are mixed at equal levels.
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FmAlgorithm {
+    /// 2 -> 1
+    TwoOperatorStack,
+    /// 1 + 2
+    TwoOperatorParallel,
+    /// 4 -> 3 -> 2 -> 1
+    FourOperatorStack,
+    /// (2 -> 1) + (4 -> 3)
+    FourOperatorTwoStacks,
+    /// (2 + 3 + 4) -> 1
+    FourOperatorThreeModulators,
+    /// 1 + 2 + 3 + 4
+    FourOperatorParallel,
+}
+
+impl FmAlgorithm {
+    pub const AMOUNT: u8 = 6;
+
+    pub fn from_encoder(encoder: u8) -> Self {
+        match encoder % Self::AMOUNT {
+            0 => Self::TwoOperatorStack,
+            1 => Self::TwoOperatorParallel,
+            2 => Self::FourOperatorStack,
+            3 => Self::FourOperatorTwoStacks,
+            4 => Self::FourOperatorThreeModulators,
+            _ => Self::FourOperatorParallel,
+        }
+    }
+}
+
+/// Settings of a single operator, as set from the config.
+///
+/// The envelope of an operator is deliberately a subset of a full one: its attack and
+/// sustain come from the voice envelope, and a single time is used for both its decay
+/// and its release. An operator page only has room for one setting next to the ratio
+/// and the level, and the decay shapes the timbre the most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FmOperatorConfig {
+    pub ratio: U8F24,
+    pub level: Q15,
+    /// Decay and release time of the operator envelope
+    pub decay_release: u8,
+}
+
+impl FmOperatorConfig {
+    /// Picks the ratio with the value mod 16: 0 => 1, 1 => 2, ..., 14 => 15, 15 => 0.5
+    pub fn ratio_for_encoder(encoder: u8) -> U8F24 {
+        FM_RATIOS[encoder as usize % FM_RATIOS.len()]
+    }
+}
+
+/// A sine oscillator with its own level and envelope
+#[derive(Debug, Clone, Copy)]
+pub struct FmOperator {
+    osc: WavetableOscillator<'static, SAMPLE_RATE>,
+    level: Q15,
+    adsr: ADSR,
+}
+
+impl Format for FmOperator {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "FmOperator {{ adsr: {} }}", self.adsr)
+    }
+}
+
+impl FmOperator {
+    fn new(attack: u8, sustain: u8, decay_release: u8) -> Self {
+        Self {
+            osc: WavetableOscillator::new(&SINE_WAVETABLE),
+            level: Q15::ZERO,
+            adsr: ADSR::new(
+                sustain,
+                attack,
+                decay_release,
+                decay_release,
+                OPERATOR_ENVELOPE_VELOCITY,
+            ),
+        }
+    }
+
+    /// Renders the operator, with its phase modulated by the sum of `modulators`
+    fn get_samples<T: CmsisOperations, const LEN: usize>(
+        &mut self,
+        buffer: &mut [Q15; LEN],
+        modulators: &[&[Q15; LEN]],
+    ) {
+        let mut osc_buf = [Q15::ZERO; LEN];
+        let mut envelope_buf = [Q15::ZERO; LEN];
+        let mut enveloped_buf = [Q15::ZERO; LEN];
+
+        if modulators.is_empty() {
+            self.osc.get_samples::<T, LEN>(&mut osc_buf);
+        } else {
+            self.osc
+                .get_phase_modulated_samples::<T, LEN>(&mut osc_buf, modulators);
+        }
+
+        self.adsr.get_samples::<LEN>(&mut envelope_buf);
+
+        T::multiply_q15(&osc_buf, &envelope_buf, &mut enveloped_buf);
+        T::multiply_q15(&enveloped_buf, &[self.level; LEN], buffer);
+    }
+}
+
+/// Up to four sine operators modulating each other's phase
+#[derive(Debug, Clone, Copy)]
+pub struct FmVoice {
+    operators: [FmOperator; FM_OPERATOR_AMOUNT],
+    algorithm: FmAlgorithm,
+}
+
+impl Format for FmVoice {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "FmVoice {{ algorithm: {} }}", self.algorithm)
+    }
+}
+
+impl FmVoice {
+    pub fn new(attack: u8, sustain: u8, decay_release: u8) -> Self {
+        Self {
+            operators: [FmOperator::new(attack, sustain, decay_release); FM_OPERATOR_AMOUNT],
+            algorithm: FmAlgorithm::TwoOperatorStack,
+        }
+    }
+
+    pub fn set_algorithm(&mut self, algorithm: FmAlgorithm) {
+        self.algorithm = algorithm;
+    }
+
+    /// Attack and sustain are shared by all the operator envelopes, and follow the
+    /// voice envelope
+    pub fn set_envelope(&mut self, attack: u8, sustain: u8) {
+        for operator in self.operators.iter_mut() {
+            operator.adsr.set_attack(attack);
+            operator.adsr.set_sustain(sustain);
+        }
+    }
+
+    pub fn set_phase_mode(&mut self, phase_mode: PhaseMode) {
+        for operator in self.operators.iter_mut() {
+            operator.osc.set_phase_mode(phase_mode);
+        }
+    }
+
+    /// Every operator gets its own sequence of random phases out of `seed`
+    pub fn set_phase_seed(&mut self, seed: u32) {
+        let mut seeds = Rng::new(seed);
+        for operator in self.operators.iter_mut() {
+            operator.osc.set_phase_seed(seeds.next_u32());
+        }
+    }
+
+    /// The ratio takes effect on the next [`Self::set_note`] or [`Self::retune`]
+    pub fn set_operators(&mut self, configs: &[FmOperatorConfig; FM_OPERATOR_AMOUNT]) {
+        for (operator, config) in self.operators.iter_mut().zip(configs.iter()) {
+            operator.osc.set_frequency_ratio(config.ratio);
+            operator.level = config.level;
+            operator.adsr.set_decay_release(config.decay_release);
+        }
+    }
+
+    pub fn set_note(&mut self, note: &Note, tuning: &Tuning) {
+        for operator in self.operators.iter_mut() {
+            operator.osc.set_note(note, tuning);
+            operator.adsr.play(OPERATOR_ENVELOPE_VELOCITY);
+        }
+    }
+
+    /// Updates the pitch of the current note without touching the phase
+    pub fn retune(&mut self, note: &Note, tuning: &Tuning) {
+        for operator in self.operators.iter_mut() {
+            operator.osc.retune(note, tuning);
+        }
+    }
+
+    /// Detunes every operator by the same `drift`, so the ratios between them hold
+    pub fn set_drift(&mut self, drift: U8F24) {
+        for operator in self.operators.iter_mut() {
+            operator.osc.set_drift(drift);
+        }
+    }
+
+    pub fn retrigger(&mut self) {
+        for operator in self.operators.iter_mut() {
+            operator.adsr.retrigger(OPERATOR_ENVELOPE_VELOCITY);
+        }
+    }
+
+    pub fn stop_playing(&mut self) {
+        for operator in self.operators.iter_mut() {
+            operator.adsr.stop_playing();
+        }
+    }
+
+    pub fn get_samples<T: CmsisOperations, const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        let [op1, op2, op3, op4] = &mut self.operators;
+
+        let mut out1 = [Q15::ZERO; LEN];
+        let mut out2 = [Q15::ZERO; LEN];
+        let mut out3 = [Q15::ZERO; LEN];
+        let mut out4 = [Q15::ZERO; LEN];
+
+        match self.algorithm {
+            FmAlgorithm::TwoOperatorStack => {
+                op2.get_samples::<T, LEN>(&mut out2, &[]);
+                op1.get_samples::<T, LEN>(buffer, &[&out2]);
+            }
+            FmAlgorithm::TwoOperatorParallel => {
+                op1.get_samples::<T, LEN>(&mut out1, &[]);
+                op2.get_samples::<T, LEN>(&mut out2, &[]);
+
+                Self::mix_carriers::<T, LEN>(&[&out1, &out2], buffer);
+            }
+            FmAlgorithm::FourOperatorStack => {
+                op4.get_samples::<T, LEN>(&mut out4, &[]);
+                op3.get_samples::<T, LEN>(&mut out3, &[&out4]);
+                op2.get_samples::<T, LEN>(&mut out2, &[&out3]);
+                op1.get_samples::<T, LEN>(buffer, &[&out2]);
+            }
+            FmAlgorithm::FourOperatorTwoStacks => {
+                op2.get_samples::<T, LEN>(&mut out2, &[]);
+                op1.get_samples::<T, LEN>(&mut out1, &[&out2]);
+                op4.get_samples::<T, LEN>(&mut out4, &[]);
+                op3.get_samples::<T, LEN>(&mut out3, &[&out4]);
+
+                Self::mix_carriers::<T, LEN>(&[&out1, &out3], buffer);
+            }
+            FmAlgorithm::FourOperatorThreeModulators => {
+                op2.get_samples::<T, LEN>(&mut out2, &[]);
+                op3.get_samples::<T, LEN>(&mut out3, &[]);
+                op4.get_samples::<T, LEN>(&mut out4, &[]);
+                op1.get_samples::<T, LEN>(buffer, &[&out2, &out3, &out4]);
+            }
+            FmAlgorithm::FourOperatorParallel => {
+                op1.get_samples::<T, LEN>(&mut out1, &[]);
+                op2.get_samples::<T, LEN>(&mut out2, &[]);
+                op3.get_samples::<T, LEN>(&mut out3, &[]);
+                op4.get_samples::<T, LEN>(&mut out4, &[]);
+
+                Self::mix_carriers::<T, LEN>(&[&out1, &out2, &out3, &out4], buffer);
+            }
+        }
+    }
+
+    /// Sums 2 or 4 carriers, scaled down so that the sum can't clip
+    fn mix_carriers<T: CmsisOperations, const LEN: usize>(
+        carriers: &[&[Q15; LEN]],
+        buffer: &mut [Q15; LEN],
+    ) {
+        let shift = -(carriers.len().ilog2() as i8);
+        let mut scaled = [Q15::ZERO; LEN];
+
+        buffer.fill(Q15::ZERO);
+
+        for carrier in carriers {
+            T::shift_q15(*carrier, shift, &mut scaled);
+            T::add_q15(&buffer.clone(), &scaled, buffer);
+        }
+    }
+}
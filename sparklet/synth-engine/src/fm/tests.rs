@@ -0,0 +1,216 @@
+use super::*;
+use crate::wavetable::phase_increment_table::MIDI_TO_PHASE_INCREMENT;
+
+type TestOps = cmsis_rust::CmsisRustOperations;
+
+const WINDOW_SIZE: usize = 128;
+const A4: Note = Note::new(69);
+
+// Fastest attack and a full sustain, so operators play at their level right away
+const FAST_ATTACK: u8 = 0;
+const FULL_SUSTAIN: u8 = 255;
+const DECAY_RELEASE: u8 = 100;
+
+fn operator(ratio_encoder: u8, level: Q15) -> FmOperatorConfig {
+    FmOperatorConfig {
+        ratio: FmOperatorConfig::ratio_for_encoder(ratio_encoder),
+        level,
+        decay_release: DECAY_RELEASE,
+    }
+}
+
+fn create_voice(
+    algorithm: FmAlgorithm,
+    operators: [FmOperatorConfig; FM_OPERATOR_AMOUNT],
+) -> FmVoice {
+    let mut voice = FmVoice::new(FAST_ATTACK, FULL_SUSTAIN, DECAY_RELEASE);
+    voice.set_algorithm(algorithm);
+    voice.set_operators(&operators);
+    voice.set_note(&A4, &Tuning::default());
+    voice
+}
+
+/// Renders `windows` windows, after skipping the attack
+fn render(voice: &mut FmVoice, windows: usize) -> Vec<Q15> {
+    let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+    for _ in 0..16 {
+        voice.get_samples::<TestOps, WINDOW_SIZE>(&mut buffer);
+    }
+
+    let mut samples = Vec::with_capacity(windows * WINDOW_SIZE);
+    for _ in 0..windows {
+        voice.get_samples::<TestOps, WINDOW_SIZE>(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+    samples
+}
+
+fn count_zero_crossings(samples: &[Q15]) -> usize {
+    samples
+        .windows(2)
+        .filter(|w| (w[0] >= Q15::ZERO) != (w[1] >= Q15::ZERO))
+        .count()
+}
+
+/// Magnitude of the DFT of `samples` at `harmonic` times the frequency of A4
+fn harmonic_magnitude(samples: &[Q15], harmonic: usize) -> f64 {
+    let cycles_per_sample =
+        MIDI_TO_PHASE_INCREMENT[A4.as_u8() as usize].to_num::<f64>() / 256.0 * harmonic as f64;
+
+    let (re, im) = samples
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(re, im), (n, sample)| {
+            let angle = 2.0 * core::f64::consts::PI * cycles_per_sample * n as f64;
+            let sample = sample.to_num::<f64>();
+            (re + sample * angle.cos(), im - sample * angle.sin())
+        });
+
+    (re * re + im * im).sqrt() / samples.len() as f64
+}
+
+const SILENT: FmOperatorConfig = FmOperatorConfig {
+    ratio: U8F24::ONE,
+    level: Q15::ZERO,
+    decay_release: DECAY_RELEASE,
+};
+
+#[test]
+fn test_unmodulated_carrier_is_a_sine() {
+    let mut voice = create_voice(
+        FmAlgorithm::TwoOperatorStack,
+        [operator(0, Q15::MAX), SILENT, SILENT, SILENT],
+    );
+    let samples = render(&mut voice, 40);
+
+    let fundamental = harmonic_magnitude(&samples, 1);
+    assert!(
+        fundamental > 0.4,
+        "Carrier should play A4, got {}",
+        fundamental
+    );
+    for harmonic in 2..5 {
+        assert!(
+            harmonic_magnitude(&samples, harmonic) < fundamental / 100.0,
+            "A lone carrier shouldn't have harmonic {}",
+            harmonic
+        );
+    }
+}
+
+#[test]
+fn test_modulator_adds_sidebands() {
+    let mut voice = create_voice(
+        FmAlgorithm::TwoOperatorStack,
+        [
+            operator(0, Q15::MAX),
+            operator(0, Q15::from_num(0.25)),
+            SILENT,
+            SILENT,
+        ],
+    );
+    let samples = render(&mut voice, 40);
+
+    // A 1:1 modulator puts sidebands on every harmonic
+    let fundamental = harmonic_magnitude(&samples, 1);
+    for harmonic in 2..4 {
+        let magnitude = harmonic_magnitude(&samples, harmonic);
+        assert!(
+            magnitude > fundamental / 20.0,
+            "Harmonic {} should be audible, got {} vs {}",
+            harmonic,
+            magnitude,
+            fundamental
+        );
+    }
+}
+
+#[test]
+fn test_ratio_sets_operator_frequency() {
+    let mut at_unison = create_voice(
+        FmAlgorithm::TwoOperatorParallel,
+        [operator(0, Q15::MAX), SILENT, SILENT, SILENT],
+    );
+    // Encoder 2 is a ratio of 3, and 15 is a ratio of 0.5
+    let mut at_three = create_voice(
+        FmAlgorithm::TwoOperatorParallel,
+        [SILENT, operator(2, Q15::MAX), SILENT, SILENT],
+    );
+    let mut at_half = create_voice(
+        FmAlgorithm::TwoOperatorParallel,
+        [operator(15, Q15::MAX), SILENT, SILENT, SILENT],
+    );
+
+    let crossings = count_zero_crossings(&render(&mut at_unison, 40)) as f64;
+    let ratio_three = count_zero_crossings(&render(&mut at_three, 40)) as f64 / crossings;
+    let ratio_half = count_zero_crossings(&render(&mut at_half, 40)) as f64 / crossings;
+
+    assert!(
+        (ratio_three - 3.0).abs() < 0.05,
+        "Got ratio {}",
+        ratio_three
+    );
+    assert!((ratio_half - 0.5).abs() < 0.05, "Got ratio {}", ratio_half);
+}
+
+#[test]
+fn test_algorithms_route_modulators() {
+    let all_operators = [operator(0, Q15::MAX); FM_OPERATOR_AMOUNT];
+
+    // With all four operators at unison and full level, parallel carriers add up to a sine
+    let mut parallel = create_voice(FmAlgorithm::FourOperatorParallel, all_operators);
+    let parallel_samples = render(&mut parallel, 40);
+    assert!(
+        harmonic_magnitude(&parallel_samples, 2) < harmonic_magnitude(&parallel_samples, 1) / 100.0
+    );
+
+    // While every other algorithm has at least one modulator, so it's brighter
+    for algorithm in [
+        FmAlgorithm::TwoOperatorStack,
+        FmAlgorithm::FourOperatorStack,
+        FmAlgorithm::FourOperatorTwoStacks,
+        FmAlgorithm::FourOperatorThreeModulators,
+    ] {
+        let mut voice = create_voice(algorithm, all_operators);
+        let samples = render(&mut voice, 40);
+
+        assert!(
+            harmonic_magnitude(&samples, 2) > harmonic_magnitude(&samples, 1) / 20.0,
+            "{:?} should modulate its carriers",
+            algorithm
+        );
+    }
+}
+
+#[test]
+fn test_operator_envelopes_release() {
+    let mut voice = create_voice(
+        FmAlgorithm::TwoOperatorParallel,
+        [operator(0, Q15::MAX), SILENT, SILENT, SILENT],
+    );
+    render(&mut voice, 1);
+
+    voice.stop_playing();
+
+    let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+    for _ in 0..2000 {
+        voice.get_samples::<TestOps, WINDOW_SIZE>(&mut buffer);
+    }
+
+    assert!(
+        buffer
+            .iter()
+            .all(|sample| sample.abs() < Q15::from_num(0.001)),
+        "Operators should fade out after being released"
+    );
+}
+
+#[test]
+fn test_algorithm_from_encoder_wraps() {
+    assert_eq!(FmAlgorithm::from_encoder(0), FmAlgorithm::TwoOperatorStack);
+    assert_eq!(
+        FmAlgorithm::from_encoder(5),
+        FmAlgorithm::FourOperatorParallel
+    );
+    assert_eq!(FmAlgorithm::from_encoder(6), FmAlgorithm::TwoOperatorStack);
+}
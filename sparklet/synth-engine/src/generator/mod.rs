@@ -1,14 +1,114 @@
 use config::Config;
+use fixed::types::U8F24;
+
+use crate::SAMPLE_RATE;
+pub use crate::adsr::EnvelopeLoop;
+pub use crate::adsr::key_tracking::KeyTracking;
 use embassy_sync::{blocking_mutex::raw::RawMutex, channel::Receiver};
-use midi::MidiEvent;
+use midi::{MidiEvent, TuningTable};
 
-pub use crate::voice_bank::{Note, PlayNoteResult, Velocity, VoiceBank, VoiceStage};
+pub use crate::fm::{FM_OPERATOR_AMOUNT, FmAlgorithm, FmOperatorConfig};
+pub use crate::lfo::{Lfo, LfoConfig, LfoPhase, LfoShape, LfoTarget};
+pub use crate::mod_matrix::{
+    MOD_SLOT_AMOUNT, ModDestination, ModMatrix, ModSlot, ModSource, ModSources,
+};
+pub use crate::noise::NoiseColor;
+pub use crate::phase_distortion::{PhaseDistortionConfig, PhaseDistortionShape};
+pub use crate::sampler::{LoopMode, Sample};
+use crate::shaper::saturation_table::SATURATION_TABLE;
+pub use crate::shaper::{ShaperConfig, ShaperMode};
+pub use crate::string::{StringConfig, StringExcitation};
+pub use crate::tuning::PitchOffset;
+pub use crate::velocity::{VelocityCurve, VelocityResponse};
+pub use crate::voice_bank::{
+    Note, PlayNoteResult, SecondOscillatorMode, Velocity, VoiceBank, VoiceStage,
+};
+pub use crate::wavetable::{PhaseMode, SubOscillator, SubOscillatorShape};
 use crate::wavetable::{
     saw_wavetable::SAW_WAVETABLE, sine_wavetable::SINE_WAVETABLE,
     square_wavetable::SQUARE_WAVETABLE, triangle_wavetable::TRIANGLE_WAVETABLE,
 };
 pub use cmsis_interface::{CmsisOperations, Q15};
 
+/// Attack, Sustain, Decay
+pub const ENVELOPE_PAGE: usize = 0;
+/// Oscillator type, second oscillator level, second oscillator detune
+pub const OSCILLATOR_PAGE: usize = 1;
+/// Noise color, noise level, unused
+pub const NOISE_PAGE: usize = 2;
+/// Second oscillator type and mode, semitone offset, octave offset
+pub const SECOND_OSCILLATOR_PAGE: usize = 3;
+/// One page per FM operator: ratio, level, decay/release
+pub const FM_OPERATOR_FIRST_PAGE: usize = 4;
+/// Pulse width, sub oscillator type, sub oscillator level
+pub const PULSE_AND_SUB_PAGE: usize = FM_OPERATOR_FIRST_PAGE + FM_OPERATOR_AMOUNT;
+/// Phase mode, analog drift depth, unused
+pub const PHASE_PAGE: usize = PULSE_AND_SUB_PAGE + 1;
+/// String excitation, decay while held, damping once released
+pub const STRING_PAGE: usize = PHASE_PAGE + 1;
+/// Phase distortion shape, warp amount, warp decay/release
+pub const PHASE_DISTORTION_PAGE: usize = STRING_PAGE + 1;
+/// Shaper type, drive, symmetry
+pub const SHAPER_PAGE: usize = PHASE_DISTORTION_PAGE + 1;
+/// Release, delay, hold
+pub const ENVELOPE_TIMING_PAGE: usize = SHAPER_PAGE + 1;
+/// Attack curve, decay curve, release curve
+pub const ENVELOPE_CURVE_PAGE: usize = ENVELOPE_TIMING_PAGE + 1;
+/// LFO shape and target, rate, depth
+pub const LFO_PAGE: usize = ENVELOPE_CURVE_PAGE + 1;
+/// LFO phase and key sync, delay, fade in
+pub const LFO_SYNC_PAGE: usize = LFO_PAGE + 1;
+/// One page per modulation slot: source, destination, depth
+pub const MOD_SLOT_FIRST_PAGE: usize = LFO_SYNC_PAGE + 1;
+/// Modulation envelope attack, sustain, decay
+pub const MOD_ENVELOPE_PAGE: usize = MOD_SLOT_FIRST_PAGE + MOD_SLOT_AMOUNT;
+/// Modulation envelope release, unused, unused
+pub const MOD_ENVELOPE_RELEASE_PAGE: usize = MOD_ENVELOPE_PAGE + 1;
+/// Velocity curve, velocity sensitivity, fixed velocity
+pub const VELOCITY_PAGE: usize = MOD_ENVELOPE_RELEASE_PAGE + 1;
+/// Attack key tracking, decay/release key tracking, amplitude key tracking
+pub const KEY_TRACKING_PAGE: usize = VELOCITY_PAGE + 1;
+/// Envelope loop count, modulation envelope loop count, unused
+pub const ENVELOPE_LOOP_PAGE: usize = KEY_TRACKING_PAGE + 1;
+
+/// Every wavetable of the second oscillator can be used in every mode
+const SECOND_OSCILLATOR_MODE_AMOUNT: u8 = 3;
+
+/// Every LFO shape can be sent to every target
+const LFO_SHAPE_AMOUNT: u8 = 5;
+const LFO_TARGET_AMOUNT: u8 = 3;
+
+/// Modulation sources, with 0 turning the slot off
+const MOD_SOURCE_AMOUNT: u8 = 9;
+/// Depth encoder value at which a modulation slot does nothing
+const MOD_DEPTH_CENTER: i16 = 128;
+
+/// Velocity curves a patch can pick. The custom curve isn't one of them, as a patch
+/// has no room for its table
+const VELOCITY_CURVE_AMOUNT: u8 = 4;
+
+/// Encoder value at which the second oscillator isn't detuned
+const DETUNE_CENTER: i16 = 128;
+/// Detune at either end of the encoder
+const MAX_DETUNE_CENTS: i16 = 50;
+/// Semitone offsets go from -12 to 12
+const SEMITONE_OFFSET_AMOUNT: u8 = 25;
+/// Octave offsets go from -2 to 2
+const OCTAVE_OFFSET_AMOUNT: u8 = 5;
+
+/// Symmetry encoder value where both halves of the wave are shaped the same
+const SYMMETRY_CENTER: i16 = 128;
+
+/// Oscillator types past the wavetables play only noise, past those FM,
+/// and then the string and phase distortion
+const OSCILLATOR_TYPE_AMOUNT: u8 = PHASE_DISTORTION_OSCILLATOR + 1;
+const SQUARE_OSCILLATOR: u8 = 2;
+const WHITE_NOISE_OSCILLATOR: u8 = 4;
+const PINK_NOISE_OSCILLATOR: u8 = 5;
+const FIRST_FM_OSCILLATOR: u8 = 6;
+const STRING_OSCILLATOR: u8 = FIRST_FM_OSCILLATOR + FmAlgorithm::AMOUNT;
+const PHASE_DISTORTION_OSCILLATOR: u8 = STRING_OSCILLATOR + 1;
+
 pub struct Generator<
     'ac,
     'wt,
@@ -49,24 +149,414 @@ impl<
         }
     }
 
+    /// Maps an encoder to a mix level, where 0 is off and 255 is only the mixed in source
+    fn get_mix_for_encoder(encoder: u8) -> Q15 {
+        if encoder == u8::MAX {
+            Q15::MAX
+        } else {
+            Q15::from_bits((encoder as i16) << 7)
+        }
+    }
+
+    /// Maps an encoder to the range `-amount / 2..=amount / 2`, wrapping around like the
+    /// oscillator type does, so that 0 is always no offset.
+    fn get_signed_offset_for_encoder(encoder: u8, amount: u8) -> i8 {
+        let offset = encoder % amount;
+        if offset > amount / 2 {
+            offset as i8 - amount as i8
+        } else {
+            offset as i8
+        }
+    }
+
+    /// Wavetable, mix level, mode and pitch offset of the second oscillator.
+    ///
+    /// The type encoder picks the wavetable with its value mod 4 like the first
+    /// oscillator, and the mode with the next "digit": 0-3 mix, 4-7 hard sync
+    /// and 8-11 ring modulation, repeating from 12 on.
+    fn get_second_oscillator_for_config(
+        config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
+    ) -> (&'static [Q15; 256], Q15, SecondOscillatorMode, PitchOffset) {
+        let mix = Self::get_mix_for_encoder(config.pages[OSCILLATOR_PAGE].values[1]);
+        let detune = config.pages[OSCILLATOR_PAGE].values[2] as i16 - DETUNE_CENTER;
+        let cents = detune * MAX_DETUNE_CENTS / DETUNE_CENTER;
+
+        let page = &config.pages[SECOND_OSCILLATOR_PAGE];
+        let wavetable = Self::get_wavetable_for_encoder(page.values[0]);
+        let mode = match (page.values[0] / 4) % SECOND_OSCILLATOR_MODE_AMOUNT {
+            0 => SecondOscillatorMode::Mix,
+            1 => SecondOscillatorMode::HardSync,
+            _ => SecondOscillatorMode::RingModulation,
+        };
+        let semitones = Self::get_signed_offset_for_encoder(page.values[1], SEMITONE_OFFSET_AMOUNT);
+        let octaves = Self::get_signed_offset_for_encoder(page.values[2], OCTAVE_OFFSET_AMOUNT);
+
+        let pitch_offset = PitchOffset::new(semitones + 12 * octaves, cents as i8);
+
+        (wavetable, mix, mode, pitch_offset)
+    }
+
+    /// Pulse width of the first oscillator, if it's a pulse.
+    ///
+    /// The square oscillator type turns into a pulse when the pulse width isn't 0,
+    /// with 128 being a 50% duty cycle.
+    fn get_pulse_width_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> Option<Q15> {
+        let osc_type = config.pages[OSCILLATOR_PAGE].values[0] % OSCILLATOR_TYPE_AMOUNT;
+        let width = config.pages[PULSE_AND_SUB_PAGE].values[0];
+
+        if osc_type == SQUARE_OSCILLATOR && width != 0 {
+            Some(Q15::from_bits((width as i16) << 7))
+        } else {
+            None
+        }
+    }
+
+    /// Shape, octave and level of the sub oscillator.
+    ///
+    /// The type goes through square one and two octaves down, and then sine one and
+    /// two octaves down, repeating from 4 on.
+    fn get_sub_oscillator_for_config(
+        config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
+    ) -> (SubOscillator, Q15) {
+        let page = &config.pages[PULSE_AND_SUB_PAGE];
+
+        let shape = match (page.values[1] / 2) % 2 {
+            0 => SubOscillatorShape::Square,
+            _ => SubOscillatorShape::Sine,
+        };
+        let octaves = page.values[1] % 2 + 1;
+        let mix = Self::get_mix_for_encoder(page.values[2]);
+
+        (SubOscillator::new(shape, octaves), mix)
+    }
+
+    /// Depth of the analog drift, or `None` when it's off
+    fn get_drift_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> Option<Q15> {
+        match config.pages[PHASE_PAGE].values[1] {
+            0 => None,
+            depth => Some(Self::get_mix_for_encoder(depth)),
+        }
+    }
+
+    /// Where the oscillators start every note, with the value mod 3
+    fn get_phase_mode_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> PhaseMode {
+        match config.pages[PHASE_PAGE].values[0] % 3 {
+            0 => PhaseMode::Reset,
+            1 => PhaseMode::FreeRunning,
+            _ => PhaseMode::Random,
+        }
+    }
+
+    /// FM algorithm, if the oscillator type is FM, and the settings of every operator.
+    ///
+    /// Every operator page is ratio, level and a time used for both the decay and the
+    /// release of the operator, which takes its attack and sustain from the envelope page.
+    fn get_fm_for_config(
+        config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
+    ) -> (Option<FmAlgorithm>, [FmOperatorConfig; FM_OPERATOR_AMOUNT]) {
+        let osc_type = config.pages[OSCILLATOR_PAGE].values[0] % OSCILLATOR_TYPE_AMOUNT;
+        let algorithm = osc_type
+            .checked_sub(FIRST_FM_OSCILLATOR)
+            .filter(|algorithm| *algorithm < FmAlgorithm::AMOUNT)
+            .map(FmAlgorithm::from_encoder);
+
+        let operators = core::array::from_fn(|operator| {
+            let page = &config.pages[FM_OPERATOR_FIRST_PAGE + operator];
+
+            FmOperatorConfig {
+                ratio: FmOperatorConfig::ratio_for_encoder(page.values[0]),
+                level: Self::get_mix_for_encoder(page.values[1]),
+                decay_release: page.values[2],
+            }
+        });
+
+        (algorithm, operators)
+    }
+
+    /// Settings of the string, if the oscillator type is the string.
+    ///
+    /// The excitation is a pluck for even values and a strike for odd ones.
+    fn get_string_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> Option<StringConfig> {
+        let osc_type = config.pages[OSCILLATOR_PAGE].values[0] % OSCILLATOR_TYPE_AMOUNT;
+        if osc_type != STRING_OSCILLATOR {
+            return None;
+        }
+
+        let page = &config.pages[STRING_PAGE];
+        let excitation = match page.values[0] % 2 {
+            0 => StringExcitation::Pluck,
+            _ => StringExcitation::Strike,
+        };
+
+        Some(StringConfig {
+            excitation,
+            decay: StringConfig::feedback_for_encoder(page.values[1]),
+            damping: StringConfig::feedback_for_encoder(page.values[2]),
+        })
+    }
+
+    /// Settings of the phase distortion oscillator, if it's the oscillator type.
+    ///
+    /// The shape goes through saw, square and resonant, repeating from 3 on.
+    fn get_phase_distortion_for_config(
+        config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
+    ) -> Option<PhaseDistortionConfig> {
+        let osc_type = config.pages[OSCILLATOR_PAGE].values[0] % OSCILLATOR_TYPE_AMOUNT;
+        if osc_type != PHASE_DISTORTION_OSCILLATOR {
+            return None;
+        }
+
+        let page = &config.pages[PHASE_DISTORTION_PAGE];
+        let shape = match page.values[0] % 3 {
+            0 => PhaseDistortionShape::Saw,
+            1 => PhaseDistortionShape::Square,
+            _ => PhaseDistortionShape::Resonant,
+        };
+
+        Some(PhaseDistortionConfig {
+            shape,
+            amount: Self::get_mix_for_encoder(page.values[1]),
+            decay_release: page.values[2],
+        })
+    }
+
+    /// Settings of the shaper, if it's on.
+    ///
+    /// The type goes through off, saturation and wavefolder, repeating from 3 on.
+    fn get_shaper_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> Option<ShaperConfig> {
+        let page = &config.pages[SHAPER_PAGE];
+        let mode = match page.values[0] % 3 {
+            0 => return None,
+            1 => ShaperMode::Table(&SATURATION_TABLE),
+            _ => ShaperMode::Fold,
+        };
+
+        Some(ShaperConfig {
+            mode,
+            drive: Self::get_mix_for_encoder(page.values[1]),
+            symmetry: Q15::from_bits((page.values[2] as i16 - SYMMETRY_CENTER) << 8),
+        })
+    }
+
+    /// Settings of the LFO, or `None` when its depth is 0 and no modulation slot uses it.
+    ///
+    /// The shape encoder picks sine, triangle, saw, square or sample and hold with its
+    /// value mod 5, and the target with the next "digit": 0-4 pitch, 5-9 amplitude and
+    /// 10-14 second oscillator mix, repeating from 15 on.
+    ///
+    /// The phase goes through per voice synced to the keys, per voice free running,
+    /// global free running and global synced to the keys, repeating from 4 on.
+    fn get_lfo_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> Option<LfoConfig> {
+        let page = &config.pages[LFO_PAGE];
+        let modulated = Self::get_mod_slots_for_config(config)
+            .iter()
+            .flatten()
+            .any(|slot| {
+                slot.source == ModSource::Lfo || slot.destination == ModDestination::LfoDepth
+            });
+        if page.values[2] == 0 && !modulated {
+            return None;
+        }
+
+        let shape = match page.values[0] % LFO_SHAPE_AMOUNT {
+            0 => LfoShape::Sine,
+            1 => LfoShape::Triangle,
+            2 => LfoShape::Saw,
+            3 => LfoShape::Square,
+            _ => LfoShape::SampleAndHold,
+        };
+        let target = match (page.values[0] / LFO_SHAPE_AMOUNT) % LFO_TARGET_AMOUNT {
+            0 => LfoTarget::Pitch,
+            1 => LfoTarget::Amplitude,
+            _ => LfoTarget::SecondOscMix,
+        };
+
+        let sync_page = &config.pages[LFO_SYNC_PAGE];
+        let (phase, key_sync) = match sync_page.values[0] % 4 {
+            0 => (LfoPhase::PerVoice, true),
+            1 => (LfoPhase::PerVoice, false),
+            2 => (LfoPhase::Global, false),
+            _ => (LfoPhase::Global, true),
+        };
+
+        Some(LfoConfig {
+            shape,
+            target,
+            rate: LfoConfig::rate_for_encoder(page.values[1]),
+            depth: Self::get_mix_for_encoder(page.values[2]),
+            phase,
+            key_sync,
+            delay: sync_page.values[1],
+            fade_in: sync_page.values[2],
+        })
+    }
+
+    /// Every modulation slot, with `None` for the ones that are off.
+    ///
+    /// The source encoder goes through off, envelope, LFO, velocity, key, aftertouch,
+    /// mod wheel, pitch bend and modulation envelope, repeating from 9 on. The destination encoder goes through
+    /// pitch, amplitude, second oscillator mix, noise level, LFO depth and pulse width, repeating from 6 on.
+    /// The depth is 0 at 128, going negative below it.
+    fn get_mod_slots_for_config(
+        config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
+    ) -> [Option<ModSlot>; MOD_SLOT_AMOUNT] {
+        core::array::from_fn(|slot| {
+            let page = &config.pages[MOD_SLOT_FIRST_PAGE + slot];
+
+            let source = match page.values[0] % MOD_SOURCE_AMOUNT {
+                0 => return None,
+                1 => ModSource::Envelope,
+                2 => ModSource::Lfo,
+                3 => ModSource::Velocity,
+                4 => ModSource::Key,
+                5 => ModSource::Aftertouch,
+                6 => ModSource::ModWheel,
+                7 => ModSource::PitchBend,
+                _ => ModSource::ModEnvelope,
+            };
+            let destination = match page.values[1] % ModDestination::AMOUNT as u8 {
+                0 => ModDestination::Pitch,
+                1 => ModDestination::Amplitude,
+                2 => ModDestination::SecondOscMix,
+                3 => ModDestination::NoiseLevel,
+                4 => ModDestination::LfoDepth,
+                _ => ModDestination::PulseWidth,
+            };
+            let depth = Q15::from_bits((page.values[2] as i16 - MOD_DEPTH_CENTER) << 8);
+            if depth == Q15::ZERO {
+                return None;
+            }
+
+            Some(ModSlot {
+                source,
+                destination,
+                depth,
+            })
+        })
+    }
+
+    /// Velocity curve and sensitivity.
+    ///
+    /// The curve encoder goes through linear, soft, hard and fixed, repeating from 4 on.
+    /// The fixed curve plays every note at the velocity of the third encoder, halved to
+    /// fit MIDI velocities.
+    fn get_velocity_response_for_config(
+        config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
+    ) -> (VelocityCurve, u8) {
+        let page = &config.pages[VELOCITY_PAGE];
+
+        let curve = match page.values[0] % VELOCITY_CURVE_AMOUNT {
+            0 => VelocityCurve::Linear,
+            1 => VelocityCurve::Soft,
+            2 => VelocityCurve::Hard,
+            _ => VelocityCurve::Fixed(page.values[2] >> 1),
+        };
+
+        (curve, page.values[1])
+    }
+
+    fn get_key_tracking_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> KeyTracking {
+        let page = &config.pages[KEY_TRACKING_PAGE];
+
+        KeyTracking {
+            attack: page.values[0],
+            decay_release: page.values[1],
+            amplitude: page.values[2],
+        }
+    }
+
+    /// Noise color and how much of it to mix into the voice.
+    ///
+    /// The noise oscillator types replace the wavetable entirely, otherwise the
+    /// noise page crossfades between the wavetable (level 0) and noise (level 255).
+    fn get_noise_for_config(config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) -> (NoiseColor, Q15) {
+        match config.pages[OSCILLATOR_PAGE].values[0] % OSCILLATOR_TYPE_AMOUNT {
+            WHITE_NOISE_OSCILLATOR => (NoiseColor::White, Q15::MAX),
+            PINK_NOISE_OSCILLATOR => (NoiseColor::Pink, Q15::MAX),
+            _ => {
+                let color = match config.pages[NOISE_PAGE].values[0] % 2 {
+                    0 => NoiseColor::White,
+                    _ => NoiseColor::Pink,
+                };
+                let mix = Self::get_mix_for_encoder(config.pages[NOISE_PAGE].values[1]);
+
+                (color, mix)
+            }
+        }
+    }
+
     pub fn new(
         receiver: Receiver<'ac, M, MidiEvent, CHANNEL_SIZE>,
         initial_config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
     ) -> Self {
-        let attack = initial_config.pages[0].values[0];
-        let sustain = initial_config.pages[0].values[1];
-        let decay_release = initial_config.pages[0].values[2];
-        let osc_type = initial_config.pages[1].values[0] % 4;
-        let wavetable = match osc_type {
-            0 => &SINE_WAVETABLE,
-            1 => &SAW_WAVETABLE,
-            2 => &SQUARE_WAVETABLE,
-            _ => &TRIANGLE_WAVETABLE,
-        };
+        let attack = initial_config.pages[ENVELOPE_PAGE].values[0];
+        let sustain = initial_config.pages[ENVELOPE_PAGE].values[1];
+        let decay = initial_config.pages[ENVELOPE_PAGE].values[2];
+        let release = initial_config.pages[ENVELOPE_TIMING_PAGE].values[0];
+        let osc_type = initial_config.pages[OSCILLATOR_PAGE].values[0] % OSCILLATOR_TYPE_AMOUNT;
+        let wavetable = Self::get_wavetable_for_encoder(osc_type);
 
-        Self {
-            voice_bank: VoiceBank::new(wavetable, sustain, attack, decay_release, receiver),
-        }
+        let mut voice_bank = VoiceBank::new(wavetable, sustain, attack, decay, release, receiver);
+
+        let delay = initial_config.pages[ENVELOPE_TIMING_PAGE].values[1];
+        let hold = initial_config.pages[ENVELOPE_TIMING_PAGE].values[2];
+        voice_bank.set_delay_and_hold_all_voices(delay, hold);
+
+        let loops = initial_config.pages[ENVELOPE_LOOP_PAGE].values;
+        voice_bank.set_envelope_loops_all_voices(
+            EnvelopeLoop::for_config(loops[0]),
+            EnvelopeLoop::for_config(loops[1]),
+        );
+
+        let curves = initial_config.pages[ENVELOPE_CURVE_PAGE].values;
+        voice_bank.set_envelope_curves_all_voices(curves[0], curves[1], curves[2]);
+
+        let pulse_width = Self::get_pulse_width_for_config(initial_config);
+        voice_bank.set_pulse_width_all_voices(pulse_width);
+
+        let (sub_osc, sub_mix) = Self::get_sub_oscillator_for_config(initial_config);
+        voice_bank.set_sub_oscillator_all_voices(sub_osc, sub_mix);
+
+        voice_bank.set_phase_mode_all_voices(Self::get_phase_mode_for_config(initial_config));
+
+        let (second_wavetable, second_mix, second_mode, second_pitch_offset) =
+            Self::get_second_oscillator_for_config(initial_config);
+        voice_bank.set_second_oscillator_all_voices(
+            second_wavetable,
+            second_mix,
+            second_mode,
+            second_pitch_offset,
+        );
+
+        let (fm_algorithm, fm_operators) = Self::get_fm_for_config(initial_config);
+        voice_bank.set_fm_all_voices(fm_algorithm, &fm_operators);
+
+        voice_bank.set_string_all_voices(Self::get_string_for_config(initial_config));
+        voice_bank
+            .set_phase_distortion_all_voices(Self::get_phase_distortion_for_config(initial_config));
+        voice_bank.set_shaper_all_voices(Self::get_shaper_for_config(initial_config));
+        voice_bank.set_drift_all_voices(Self::get_drift_for_config(initial_config));
+        voice_bank.set_lfo_all_voices(Self::get_lfo_for_config(initial_config));
+        voice_bank.set_mod_matrix(Self::get_mod_slots_for_config(initial_config));
+
+        let mod_envelope = initial_config.pages[MOD_ENVELOPE_PAGE].values;
+        let mod_envelope_release = initial_config.pages[MOD_ENVELOPE_RELEASE_PAGE].values[0];
+        voice_bank.set_mod_envelope_all_voices(
+            mod_envelope[0],
+            mod_envelope[1],
+            mod_envelope[2],
+            mod_envelope_release,
+        );
+
+        let (velocity_curve, velocity_sensitivity) =
+            Self::get_velocity_response_for_config(initial_config);
+        voice_bank.set_velocity_response(velocity_curve, velocity_sensitivity);
+        voice_bank.set_key_tracking_all_voices(Self::get_key_tracking_for_config(initial_config));
+
+        let (noise_color, noise_mix) = Self::get_noise_for_config(initial_config);
+        voice_bank.set_noise_all_voices(noise_color, noise_mix);
+
+        Self { voice_bank }
     }
 
     pub fn get_voice_bank(&self) -> &VoiceBank<'wt, 'ac, M, VOICE_BANK_SIZE, CHANNEL_SIZE> {
@@ -74,17 +564,96 @@ impl<
     }
 
     pub fn apply_config(&mut self, config: &Config<PAGE_AMOUNT, ENCODER_AMOUNT>) {
-        let attack = config.pages[0].values[0];
-        let sustain = config.pages[0].values[1];
-        let decay_release = config.pages[0].values[2];
+        let attack = config.pages[ENVELOPE_PAGE].values[0];
+        let sustain = config.pages[ENVELOPE_PAGE].values[1];
+        let decay = config.pages[ENVELOPE_PAGE].values[2];
+        let release = config.pages[ENVELOPE_TIMING_PAGE].values[0];
+
+        self.voice_bank
+            .set_adsr_config_all_voices(sustain, attack, decay, release);
 
+        let delay = config.pages[ENVELOPE_TIMING_PAGE].values[1];
+        let hold = config.pages[ENVELOPE_TIMING_PAGE].values[2];
+        self.voice_bank.set_delay_and_hold_all_voices(delay, hold);
+
+        let loops = config.pages[ENVELOPE_LOOP_PAGE].values;
+        self.voice_bank.set_envelope_loops_all_voices(
+            EnvelopeLoop::for_config(loops[0]),
+            EnvelopeLoop::for_config(loops[1]),
+        );
+
+        let curves = config.pages[ENVELOPE_CURVE_PAGE].values;
         self.voice_bank
-            .set_adsr_config_all_voices(sustain, attack, decay_release);
+            .set_envelope_curves_all_voices(curves[0], curves[1], curves[2]);
 
-        let osc_type = config.pages[1].values[0] % 4;
+        let osc_type = config.pages[OSCILLATOR_PAGE].values[0] % OSCILLATOR_TYPE_AMOUNT;
         let wavetable = Self::get_wavetable_for_encoder(osc_type);
 
         self.voice_bank.set_wavetable_all_voices(wavetable);
+
+        let pulse_width = Self::get_pulse_width_for_config(config);
+        self.voice_bank.set_pulse_width_all_voices(pulse_width);
+
+        let (sub_osc, sub_mix) = Self::get_sub_oscillator_for_config(config);
+        self.voice_bank
+            .set_sub_oscillator_all_voices(sub_osc, sub_mix);
+
+        self.voice_bank
+            .set_phase_mode_all_voices(Self::get_phase_mode_for_config(config));
+
+        let (second_wavetable, second_mix, second_mode, second_pitch_offset) =
+            Self::get_second_oscillator_for_config(config);
+        self.voice_bank.set_second_oscillator_all_voices(
+            second_wavetable,
+            second_mix,
+            second_mode,
+            second_pitch_offset,
+        );
+
+        let (fm_algorithm, fm_operators) = Self::get_fm_for_config(config);
+        self.voice_bank
+            .set_fm_all_voices(fm_algorithm, &fm_operators);
+
+        self.voice_bank
+            .set_string_all_voices(Self::get_string_for_config(config));
+        self.voice_bank
+            .set_phase_distortion_all_voices(Self::get_phase_distortion_for_config(config));
+        self.voice_bank
+            .set_shaper_all_voices(Self::get_shaper_for_config(config));
+        self.voice_bank
+            .set_drift_all_voices(Self::get_drift_for_config(config));
+        self.voice_bank
+            .set_lfo_all_voices(Self::get_lfo_for_config(config));
+        self.voice_bank
+            .set_mod_matrix(Self::get_mod_slots_for_config(config));
+
+        let mod_envelope = config.pages[MOD_ENVELOPE_PAGE].values;
+        let mod_envelope_release = config.pages[MOD_ENVELOPE_RELEASE_PAGE].values[0];
+        self.voice_bank.set_mod_envelope_all_voices(
+            mod_envelope[0],
+            mod_envelope[1],
+            mod_envelope[2],
+            mod_envelope_release,
+        );
+
+        let (velocity_curve, velocity_sensitivity) = Self::get_velocity_response_for_config(config);
+        self.voice_bank
+            .set_velocity_response(velocity_curve, velocity_sensitivity);
+        self.voice_bank
+            .set_key_tracking_all_voices(Self::get_key_tracking_for_config(config));
+
+        let (noise_color, noise_mix) = Self::get_noise_for_config(config);
+        self.voice_bank.set_noise_all_voices(noise_color, noise_mix);
+    }
+
+    /// Plays `sample` instead of the oscillators from the next note on,
+    /// or goes back to the oscillators with `None`
+    pub fn set_sample(&mut self, sample: Option<Sample<'wt>>) {
+        self.voice_bank.set_sample_all_voices(sample);
+    }
+
+    pub fn apply_tuning(&mut self, tuning: &TuningTable) {
+        self.voice_bank.set_tuning_table(tuning);
     }
 
     pub fn render_samples<T: CmsisOperations>(&mut self, sample_buffer: &mut [Q15]) {
@@ -98,23 +667,247 @@ impl<
 
         let mut i: usize = 0;
 
+        // The global LFO moves on whether any voice is playing or not
+        let lfo_config = self.voice_bank.lfo_config;
+        let mut global_lfo_buf = [Q15::ZERO; WINDOW_SIZE];
+        if let Some(LfoConfig {
+            phase: LfoPhase::Global,
+            ..
+        }) = lfo_config
+        {
+            self.voice_bank
+                .global_lfo
+                .get_samples::<WINDOW_SIZE>(&mut global_lfo_buf);
+        }
+
+        let mod_matrix = self.voice_bank.mod_matrix;
+        let mod_wheel = self.voice_bank.mod_wheel;
+        let channel_pressure = self.voice_bank.channel_pressure;
+        let pitch_bend = self.voice_bank.pitch_bend;
+        let mod_envelope_enabled = mod_matrix.uses_source(ModSource::ModEnvelope);
+
         for voice in self.voice_bank.voices.iter_mut() {
             if voice.adsr.is_idle() {
                 continue;
             }
 
+            // The drift moves slowly enough to be updated once a window
+            let (pitch_drift, drift_gain) = if voice.drift_enabled {
+                voice.drift.next_window()
+            } else {
+                (U8F24::ONE, Q15::MAX)
+            };
+
+            let mut lfo_buf = [Q15::ZERO; WINDOW_SIZE];
+            let lfo_fade = match lfo_config {
+                Some(lfo) => {
+                    match lfo.phase {
+                        LfoPhase::Global => lfo_buf = global_lfo_buf,
+                        LfoPhase::PerVoice => voice.lfo.get_samples::<WINDOW_SIZE>(&mut lfo_buf),
+                    }
+
+                    voice.lfo_fade.next_window(WINDOW_SIZE as u32)
+                }
+                None => Q15::ZERO,
+            };
+
+            // Only moved on when something follows it
+            let mod_envelope = if mod_envelope_enabled {
+                let level = voice.mod_envelope.get_level();
+                voice
+                    .mod_envelope
+                    .get_samples::<WINDOW_SIZE>(&mut [Q15::ZERO; WINDOW_SIZE]);
+                level
+            } else {
+                Q15::ZERO
+            };
+
+            // The matrix is evaluated once a window, from where the sources are at its start
+            let modulation = mod_matrix.evaluate(&ModSources {
+                envelope: voice.adsr.get_level(),
+                lfo: lfo_buf[0].saturating_mul(lfo_fade),
+                velocity: ModSources::from_controller(voice.velocity.as_u8()),
+                key: ModSources::key_for_note(voice.note.as_u8()),
+                aftertouch: voice.pressure.max(channel_pressure),
+                mod_wheel,
+                pitch_bend,
+                mod_envelope,
+            });
+
+            // The LFO scaled by its depth and fade in
+            let lfo_depth = match lfo_config {
+                Some(lfo) => modulation
+                    .offset(lfo.depth, ModDestination::LfoDepth)
+                    .saturating_mul(lfo_fade),
+                None => Q15::ZERO,
+            };
+            T::multiply_q15(&lfo_buf.clone(), &[lfo_depth; WINDOW_SIZE], &mut lfo_buf);
+            let lfo_target = lfo_config.map(|lfo| lfo.target);
+
+            // The vibrato and the modulation go along with the drift, once a window too
+            let vibrato = if lfo_target == Some(LfoTarget::Pitch) {
+                Lfo::pitch_ratio(lfo_buf[0])
+            } else {
+                U8F24::ONE
+            };
+            voice.set_pitch_drift(
+                pitch_drift
+                    .saturating_mul(vibrato)
+                    .saturating_mul(modulation.pitch_ratio()),
+            );
+
+            let second_osc_mix = if lfo_target == Some(LfoTarget::SecondOscMix) {
+                voice
+                    .second_osc_mix
+                    .saturating_add(lfo_buf[0])
+                    .max(Q15::ZERO)
+            } else {
+                voice.second_osc_mix
+            };
+            let second_osc_mix =
+                modulation.offset(second_osc_mix, ModDestination::SecondOscMix);
+            let noise_mix = modulation.offset(voice.noise_mix, ModDestination::NoiseLevel);
+            if let Some(pulse_width) = voice.pulse_width
+                && mod_matrix.routes_to(ModDestination::PulseWidth)
+            {
+                // Ramped in over the window by the oscillator
+                let pulse_width = modulation.offset(pulse_width, ModDestination::PulseWidth);
+                voice.wavetable_osc.set_pulse_width(Some(pulse_width));
+            }
+
             // Temporary buffers for this voice
             let mut wavetable_buf = [Q15::ZERO; WINDOW_SIZE];
             let mut envelope_buf = [Q15::ZERO; WINDOW_SIZE];
             let mut mixed_buf = [Q15::ZERO; WINDOW_SIZE];
 
-            // Generate wavetable samples
-            voice
-                .wavetable_osc
-                .get_samples::<T, WINDOW_SIZE>(&mut wavetable_buf);
+            // The sub oscillator follows the phase of the first oscillator,
+            // so it has to be rendered before the first oscillator moves on
+            let mut sub_buf = [Q15::ZERO; WINDOW_SIZE];
+            let sub_enabled = noise_mix != Q15::MAX
+                && !voice.fm_enabled
+                && !voice.string_enabled
+                && !voice.phase_distortion_enabled
+                && !voice.sample_osc.has_sample()
+                && voice.sub_osc_mix != Q15::ZERO;
+            if sub_enabled {
+                voice
+                    .sub_osc
+                    .get_samples::<T, WINDOW_SIZE, SAMPLE_RATE>(&voice.wavetable_osc, &mut sub_buf);
+            }
+
+            // Generate wavetable samples, crossfaded with the second oscillator
+            // and the sub oscillator, or the string, phase distortion, FM or recorded samples instead
+            let mut tone_buf = [Q15::ZERO; WINDOW_SIZE];
+            if noise_mix != Q15::MAX && voice.sample_osc.has_sample() {
+                voice
+                    .sample_osc
+                    .get_samples::<T, WINDOW_SIZE>(&mut tone_buf);
+            } else if noise_mix != Q15::MAX && voice.string_enabled {
+                voice.string_voice.get_samples::<WINDOW_SIZE>(&mut tone_buf);
+            } else if noise_mix != Q15::MAX && voice.phase_distortion_enabled {
+                voice
+                    .phase_distortion_osc
+                    .get_samples::<T, WINDOW_SIZE>(&mut tone_buf);
+            } else if noise_mix != Q15::MAX && voice.fm_enabled {
+                voice.fm_voice.get_samples::<T, WINDOW_SIZE>(&mut tone_buf);
+            } else if noise_mix != Q15::MAX && second_osc_mix == Q15::ZERO {
+                voice
+                    .wavetable_osc
+                    .get_samples::<T, WINDOW_SIZE>(&mut tone_buf);
+            } else if noise_mix != Q15::MAX {
+                let mut second_buf = [Q15::ZERO; WINDOW_SIZE];
+
+                match voice.second_osc_mode {
+                    SecondOscillatorMode::Mix => {
+                        voice
+                            .wavetable_osc
+                            .get_samples::<T, WINDOW_SIZE>(&mut tone_buf);
+                        voice
+                            .second_wavetable_osc
+                            .get_samples::<T, WINDOW_SIZE>(&mut second_buf);
+                    }
+                    SecondOscillatorMode::HardSync => {
+                        let mut sync = [false; WINDOW_SIZE];
+                        voice
+                            .wavetable_osc
+                            .get_samples_with_sync_out::<T, WINDOW_SIZE>(&mut tone_buf, &mut sync);
+                        voice
+                            .second_wavetable_osc
+                            .get_synced_samples::<T, WINDOW_SIZE>(&mut second_buf, &sync);
+                    }
+                    SecondOscillatorMode::RingModulation => {
+                        let mut modulator_buf = [Q15::ZERO; WINDOW_SIZE];
+                        voice
+                            .wavetable_osc
+                            .get_samples::<T, WINDOW_SIZE>(&mut tone_buf);
+                        voice
+                            .second_wavetable_osc
+                            .get_samples::<T, WINDOW_SIZE>(&mut modulator_buf);
+                        T::multiply_q15(&tone_buf, &modulator_buf, &mut second_buf);
+                    }
+                }
+
+                Self::crossfade::<T>(
+                    &tone_buf.clone(),
+                    &second_buf,
+                    second_osc_mix,
+                    &mut tone_buf,
+                );
+            }
+
+            if sub_enabled {
+                Self::crossfade::<T>(
+                    &tone_buf.clone(),
+                    &sub_buf,
+                    voice.sub_osc_mix,
+                    &mut tone_buf,
+                );
+            }
+
+            // Then crossfaded with noise
+            if noise_mix == Q15::ZERO {
+                wavetable_buf = tone_buf;
+            } else {
+                let mut noise_buf = [Q15::ZERO; WINDOW_SIZE];
+                voice
+                    .noise_osc
+                    .get_samples::<T, WINDOW_SIZE>(&mut noise_buf);
+
+                Self::crossfade::<T>(&tone_buf, &noise_buf, noise_mix, &mut wavetable_buf);
+            }
+
+            // Shaped before the envelope, so the timbre doesn't change as the note fades
+            if voice.shaper_enabled {
+                voice.shaper.process::<WINDOW_SIZE>(&mut wavetable_buf);
+            }
 
             // Generate ADSR envelope (now includes velocity scaling)
             voice.adsr.get_samples::<WINDOW_SIZE>(&mut envelope_buf);
+            if voice.drift_enabled {
+                T::multiply_q15(
+                    &envelope_buf.clone(),
+                    &[drift_gain; WINDOW_SIZE],
+                    &mut envelope_buf,
+                );
+            }
+            if lfo_target == Some(LfoTarget::Amplitude) && lfo_depth != Q15::ZERO {
+                let gains = lfo_buf.map(|value| Lfo::tremolo_gain(value, lfo_depth));
+                T::multiply_q15(&envelope_buf.clone(), &gains, &mut envelope_buf);
+            }
+            if mod_matrix.routes_to(ModDestination::Amplitude) {
+                // Ramped from where the last window left it, so the level doesn't step
+                let gain = modulation.amplitude_gain().to_bits() as i32;
+                let from = voice.mod_gain.map_or(gain, |from| from.to_bits() as i32);
+                voice.mod_gain = Some(Q15::from_bits(gain as i16));
+
+                let gains: [Q15; WINDOW_SIZE] = core::array::from_fn(|sample| {
+                    let step = (gain - from) * sample as i32 / WINDOW_SIZE as i32;
+                    Q15::from_bits((from + step) as i16)
+                });
+                T::multiply_q15(&envelope_buf.clone(), &gains, &mut envelope_buf);
+            } else {
+                voice.mod_gain = None;
+            }
 
             // Multiply wavetable by envelope (element-wise)
             T::multiply_q15(&wavetable_buf, &envelope_buf, &mut mixed_buf);
@@ -140,6 +933,26 @@ impl<
         }
     }
 
+    /// output = dry * (MAX - mix) + wet * mix
+    fn crossfade<T: CmsisOperations>(
+        dry: &[Q15; WINDOW_SIZE],
+        wet: &[Q15; WINDOW_SIZE],
+        mix: Q15,
+        output: &mut [Q15; WINDOW_SIZE],
+    ) {
+        if mix == Q15::MAX {
+            output.copy_from_slice(wet);
+            return;
+        }
+
+        let mut scaled_dry = [Q15::ZERO; WINDOW_SIZE];
+        let mut scaled_wet = [Q15::ZERO; WINDOW_SIZE];
+
+        T::multiply_q15(dry, &[Q15::MAX - mix; WINDOW_SIZE], &mut scaled_dry);
+        T::multiply_q15(wet, &[mix; WINDOW_SIZE], &mut scaled_wet);
+        T::add_q15(&scaled_dry, &scaled_wet, output);
+    }
+
     #[cfg(test)]
     #[allow(dead_code)]
     pub(crate) fn get_voice_bank_mut(&mut self) -> &mut VoiceBank<'wt, 'ac, M, VOICE_BANK_SIZE, CHANNEL_SIZE> {
@@ -12,33 +12,67 @@ const TEST_VOICE_BANK_SIZE: usize = 4;
 const TEST_CHANNEL_SIZE: usize = 16; // Larger channel to avoid overflow in tests
 
 // Config dimensions for tests
-const TEST_PAGE_AMOUNT: usize = 2;
+const TEST_PAGE_AMOUNT: usize = 26;
 const TEST_ENCODER_AMOUNT: usize = 3;
 
 // Default ADSR config for tests (moderate settings)
 const TEST_SUSTAIN: u8 = 200;
 const TEST_ATTACK: u8 = 50;
-const TEST_DECAY_RELEASE: u8 = 100;
+const TEST_DECAY: u8 = 100;
+const TEST_RELEASE: u8 = 120;
 
 // Fast ADSR config for lifecycle tests
 const FAST_SUSTAIN: u8 = 200;
 const FAST_ATTACK: u8 = 10;
-const FAST_DECAY_RELEASE: u8 = 10;
+const FAST_DECAY: u8 = 10;
+const FAST_RELEASE: u8 = 10;
+
+/// Fills every page after the given ones with zeroes
+fn padded_config<const PAGE_AMOUNT: usize>(
+    config: Config<PAGE_AMOUNT, TEST_ENCODER_AMOUNT>,
+) -> Config<TEST_PAGE_AMOUNT, TEST_ENCODER_AMOUNT> {
+    let mut padded = Config {
+        pages: [config::Page::from_config([0; TEST_ENCODER_AMOUNT]); TEST_PAGE_AMOUNT],
+    };
+    padded.pages[..PAGE_AMOUNT].copy_from_slice(&config.pages);
+    padded
+}
 
-// Macro for easily setting up a Generator instance with a Sender and initial config
+type TestGenerator<'ac, 'wt> = Generator<
+    'ac,
+    'wt,
+    NoopRawMutex,
+    TEST_CHANNEL_SIZE,
+    TEST_VOICE_BANK_SIZE,
+    WINDOW_SIZE,
+    TEST_PAGE_AMOUNT,
+    TEST_ENCODER_AMOUNT,
+>;
+
+// Macro for easily setting up a Generator instance with a Sender and initial config,
+// optionally changed by a closure before the Generator gets it
 macro_rules! setup_synth_engine {
     ($sender:ident, $se:ident) => {
+        setup_synth_engine!($sender, $se, |_| {});
+    };
+    ($sender:ident, $se:ident, $configure:expr) => {
         let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
         let $sender = channel.sender();
         let receiver = channel.receiver();
-        let test_config = Config {
+        let mut test_config = padded_config(Config {
             pages: [
                 config::Page {
-                    values: [TEST_ATTACK, TEST_SUSTAIN, TEST_DECAY_RELEASE],
+                    values: [TEST_ATTACK, TEST_SUSTAIN, TEST_DECAY],
                 }, // Page 0: ADSR
                 config::Page { values: [0, 0, 0] }, // Page 1: Oscillator type = 0 (sine)
+                config::Page { values: [0, 0, 0] }, // Page 2: Noise off
+                config::Page { values: [0, 0, 0] }, // Page 3: Second oscillator
             ],
-        };
+        });
+        test_config.pages[ENVELOPE_TIMING_PAGE].values = [TEST_RELEASE, 0, 0];
+        test_config.pages[VELOCITY_PAGE].values = [0, 255, 0]; // Linear, fully sensitive
+        let configure: &dyn Fn(&mut Config<TEST_PAGE_AMOUNT, TEST_ENCODER_AMOUNT>) = &$configure;
+        configure(&mut test_config);
         let mut $se = Generator::<
             '_,
             '_,
@@ -52,6 +86,37 @@ macro_rules! setup_synth_engine {
     };
 }
 
+/// Renders `windows` windows one after the other
+fn render_windows(se: &mut TestGenerator<'_, '_>, windows: usize) -> Vec<Q15> {
+    let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+    let mut samples = Vec::new();
+    for _ in 0..windows {
+        se.render_samples::<TestOps>(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+    samples
+}
+
+/// Plays `key` with the test config changed by `configure`, and renders `windows` windows of it
+fn render_note(
+    key: u8,
+    vel: u8,
+    windows: usize,
+    configure: impl Fn(&mut Config<TEST_PAGE_AMOUNT, TEST_ENCODER_AMOUNT>),
+) -> Vec<Q15> {
+    setup_synth_engine!(sender, se, configure);
+    sender.try_send(MidiEvent::NoteOn { key, vel }).unwrap();
+    render_windows(&mut se, windows)
+}
+
+/// Loudest sample of every window
+fn window_peaks(samples: &[Q15]) -> Vec<i16> {
+    samples
+        .chunks(WINDOW_SIZE)
+        .map(|window| window.iter().map(|s| s.to_bits().abs()).max().unwrap())
+        .collect()
+}
+
 // --- Property-Based Tests ---
 
 #[test]
@@ -163,14 +228,17 @@ fn test_envelope_lifecycle_to_idle() {
     let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
     let sender = channel.sender();
     let receiver = channel.receiver();
-    let fast_config = Config {
+    let mut fast_config = padded_config(Config {
         pages: [
             config::Page {
-                values: [FAST_ATTACK, FAST_SUSTAIN, FAST_DECAY_RELEASE],
+                values: [FAST_ATTACK, FAST_SUSTAIN, FAST_DECAY],
             },
             config::Page { values: [0, 0, 0] }, // Sine wavetable
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
+    fast_config.pages[ENVELOPE_TIMING_PAGE].values = [FAST_RELEASE, 0, 0];
     let mut se = Generator::<
         '_,
         '_,
@@ -301,14 +369,17 @@ fn test_rapid_note_on_off_sequences() {
     let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
     let sender = channel.sender();
     let receiver = channel.receiver();
-    let fast_config = Config {
+    let mut fast_config = padded_config(Config {
         pages: [
             config::Page {
-                values: [FAST_ATTACK, FAST_SUSTAIN, FAST_DECAY_RELEASE],
+                values: [FAST_ATTACK, FAST_SUSTAIN, FAST_DECAY],
             },
             config::Page { values: [0, 0, 0] }, // Sine wavetable
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
+    fast_config.pages[ENVELOPE_TIMING_PAGE].values = [FAST_RELEASE, 0, 0];
     let mut se = Generator::<
         '_,
         '_,
@@ -576,14 +647,17 @@ fn test_config_adsr_update() {
     let receiver = channel.receiver();
 
     // Initialize with default config
-    let initial_config = Config {
+    let mut initial_config = padded_config(Config {
         pages: [
             config::Page {
                 values: [50, 200, 100],
-            }, // Attack=50, Sustain=200, Decay/Release=100
+            }, // Attack=50, Sustain=200, Decay=100
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
             config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
+    initial_config.pages[ENVELOPE_TIMING_PAGE].values = [100, 0, 0]; // Release=100
 
     let mut se = Generator::<
         '_,
@@ -604,14 +678,17 @@ fn test_config_adsr_update() {
     se.render_samples::<TestOps>(&mut buffer);
 
     // Update config with new ADSR values
-    let new_config = Config {
+    let mut new_config = padded_config(Config {
         pages: [
             config::Page {
                 values: [10, 100, 10],
-            }, // Fast attack/release, lower sustain
+            }, // Fast attack/decay, lower sustain
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
             config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
+    new_config.pages[ENVELOPE_TIMING_PAGE].values = [10, 0, 0]; // Fast release
     se.apply_config(&new_config);
 
     // Next render should use the new config
@@ -621,6 +698,109 @@ fn test_config_adsr_update() {
     assert_eq!(se.get_voice_bank().count_active_voices(), 1);
 }
 
+#[test]
+fn test_config_release_independent_of_decay() {
+    // Same fast decay, but one releases quickly and the other slowly
+    setup_synth_engine!(short_sender, short_se, |config| {
+        config.pages[ENVELOPE_PAGE].values = [FAST_ATTACK, 100, FAST_DECAY];
+        config.pages[ENVELOPE_TIMING_PAGE].values = [FAST_RELEASE, 0, 0];
+    });
+    setup_synth_engine!(long_sender, long_se, |config| {
+        config.pages[ENVELOPE_PAGE].values = [FAST_ATTACK, 100, FAST_DECAY];
+        config.pages[ENVELOPE_TIMING_PAGE].values = [200, 0, 0];
+    });
+
+    let mut short_buffer = [Q15::ZERO; WINDOW_SIZE];
+    let mut long_buffer = [Q15::ZERO; WINDOW_SIZE];
+
+    short_sender
+        .try_send(MidiEvent::NoteOn { key: 60, vel: 100 })
+        .unwrap();
+    long_sender
+        .try_send(MidiEvent::NoteOn { key: 60, vel: 100 })
+        .unwrap();
+
+    // While held, the release time doesn't make a difference
+    for _ in 0..20 {
+        short_se.render_samples::<TestOps>(&mut short_buffer);
+        long_se.render_samples::<TestOps>(&mut long_buffer);
+        assert_eq!(short_buffer, long_buffer);
+    }
+
+    short_sender
+        .try_send(MidiEvent::NoteOff { key: 60, vel: 0 })
+        .unwrap();
+    long_sender
+        .try_send(MidiEvent::NoteOff { key: 60, vel: 0 })
+        .unwrap();
+
+    for _ in 0..100 {
+        short_se.render_samples::<TestOps>(&mut short_buffer);
+        long_se.render_samples::<TestOps>(&mut long_buffer);
+    }
+
+    assert_eq!(
+        short_se.get_voice_bank().count_active_voices(),
+        0,
+        "The short release should have finished"
+    );
+    assert_eq!(
+        long_se.get_voice_bank().count_active_voices(),
+        1,
+        "The long release should still be going"
+    );
+}
+
+#[test]
+fn test_config_delay_and_hold() {
+    let render = |timing_page: [u8; 3]| {
+        let samples = render_note(60, 127, 40, |config| {
+            config.pages[ENVELOPE_PAGE].values = [FAST_ATTACK, 100, FAST_DECAY];
+            config.pages[ENVELOPE_TIMING_PAGE].values = timing_page;
+        });
+        window_peaks(&samples)
+    };
+
+    let plain = render([FAST_RELEASE, 0, 0]);
+    assert!(plain[0] > 0);
+
+    // 40 is ~35 ms, so a few windows stay silent
+    let delayed = render([FAST_RELEASE, 40, 0]);
+    assert_eq!(delayed[0], 0, "The delay should be silent");
+    assert_eq!(delayed[1], 0);
+    assert!(
+        delayed.iter().any(|&peak| peak > 0),
+        "The attack should start after the delay"
+    );
+
+    // Holding keeps the sound at its peak after the plain envelope has decayed
+    let held = render([FAST_RELEASE, 0, 100]);
+    let last = plain.len() - 1;
+    assert!(held[last] > plain[last], "Holding should delay the decay");
+}
+
+#[test]
+fn test_config_envelope_curves() {
+    let render = |curve_page: [u8; 3]| {
+        let samples = render_note(60, 127, 20, |config| {
+            config.pages[ENVELOPE_PAGE].values = [100, 100, 100];
+            config.pages[ENVELOPE_TIMING_PAGE].values = [100, 0, 0];
+            config.pages[ENVELOPE_CURVE_PAGE].values = curve_page;
+        });
+        samples
+            .iter()
+            .map(|s| s.to_num::<f64>().powi(2))
+            .sum::<f64>()
+    };
+
+    // Over the start of the attack, the exponential curve is the loudest
+    let exponential = render([0, 0, 0]);
+    let linear = render([128, 0, 0]);
+    let logarithmic = render([255, 0, 0]);
+    assert!(exponential > linear, "{} > {}", exponential, linear);
+    assert!(linear > logarithmic, "{} > {}", linear, logarithmic);
+}
+
 #[test]
 fn test_config_wavetable_switch() {
     let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
@@ -628,14 +808,16 @@ fn test_config_wavetable_switch() {
     let receiver = channel.receiver();
 
     // Start with sine wave (osc_type = 0)
-    let sine_config = Config {
+    let sine_config = padded_config(Config {
         pages: [
             config::Page {
                 values: [50, 200, 100],
             },
             config::Page { values: [0, 0, 0] }, // Sine wave
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
 
     let mut se = Generator::<
         '_,
@@ -656,14 +838,16 @@ fn test_config_wavetable_switch() {
     se.render_samples::<TestOps>(&mut buffer_sine);
 
     // Switch to square wave (osc_type = 2)
-    let square_config = Config {
+    let square_config = padded_config(Config {
         pages: [
             config::Page {
                 values: [50, 200, 100],
             },
             config::Page { values: [2, 0, 0] }, // Square wave
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
     se.apply_config(&square_config);
 
     // Generate more samples with square wave
@@ -683,14 +867,16 @@ fn test_config_update_mid_note() {
     let sender = channel.sender();
     let receiver = channel.receiver();
 
-    let initial_config = Config {
+    let initial_config = padded_config(Config {
         pages: [
             config::Page {
                 values: [50, 200, 100],
             },
             config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
 
     let mut se = Generator::<
         '_,
@@ -714,14 +900,16 @@ fn test_config_update_mid_note() {
     let last_sample_before = buffer[WINDOW_SIZE - 1];
 
     // Update config mid-note
-    let new_config = Config {
+    let new_config = padded_config(Config {
         pages: [
             config::Page {
                 values: [10, 150, 50],
             },
             config::Page { values: [1, 0, 0] }, // Switch to sawtooth
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
     se.apply_config(&new_config);
 
     // Continue rendering
@@ -742,15 +930,17 @@ fn test_config_oscillator_modulo() {
     let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
     let receiver = channel.receiver();
 
-    // Test that oscillator type values >= 4 wrap around via modulo
-    let config_with_high_osc = Config {
+    // Test that oscillator type values >= 14 wrap around via modulo
+    let config_with_high_osc = padded_config(Config {
         pages: [
             config::Page {
                 values: [50, 200, 100],
             },
-            config::Page { values: [7, 0, 0] }, // 7 % 4 = 3 (triangle)
+            config::Page { values: [17, 0, 0] }, // 17 % 14 = 3 (triangle)
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
 
     let mut se = Generator::<
         '_,
@@ -767,17 +957,969 @@ fn test_config_oscillator_modulo() {
     let mut buffer = [Q15::ZERO; WINDOW_SIZE];
     se.render_samples::<TestOps>(&mut buffer);
 
-    // Test another value: 5 % 4 = 1 (saw)
-    let config_with_wrap = Config {
+    // Test another value: 15 % 14 = 1 (saw)
+    let config_with_wrap = padded_config(Config {
         pages: [
             config::Page {
                 values: [50, 200, 100],
             },
-            config::Page { values: [5, 0, 0] }, // 5 % 4 = 1 (saw)
+            config::Page { values: [15, 0, 0] }, // 15 % 14 = 1 (saw)
+            config::Page { values: [0, 0, 0] },
+            config::Page { values: [0, 0, 0] },
         ],
-    };
+    });
     se.apply_config(&config_with_wrap);
 
     se.render_samples::<TestOps>(&mut buffer);
     // Should not panic and should produce output
 }
+
+#[test]
+fn test_config_noise_oscillator() {
+    let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
+    let sender = channel.sender();
+    let receiver = channel.receiver();
+
+    let sine_config = padded_config(Config {
+        pages: [
+            config::Page {
+                values: [10, 200, 100],
+            },
+            config::Page { values: [0, 0, 0] }, // Sine wave
+            config::Page { values: [0, 0, 0] }, // Noise off
+            config::Page { values: [0, 0, 0] },
+        ],
+    });
+
+    let mut se = Generator::<
+        '_,
+        '_,
+        NoopRawMutex,
+        TEST_CHANNEL_SIZE,
+        TEST_VOICE_BANK_SIZE,
+        WINDOW_SIZE,
+        TEST_PAGE_AMOUNT,
+        TEST_ENCODER_AMOUNT,
+    >::new(receiver, &sine_config);
+
+    sender
+        .try_send(MidiEvent::NoteOn { key: 60, vel: 127 })
+        .unwrap();
+    let mut buffer_sine = [Q15::ZERO; WINDOW_SIZE];
+    se.render_samples::<TestOps>(&mut buffer_sine);
+
+    // Adjacent samples of a low sine barely move, while those of white noise jump around
+    let roughness = |buffer: &[Q15; WINDOW_SIZE]| -> i32 {
+        buffer
+            .windows(2)
+            .map(|pair| (pair[1].to_bits() as i32 - pair[0].to_bits() as i32).abs())
+            .sum()
+    };
+
+    for osc_type in [4, 5] {
+        let noise_config = padded_config(Config {
+            pages: [
+                config::Page {
+                    values: [10, 200, 100],
+                },
+                config::Page {
+                    values: [osc_type, 0, 0],
+                }, // White or pink noise
+                config::Page { values: [0, 0, 0] },
+                config::Page { values: [0, 0, 0] },
+            ],
+        });
+        se.apply_config(&noise_config);
+
+        let mut buffer_noise = [Q15::ZERO; WINDOW_SIZE];
+        se.render_samples::<TestOps>(&mut buffer_noise);
+        se.render_samples::<TestOps>(&mut buffer_noise);
+
+        assert!(
+            roughness(&buffer_noise) > 4 * roughness(&buffer_sine),
+            "Noise oscillator type {} should be much rougher than a sine",
+            osc_type
+        );
+    }
+}
+
+#[test]
+fn test_config_noise_level_mixes_into_wavetable() {
+    let render_with_noise_level = |level: u8| {
+        let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
+        let sender = channel.sender();
+        let receiver = channel.receiver();
+
+        let config = padded_config(Config {
+            pages: [
+                config::Page {
+                    values: [50, 200, 100],
+                },
+                config::Page { values: [0, 0, 0] }, // Sine wave
+                config::Page {
+                    values: [0, level, 0],
+                }, // White noise
+                config::Page { values: [0, 0, 0] },
+            ],
+        });
+
+        let mut se = Generator::<
+            '_,
+            '_,
+            NoopRawMutex,
+            TEST_CHANNEL_SIZE,
+            TEST_VOICE_BANK_SIZE,
+            WINDOW_SIZE,
+            TEST_PAGE_AMOUNT,
+            TEST_ENCODER_AMOUNT,
+        >::new(receiver, &config);
+
+        sender
+            .try_send(MidiEvent::NoteOn { key: 60, vel: 100 })
+            .unwrap();
+        let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+        se.render_samples::<TestOps>(&mut buffer);
+        buffer
+    };
+
+    let dry = render_with_noise_level(0);
+    let wet = render_with_noise_level(128);
+
+    assert_ne!(dry, wet, "Noise level should change the output");
+    assert_eq!(
+        wet,
+        render_with_noise_level(128),
+        "Noise should be deterministic for a fresh engine"
+    );
+}
+
+fn render_first_window(
+    oscillator_page: [u8; 3],
+    second_oscillator_page: [u8; 3],
+    key: u8,
+) -> [Q15; WINDOW_SIZE] {
+    let channel = Channel::<NoopRawMutex, MidiEvent, TEST_CHANNEL_SIZE>::new();
+    let sender = channel.sender();
+    let receiver = channel.receiver();
+
+    let config = padded_config(Config {
+        pages: [
+            config::Page {
+                values: [50, 200, 100],
+            },
+            config::Page {
+                values: oscillator_page,
+            },
+            config::Page { values: [0, 0, 0] },
+            config::Page {
+                values: second_oscillator_page,
+            },
+        ],
+    });
+
+    let mut se = Generator::<
+        '_,
+        '_,
+        NoopRawMutex,
+        TEST_CHANNEL_SIZE,
+        TEST_VOICE_BANK_SIZE,
+        WINDOW_SIZE,
+        TEST_PAGE_AMOUNT,
+        TEST_ENCODER_AMOUNT,
+    >::new(receiver, &config);
+
+    sender
+        .try_send(MidiEvent::NoteOn { key, vel: 100 })
+        .unwrap();
+    let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+    se.render_samples::<TestOps>(&mut buffer);
+    buffer
+}
+
+#[test]
+fn test_second_oscillator_pitch_offset() {
+    // Only the second oscillator, a square an octave and a fifth up
+    let second_only = render_first_window([0, 255, 128], [2, 7, 1], 48);
+    let square_at_offset = render_first_window([2, 0, 128], [0, 0, 0], 48 + 12 + 7);
+    assert_eq!(second_only, square_at_offset);
+
+    // Wrapping encoders go down: 13 is 12 semitones down and 4 is an octave down
+    let second_down = render_first_window([0, 255, 128], [0, 13, 4], 60);
+    let sine_down = render_first_window([0, 0, 128], [0, 0, 0], 60 - 12 - 12);
+    assert_eq!(second_down, sine_down);
+}
+
+#[test]
+fn test_second_oscillator_mix_and_detune() {
+    let first_only = render_first_window([0, 0, 128], [1, 0, 0], 60);
+    let mixed = render_first_window([0, 128, 128], [1, 0, 0], 60);
+    let mixed_detuned = render_first_window([0, 128, 255], [1, 0, 0], 60);
+
+    assert_eq!(
+        first_only,
+        render_first_window([0, 0, 255], [1, 5, 2], 60),
+        "Second oscillator settings shouldn't matter when its level is 0"
+    );
+    assert_ne!(first_only, mixed, "Second oscillator should be mixed in");
+    assert_ne!(mixed, mixed_detuned, "Detune should change the output");
+}
+
+#[test]
+fn test_second_oscillator_modes() {
+    // Only the second oscillator, a saw a fifth up. The first oscillator
+    // is high enough to wrap a few times in a window
+    let mixed = render_first_window([0, 255, 128], [1, 7, 0], 84);
+    let synced = render_first_window([0, 255, 128], [1 + 4, 7, 0], 84);
+    let ring_modulated = render_first_window([0, 255, 128], [1 + 8, 7, 0], 84);
+
+    assert_ne!(
+        mixed, synced,
+        "Hard sync should reset the second oscillator"
+    );
+    assert_ne!(
+        mixed, ring_modulated,
+        "Ring modulation should change the output"
+    );
+    assert_ne!(synced, ring_modulated);
+
+    // The mode repeats after ring modulation
+    assert_eq!(
+        mixed,
+        render_first_window([0, 255, 128], [1 + 12, 7, 0], 84)
+    );
+
+    // Ring modulation is the product of both oscillators, so it's never louder than either
+    let first_only = render_first_window([0, 0, 128], [0, 0, 0], 84);
+    for (ring, (first, second)) in ring_modulated
+        .iter()
+        .zip(first_only.iter().zip(mixed.iter()))
+    {
+        assert!(ring.abs() <= first.abs().min(second.abs()) + Q15::from_bits(2));
+    }
+}
+
+#[test]
+fn test_config_fm_voice() {
+    let render_fm = |algorithm: u8, operator_pages: [[u8; 3]; FM_OPERATOR_AMOUNT]| {
+        render_note(69, 127, 2, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 100];
+            config.pages[OSCILLATOR_PAGE].values = [algorithm, 0, 0];
+            for (operator, values) in operator_pages.iter().enumerate() {
+                config.pages[FM_OPERATOR_FIRST_PAGE + operator].values = *values;
+            }
+        })
+    };
+
+    // Oscillator type 6 is the two operator stack
+    let silent = render_fm(6, [[0, 0, 100]; FM_OPERATOR_AMOUNT]);
+    assert!(
+        silent.iter().all(|&s| s == Q15::ZERO),
+        "FM with every operator at level 0 should be silent"
+    );
+
+    let carrier = render_fm(6, [[0, 255, 100], [0, 0, 100], [0, 0, 100], [0, 0, 100]]);
+    let modulated = render_fm(6, [[0, 255, 100], [0, 128, 100], [0, 0, 100], [0, 0, 100]]);
+    assert!(carrier.iter().any(|&s| s != Q15::ZERO));
+    assert_ne!(carrier, modulated, "The modulator should change the output");
+
+    // Operators 3 and 4 aren't part of a two operator algorithm
+    assert_eq!(
+        modulated,
+        render_fm(
+            6,
+            [[0, 255, 100], [0, 128, 100], [3, 255, 100], [5, 255, 100]]
+        )
+    );
+}
+
+#[test]
+fn test_config_pulse_width() {
+    let render_pulse = |pulse_page: [u8; 3]| {
+        render_note(60, 127, 32, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 100];
+            config.pages[OSCILLATOR_PAGE].values = [2, 0, 0];
+            config.pages[PULSE_AND_SUB_PAGE].values = pulse_page;
+        })
+    };
+
+    let square = render_pulse([0, 0, 0]);
+    let half = render_pulse([128, 0, 0]);
+    let narrow = render_pulse([32, 0, 0]);
+
+    assert_ne!(
+        square, half,
+        "A pulse width should replace the square wavetable"
+    );
+    assert_ne!(half, narrow, "The pulse width should change the output");
+
+    let duty = |samples: &[Q15]| {
+        samples.iter().filter(|&&s| s > Q15::ZERO).count() as f64 / samples.len() as f64
+    };
+    assert!((duty(&half) - 0.5).abs() < 0.05);
+    assert!((duty(&narrow) - 0.125).abs() < 0.05);
+}
+
+#[test]
+fn test_config_pulse_width_modulation() {
+    // The modulation envelope narrows the pulse, which opens up to 50% as it decays
+    let samples = render_note(60, 127, 64, |config| {
+        config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+        config.pages[OSCILLATOR_PAGE].values = [2, 0, 0];
+        config.pages[PULSE_AND_SUB_PAGE].values = [128, 0, 0];
+        config.pages[MOD_SLOT_FIRST_PAGE].values = [8, 5, 64];
+        config.pages[MOD_ENVELOPE_PAGE].values = [0, 0, 60];
+    });
+    let duty = |samples: &[Q15]| {
+        samples.iter().filter(|&&s| s > Q15::ZERO).count() as f64 / samples.len() as f64
+    };
+
+    // The first windows ramp in from the width set on the page
+    let start = duty(&samples[4 * WINDOW_SIZE..8 * WINDOW_SIZE]);
+    let end = duty(&samples[samples.len() - 16 * WINDOW_SIZE..]);
+    assert!(start < 0.2, "Should start narrow, at {}", start);
+    assert!((end - 0.5).abs() < 0.05, "Should end at 50%, at {}", end);
+}
+
+#[test]
+fn test_config_sub_oscillator() {
+    let render_sub = |pulse_and_sub_page: [u8; 3]| {
+        render_note(60, 127, 32, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 100];
+            config.pages[OSCILLATOR_PAGE].values = [1, 0, 0];
+            config.pages[PULSE_AND_SUB_PAGE].values = pulse_and_sub_page;
+        })
+    };
+    let zero_crossings = |samples: &[Q15]| {
+        samples
+            .windows(2)
+            .filter(|w| (w[0] >= Q15::ZERO) != (w[1] >= Q15::ZERO))
+            .count() as f64
+    };
+
+    let saw = render_sub([0, 0, 0]);
+    assert_eq!(
+        saw,
+        render_sub([0, 3, 0]),
+        "The sub oscillator shouldn't matter when its level is 0"
+    );
+    assert_ne!(saw, render_sub([0, 0, 128]));
+
+    // At full level only the sub oscillator is heard, an octave or two down
+    let saw_crossings = zero_crossings(&saw);
+    for (sub_type, octaves) in [(0, 1), (1, 2), (2, 1), (3, 2)] {
+        let ratio = saw_crossings / zero_crossings(&render_sub([0, sub_type, 255]));
+        let expected = (1 << octaves) as f64;
+        assert!(
+            (ratio - expected).abs() < 0.2,
+            "Sub type {} should be {} octaves down, got a ratio of {}",
+            sub_type,
+            octaves,
+            ratio
+        );
+    }
+}
+
+#[test]
+fn test_config_phase_mode() {
+    // Plays the same note twice in a row, returning the first window of each
+    let render_twice = |phase_mode: u8| {
+        setup_synth_engine!(sender, se, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 0];
+            config.pages[OSCILLATOR_PAGE].values = [1, 0, 0];
+            config.pages[PHASE_PAGE].values = [phase_mode, 0, 0];
+        });
+
+        let mut windows = [[Q15::ZERO; WINDOW_SIZE]; 2];
+        for window in windows.iter_mut() {
+            sender
+                .try_send(MidiEvent::NoteOn { key: 60, vel: 127 })
+                .unwrap();
+            se.render_samples::<TestOps>(window);
+
+            sender
+                .try_send(MidiEvent::NoteOff { key: 60, vel: 0 })
+                .unwrap();
+            let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+            while se.get_voice_bank().count_active_voices() > 0 {
+                se.render_samples::<TestOps>(&mut buffer);
+            }
+        }
+        windows
+    };
+
+    let reset = render_twice(0);
+    assert_eq!(reset[0], reset[1], "Reset should start every note the same");
+
+    for phase_mode in [1, 2] {
+        let windows = render_twice(phase_mode);
+        assert_ne!(
+            windows[0], windows[1],
+            "Phase mode {} shouldn't restart the phase",
+            phase_mode
+        );
+        assert_eq!(
+            windows,
+            render_twice(phase_mode),
+            "Rendering should be deterministic"
+        );
+    }
+}
+
+#[test]
+fn test_sample_replaces_oscillators() {
+    setup_synth_engine!(sender, se, |config| {
+        config.pages[ENVELOPE_PAGE].values = [0, 255, 0];
+        config.pages[OSCILLATOR_PAGE].values = [1, 0, 0];
+    });
+
+    // A short burst, so the voice goes silent after the first window
+    let data = [Q15::from_num(0.5); WINDOW_SIZE];
+    se.set_sample(Some(Sample::one_shot(&data, Note::new(60))));
+
+    sender
+        .try_send(MidiEvent::NoteOn { key: 60, vel: 127 })
+        .unwrap();
+    let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+    se.render_samples::<TestOps>(&mut buffer);
+    assert!(
+        buffer.iter().all(|&s| s >= Q15::ZERO),
+        "Should be the sample, not the saw"
+    );
+    assert!(buffer.iter().any(|&s| s > Q15::ZERO));
+
+    se.render_samples::<TestOps>(&mut buffer);
+    assert!(
+        buffer.iter().all(|&s| s == Q15::ZERO),
+        "The one shot should have ended"
+    );
+
+    // Going back to the oscillators
+    se.set_sample(None);
+    sender
+        .try_send(MidiEvent::NoteOn { key: 64, vel: 127 })
+        .unwrap();
+    se.render_samples::<TestOps>(&mut buffer);
+    assert!(
+        buffer.iter().any(|&s| s < Q15::ZERO),
+        "Should be the saw again"
+    );
+}
+
+#[test]
+fn test_config_string_voice() {
+    let render_string = |osc_type: u8, string_page: [u8; 3], release: bool| {
+        setup_synth_engine!(sender, se, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[ENVELOPE_TIMING_PAGE].values = [255, 0, 0];
+            config.pages[OSCILLATOR_PAGE].values = [osc_type, 0, 0];
+            config.pages[STRING_PAGE].values = string_page;
+        });
+
+        sender
+            .try_send(MidiEvent::NoteOn { key: 57, vel: 127 })
+            .unwrap();
+        let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+        se.render_samples::<TestOps>(&mut buffer);
+
+        if release {
+            sender
+                .try_send(MidiEvent::NoteOff { key: 57, vel: 0 })
+                .unwrap();
+        }
+        render_windows(&mut se, 32)
+    };
+    let energy =
+        |samples: &[Q15]| -> f64 { samples.iter().map(|s| s.to_num::<f64>().powi(2)).sum() };
+
+    // Oscillator type 12 is the string, and it wraps around like the others
+    let string = render_string(12, [0, 250, 0], false);
+    assert!(string.iter().any(|&s| s != Q15::ZERO));
+    assert_eq!(string, render_string(12 + 14, [0, 250, 0], false));
+    assert_ne!(string, render_string(0, [0, 250, 0], false));
+    assert_ne!(string, render_string(6, [0, 250, 0], false));
+
+    assert_ne!(
+        string,
+        render_string(12, [1, 250, 0], false),
+        "Striking should sound different from plucking"
+    );
+
+    // Damping only kicks in once the note is released
+    let undamped = render_string(12, [0, 250, 255], true);
+    let damped = render_string(12, [0, 250, 0], true);
+    assert_eq!(string, render_string(12, [0, 250, 255], false));
+    assert!(
+        energy(&damped[2048..]) < energy(&undamped[2048..]) / 10.,
+        "Releasing should damp the string"
+    );
+}
+
+#[test]
+fn test_config_phase_distortion() {
+    let render_phase_distortion = |osc_type: u8, phase_distortion_page: [u8; 3]| {
+        render_note(57, 127, 8, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[OSCILLATOR_PAGE].values = [osc_type, 0, 0];
+            config.pages[PHASE_DISTORTION_PAGE].values = phase_distortion_page;
+        })
+    };
+
+    // Oscillator type 13 is phase distortion, and it wraps around like the others
+    let saw = render_phase_distortion(13, [0, 200, 255]);
+    assert!(saw.iter().any(|&s| s != Q15::ZERO));
+    assert_eq!(saw, render_phase_distortion(13 + 14, [0, 200, 255]));
+    assert_ne!(saw, render_phase_distortion(0, [0, 200, 255]));
+    assert_ne!(saw, render_phase_distortion(12, [0, 200, 255]));
+
+    // The shape repeats from 3 on
+    let square = render_phase_distortion(13, [1, 200, 255]);
+    let resonant = render_phase_distortion(13, [2, 200, 255]);
+    assert_ne!(saw, square);
+    assert_ne!(saw, resonant);
+    assert_ne!(square, resonant);
+    assert_eq!(saw, render_phase_distortion(13, [3, 200, 255]));
+
+    assert_ne!(
+        saw,
+        render_phase_distortion(13, [0, 0, 255]),
+        "The amount should change the timbre"
+    );
+}
+
+#[test]
+fn test_config_shaper() {
+    let render_shaped = |shaper_page: [u8; 3]| {
+        render_note(57, 127, 8, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[SHAPER_PAGE].values = shaper_page;
+        })
+    };
+
+    // A wavefolder with no drive and centered symmetry leaves the sine alone
+    let dry = render_shaped([0, 0, 0]);
+    assert!(dry.iter().any(|&s| s != Q15::ZERO));
+    assert_eq!(dry, render_shaped([2, 0, 128]));
+    assert_eq!(
+        dry,
+        render_shaped([3, 255, 0]),
+        "The type repeats from 3 on"
+    );
+
+    let saturated = render_shaped([1, 200, 128]);
+    let folded = render_shaped([2, 200, 128]);
+    assert_ne!(dry, saturated);
+    assert_ne!(dry, folded);
+    assert_ne!(saturated, folded);
+
+    assert_ne!(
+        folded,
+        render_shaped([2, 200, 200]),
+        "The symmetry should change the timbre"
+    );
+}
+
+#[test]
+fn test_config_analog_drift() {
+    let render_drift = |depth: u8| {
+        setup_synth_engine!(sender, se, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[PHASE_PAGE].values = [0, depth, 0];
+        });
+
+        sender
+            .try_send(MidiEvent::NoteOn { key: 57, vel: 127 })
+            .unwrap();
+        sender
+            .try_send(MidiEvent::NoteOn { key: 64, vel: 127 })
+            .unwrap();
+        render_windows(&mut se, 64)
+    };
+
+    let steady = render_drift(0);
+    let drifting = render_drift(255);
+    assert!(drifting.iter().any(|&s| s != Q15::ZERO));
+    assert_ne!(steady, drifting, "The drift should change the sound");
+
+    // The drift is seeded, so every render is the same
+    assert_eq!(drifting, render_drift(255));
+    assert_ne!(drifting, render_drift(64), "The depth should matter");
+
+    // It never makes the voices louder
+    let peak = |samples: &[Q15]| samples.iter().map(|s| s.to_bits().abs()).max().unwrap();
+    assert!(peak(&drifting) <= peak(&steady) + 64);
+}
+
+#[test]
+fn test_config_lfo() {
+    let render_lfo = |second_osc_level: u8, lfo_page: [u8; 3], sync_page: [u8; 3]| {
+        render_note(57, 127, 64, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[OSCILLATOR_PAGE].values = [0, second_osc_level, 128];
+            config.pages[SECOND_OSCILLATOR_PAGE].values = [1, 0, 0];
+            config.pages[LFO_PAGE].values = lfo_page;
+            config.pages[LFO_SYNC_PAGE].values = sync_page;
+        })
+    };
+
+    let steady = render_lfo(0, [0, 200, 0], [0, 0, 0]);
+    assert!(steady.iter().any(|&s| s != Q15::ZERO));
+
+    // Shape and target: sine, triangle, saw, square and sample and hold on the pitch,
+    // then the amplitude and then the mix of the second oscillator
+    let vibrato = render_lfo(0, [0, 200, 255], [0, 0, 0]);
+    let tremolo = render_lfo(0, [5, 200, 255], [0, 0, 0]);
+    let sweep = render_lfo(0, [10, 200, 255], [0, 0, 0]);
+    assert_ne!(steady, vibrato, "The vibrato should change the sound");
+    assert_ne!(steady, tremolo, "The tremolo should change the sound");
+    assert_ne!(
+        steady, sweep,
+        "The sweep should bring in the second oscillator"
+    );
+    assert_ne!(vibrato, tremolo);
+    assert_ne!(tremolo, render_lfo(0, [8, 200, 255], [0, 0, 0]));
+    assert_eq!(vibrato, render_lfo(0, [15, 200, 255], [0, 0, 0]));
+
+    // The rate and depth matter
+    assert_ne!(tremolo, render_lfo(0, [5, 255, 255], [0, 0, 0]));
+    assert_ne!(tremolo, render_lfo(0, [5, 200, 100], [0, 0, 0]));
+
+    // The tremolo never makes the voice louder
+    let peak = |samples: &[Q15]| samples.iter().map(|s| s.to_bits().abs()).max().unwrap();
+    assert!(peak(&tremolo) <= peak(&steady));
+
+    // The sweep moves around the level of the second oscillator
+    assert_ne!(
+        render_lfo(128, [0, 200, 0], [0, 0, 0]),
+        render_lfo(128, [10, 200, 255], [0, 0, 0])
+    );
+
+    // A delay longer than the render keeps the LFO out of it
+    assert_eq!(steady, render_lfo(0, [5, 200, 255], [0, 255, 0]));
+    assert_ne!(
+        tremolo,
+        render_lfo(0, [5, 200, 255], [0, 0, 255]),
+        "The fade in should soften the tremolo"
+    );
+}
+
+#[test]
+fn test_config_lfo_key_sync() {
+    // The second note of a voice, after a first note that already moved the LFO on
+    let render_second_note = |sync_page: [u8; 3], first_note: bool| {
+        // A fast square tremolo makes any change of phase easy to hear
+        setup_synth_engine!(sender, se, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[ENVELOPE_TIMING_PAGE].values = [0, 0, 0];
+            config.pages[LFO_PAGE].values = [8, 255, 255];
+            config.pages[LFO_SYNC_PAGE].values = sync_page;
+        });
+
+        let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+        if first_note {
+            sender
+                .try_send(MidiEvent::NoteOn { key: 57, vel: 127 })
+                .unwrap();
+            for _ in 0..8 {
+                se.render_samples::<TestOps>(&mut buffer);
+            }
+            sender
+                .try_send(MidiEvent::NoteOff { key: 57, vel: 0 })
+                .unwrap();
+        }
+        for _ in 0..15 {
+            se.render_samples::<TestOps>(&mut buffer);
+        }
+        assert_eq!(se.get_voice_bank().count_active_voices(), 0);
+
+        sender
+            .try_send(MidiEvent::NoteOn { key: 57, vel: 127 })
+            .unwrap();
+        render_windows(&mut se, 32)
+    };
+
+    // Per voice synced, per voice free, global free and global synced
+    for (phase, key_sync) in [(0, true), (1, false), (2, false), (3, true)] {
+        let after_a_note = render_second_note([phase, 0, 0], true);
+        let first = render_second_note([phase, 0, 0], false);
+
+        if key_sync {
+            assert_eq!(after_a_note, first, "Phase {} should restart", phase);
+        } else {
+            assert_ne!(after_a_note, first, "Phase {} should run free", phase);
+        }
+    }
+}
+
+#[test]
+fn test_config_mod_matrix() {
+    let render_modulated = |slot: [u8; 3], lfo_page: [u8; 3], events: &[MidiEvent]| {
+        setup_synth_engine!(sender, se, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[LFO_PAGE].values = lfo_page;
+            config.pages[MOD_SLOT_FIRST_PAGE + 2].values = slot;
+        });
+
+        for event in events {
+            sender.try_send(*event).unwrap();
+        }
+        sender
+            .try_send(MidiEvent::NoteOn { key: 57, vel: 64 })
+            .unwrap();
+        render_windows(&mut se, 32)
+    };
+    let mod_wheel = MidiEvent::Controller {
+        controller: 1,
+        value: 127,
+    };
+    let peak = |samples: &[Q15]| {
+        samples
+            .iter()
+            .map(|s| s.to_bits().unsigned_abs() as u32)
+            .max()
+            .unwrap()
+    };
+
+    let steady = render_modulated([0, 0, 0], [0, 0, 0], &[]);
+    assert!(steady.iter().any(|&s| s != Q15::ZERO));
+
+    // A slot does nothing without a source or at the center depth
+    assert_eq!(
+        steady,
+        render_modulated([0, 1, 255], [0, 0, 0], &[mod_wheel])
+    );
+    assert_eq!(
+        steady,
+        render_modulated([6, 1, 128], [0, 0, 0], &[mod_wheel])
+    );
+
+    // The pitch bend moves the pitch, either way
+    let bend_up = render_modulated(
+        [7, 0, 255],
+        [0, 0, 0],
+        &[MidiEvent::PitchBend { bend: 8191 }],
+    );
+    let bend_down = render_modulated(
+        [7, 0, 255],
+        [0, 0, 0],
+        &[MidiEvent::PitchBend { bend: -8192 }],
+    );
+    assert_ne!(steady, bend_up);
+    assert_ne!(bend_up, bend_down);
+    assert_eq!(steady, render_modulated([7, 0, 255], [0, 0, 0], &[]));
+
+    // The velocity turns the level down, and only the mod wheel moves the wheel
+    let soft = render_modulated([3, 1, 0], [0, 0, 0], &[]);
+    assert!(peak(&soft) < peak(&steady));
+    let wheel_up = render_modulated([6, 1, 255], [0, 0, 0], &[mod_wheel]);
+    assert!(peak(&wheel_up) > peak(&steady) * 99 / 100);
+    assert!(
+        peak(&render_modulated([6, 1, 255], [0, 0, 0], &[])) < peak(&steady) / 2,
+        "The level comes back as the wheel goes up"
+    );
+    assert_eq!(
+        wheel_up,
+        render_modulated(
+            [6, 1, 255],
+            [0, 0, 0],
+            &[
+                MidiEvent::Controller {
+                    controller: 7,
+                    value: 0
+                },
+                mod_wheel
+            ]
+        )
+    );
+
+    // The mod wheel brings in the LFO even when its depth is 0
+    let tremolo = render_modulated([6, 4, 255], [5, 200, 0], &[mod_wheel]);
+    assert_ne!(steady, tremolo);
+    assert_eq!(steady, render_modulated([6, 4, 255], [5, 200, 0], &[]));
+
+    // Aftertouch brings in the noise
+    assert_ne!(
+        steady,
+        render_modulated(
+            [5, 3, 255],
+            [0, 0, 0],
+            &[MidiEvent::ChannelAftertouch { vel: 100 }]
+        )
+    );
+}
+
+#[test]
+fn test_config_mod_envelope() {
+    let render_mod_envelope = |slot: [u8; 3], mod_envelope_page: [u8; 3]| {
+        render_note(57, 40, 64, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[MOD_SLOT_FIRST_PAGE].values = slot;
+            config.pages[MOD_ENVELOPE_PAGE].values = mod_envelope_page;
+        })
+    };
+    let peak = |samples: &[Q15]| {
+        samples
+            .iter()
+            .map(|s| s.to_bits().unsigned_abs() as u32)
+            .max()
+            .unwrap()
+    };
+    let crossings = |samples: &[Q15]| {
+        samples
+            .windows(2)
+            .filter(|pair| pair[0] < Q15::ZERO && pair[1] >= Q15::ZERO)
+            .count()
+    };
+
+    let steady = render_mod_envelope([0, 0, 0], [0, 0, 60]);
+
+    // A pitch drop, from an octave up down to the note
+    let drop = render_mod_envelope([8, 0, 255], [0, 0, 60]);
+    let start = 8 * WINDOW_SIZE;
+    let end = drop.len() - 16 * WINDOW_SIZE;
+    assert!(crossings(&drop[..start]) > crossings(&steady[..start]) * 3 / 2);
+    assert!(
+        crossings(&drop[end..]).abs_diff(crossings(&steady[end..])) <= 1,
+        "The pitch should be back once the envelope has decayed"
+    );
+
+    // Its own settings, and not the ones of the main envelope
+    assert_ne!(drop, render_mod_envelope([8, 0, 255], [0, 0, 120]));
+    assert_eq!(steady, render_mod_envelope([8, 0, 128], [0, 0, 60]));
+
+    // It ignores velocity, so at full sustain it gives back all the level it takes
+    let swell = render_mod_envelope([8, 1, 255], [0, 255, 0]);
+    assert!(peak(&swell) > peak(&steady) * 9 / 10);
+    assert!(peak(&render_mod_envelope([3, 1, 255], [0, 255, 0])) < peak(&steady) / 2);
+}
+
+#[test]
+fn test_config_mod_envelope_release() {
+    let render_release = |mod_envelope_release: u8| {
+        // The modulation envelope opens the amplitude, and the main one releases slowly
+        setup_synth_engine!(sender, se, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 0];
+            config.pages[ENVELOPE_TIMING_PAGE].values = [255, 0, 0];
+            config.pages[MOD_SLOT_FIRST_PAGE].values = [8, 1, 255];
+            config.pages[MOD_ENVELOPE_PAGE].values = [0, 255, 0];
+            config.pages[MOD_ENVELOPE_RELEASE_PAGE].values = [mod_envelope_release, 0, 0];
+        });
+
+        let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+        sender
+            .try_send(MidiEvent::NoteOn { key: 57, vel: 127 })
+            .unwrap();
+        for _ in 0..16 {
+            se.render_samples::<TestOps>(&mut buffer);
+        }
+        sender
+            .try_send(MidiEvent::NoteOff { key: 57, vel: 0 })
+            .unwrap();
+        for _ in 0..32 {
+            se.render_samples::<TestOps>(&mut buffer);
+        }
+        buffer.iter().map(|s| s.to_bits().abs()).max().unwrap()
+    };
+
+    // The decay is as short as it goes, but the release is its own
+    let short = render_release(0);
+    let long = render_release(255);
+    assert!(short < long / 4, "{} < {}", short, long / 4);
+}
+
+#[test]
+fn test_config_velocity_response() {
+    let render_velocity = |velocity_page: [u8; 3], vel: u8| {
+        render_note(57, vel, 16, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 255, 255];
+            config.pages[VELOCITY_PAGE].values = velocity_page;
+        })
+    };
+    let peak = |samples: &[Q15]| samples.iter().map(|s| s.to_bits().abs()).max().unwrap();
+
+    let full = render_velocity([0, 255, 0], 127);
+    let linear = render_velocity([0, 255, 0], 64);
+    assert!(peak(&linear) < peak(&full));
+
+    // Soft is louder and hard quieter for the same touch
+    assert!(peak(&render_velocity([1, 255, 0], 64)) > peak(&linear));
+    assert!(peak(&render_velocity([2, 255, 0], 64)) < peak(&linear));
+
+    // Without sensitivity, or at a fixed velocity, the touch doesn't matter
+    assert_eq!(full, render_velocity([0, 0, 0], 64));
+    assert_eq!(full, render_velocity([3, 255, 255], 20));
+    assert_eq!(
+        render_velocity([3, 255, 128], 20),
+        render_velocity([3, 255, 128], 120)
+    );
+
+    // The curves wrap around, as a patch can't pick the custom one
+    assert_eq!(linear, render_velocity([4, 255, 0], 64));
+}
+
+#[test]
+fn test_config_key_tracking() {
+    // Peak of every window, as the note decays
+    let render_key = |key_tracking_page: [u8; 3], key: u8| {
+        let samples = render_note(key, 127, 256, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 0, 60];
+            config.pages[KEY_TRACKING_PAGE].values = key_tracking_page;
+        });
+        window_peaks(&samples)
+    };
+    // Windows until the note falls below a quarter of its peak
+    let decay_windows = |peaks: &[i16]| {
+        let top = *peaks.iter().max().unwrap();
+        peaks.iter().position(|&peak| peak < top / 4).unwrap()
+    };
+
+    // Middle C is where the tracking pivots
+    assert_eq!(render_key([0, 0, 0], 60), render_key([255, 255, 255], 60));
+
+    // Higher keys decay faster and lower keys slower
+    let high = decay_windows(&render_key([0, 255, 0], 84));
+    let untracked_high = decay_windows(&render_key([0, 0, 0], 84));
+    let low = decay_windows(&render_key([0, 255, 0], 36));
+    let untracked_low = decay_windows(&render_key([0, 0, 0], 36));
+    assert!(high < untracked_high, "{} < {}", high, untracked_high);
+    assert!(low > untracked_low, "{} > {}", low, untracked_low);
+
+    // Higher keys get quieter
+    let top = |peaks: Vec<i16>| peaks.into_iter().max().unwrap();
+    assert!(top(render_key([0, 0, 255], 84)) < top(render_key([0, 0, 0], 84)));
+    assert_eq!(
+        top(render_key([0, 0, 255], 48)),
+        top(render_key([0, 0, 0], 48))
+    );
+}
+
+#[test]
+fn test_config_envelope_loop() {
+    let render_loop = |envelope_loop: u8| {
+        let samples = render_note(60, 127, 256, |config| {
+            config.pages[ENVELOPE_PAGE].values = [0, 0, 20];
+            config.pages[ENVELOPE_LOOP_PAGE].values = [envelope_loop, 0, 0];
+        });
+        window_peaks(&samples)
+    };
+    // Times the note decays below half its peak
+    let decays = |peaks: &[i16]| {
+        let half = *peaks.iter().max().unwrap() / 2;
+        peaks
+            .windows(2)
+            .filter(|pair| pair[0] >= half && pair[1] < half)
+            .count()
+    };
+
+    let settled = render_loop(0);
+    let looped_once = render_loop(1);
+    let looped_forever = render_loop(255);
+
+    assert_eq!(decays(&settled), 1);
+    assert_eq!(decays(&looped_once), 2);
+    assert!(decays(&looped_forever) > 2);
+
+    // Once it stops looping it settles just like without the loop
+    assert_eq!(*settled.last().unwrap(), 0);
+    assert_eq!(*looped_once.last().unwrap(), 0);
+    assert!(*looped_forever.last().unwrap() > 0);
+}
@@ -0,0 +1,256 @@
+use cmsis_interface::Q15;
+use defmt::Format;
+use fixed::types::{U8F24, U16F16};
+
+use crate::{
+    SAMPLE_RATE,
+    adsr::config_table::{DELAY_TIME_TABLE, HOLD_TIME_TABLE},
+    rng::Rng,
+    tuning::scale_by_semitone_fraction,
+    wavetable::sine_wavetable::SINE_WAVETABLE,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Slowest rate the encoder reaches, in Hz
+const MIN_RATE: U16F16 = U16F16::lit("0.05");
+/// Fastest rate the encoder reaches, in Hz
+const MAX_RATE: U16F16 = U16F16::lit("20");
+
+/// How far the pitch goes at full depth, in cents either way
+const MAX_PITCH_DEPTH_CENTS: i32 = 100;
+
+/// Fractions of a semitone are expressed in 1/16384ths, like the tuning does
+const SEMITONE_FRACTION_BITS: u32 = 14;
+
+/// Seed used when none is given
+const DEFAULT_SEED: u32 = 0x1F0_5EED;
+
+/// Shape of a cycle of the LFO, every one starting at the beginning of the cycle
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LfoShape {
+    Sine,
+    Triangle,
+    /// Rises from 0 to the top, jumps to the bottom and rises back to 0
+    Saw,
+    /// At the top for the first half of the cycle and at the bottom for the second one
+    Square,
+    /// A new random value every cycle
+    SampleAndHold,
+}
+
+/// What the LFO moves
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LfoTarget {
+    /// Vibrato of every pitched oscillator, up to a semitone either way
+    Pitch,
+    /// Tremolo, which only takes level away so it can't clip
+    Amplitude,
+    /// Sweeps the level of the second oscillator around the one it's set to, crossfading
+    /// it with the first oscillator
+    SecondOscMix,
+}
+
+/// Whether the voices share an LFO
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LfoPhase {
+    /// Every voice moves together
+    Global,
+    /// Every voice has its own LFO, which drift apart when they're free running
+    PerVoice,
+}
+
+/// Settings of the LFO, as set from the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LfoConfig {
+    pub shape: LfoShape,
+    pub target: LfoTarget,
+    /// In Hz
+    pub rate: U16F16,
+    pub depth: Q15,
+    pub phase: LfoPhase,
+    /// Restarts the cycle on every new note
+    pub key_sync: bool,
+    /// Time the LFO waits after a note starts before fading in, with 0 being none
+    pub delay: u8,
+    /// Time the LFO takes to fade in after the delay, with 0 being none
+    pub fade_in: u8,
+}
+
+impl LfoConfig {
+    /// From MIN_RATE to MAX_RATE, squared so that the slow rates get more of the encoder
+    pub fn rate_for_encoder(encoder: u8) -> U16F16 {
+        let squared = (encoder as u32 * encoder as u32) as u64;
+        let span = (MAX_RATE - MIN_RATE).to_bits() as u64;
+
+        MIN_RATE + U16F16::from_bits((span * squared / (255 * 255)) as u32)
+    }
+}
+
+/// Low frequency oscillator, to modulate the voices with.
+///
+/// The phase is a whole cycle over the range of a u32, so it wraps by itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Lfo {
+    phase: u32,
+    phase_increment: u32,
+    shape: LfoShape,
+    rng: Rng,
+    /// Value of the sample and hold for the current cycle
+    held: Q15,
+}
+
+impl Format for Lfo {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "Lfo {{ shape: {}, phase: {} }}",
+            self.shape,
+            self.phase
+        )
+    }
+}
+
+impl Lfo {
+    /// Seeds the sample and hold, so every LFO should get its own seed
+    pub fn new(seed: u32) -> Self {
+        let mut rng = Rng::new(seed);
+        let held = rng.next_q15();
+
+        Self {
+            phase: 0,
+            phase_increment: 0,
+            shape: LfoShape::Sine,
+            rng,
+            held,
+        }
+    }
+
+    pub fn set_shape(&mut self, shape: LfoShape) {
+        self.shape = shape;
+    }
+
+    /// Sets how many cycles a second the LFO goes through
+    pub fn set_rate(&mut self, rate: U16F16) {
+        // A cycle is 2^32, and the rate has 16 fractional bits
+        self.phase_increment = (((rate.to_bits() as u64) << 16) / SAMPLE_RATE as u64) as u32;
+    }
+
+    /// Goes back to the start of the cycle, with a new value for the sample and hold
+    pub fn reset(&mut self) {
+        self.phase = 0;
+        self.held = self.rng.next_q15();
+    }
+
+    /// Fills `buffer` with the next samples, from -1 to 1
+    pub fn get_samples<const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        for sample in buffer.iter_mut() {
+            *sample = self.value();
+
+            let (phase, wrapped) = self.phase.overflowing_add(self.phase_increment);
+            self.phase = phase;
+            if wrapped {
+                self.held = self.rng.next_q15();
+            }
+        }
+    }
+
+    fn value(&self) -> Q15 {
+        // The top 16 bits of the phase are plenty for how slowly it moves
+        let position = (self.phase >> 16) as i32;
+
+        match self.shape {
+            LfoShape::Sine => {
+                let index = (position >> 8) as usize;
+                let fraction = position & 0xFF;
+                let current = SINE_WAVETABLE[index].to_bits() as i32;
+                let next = SINE_WAVETABLE[(index + 1) % SINE_WAVETABLE.len()].to_bits() as i32;
+
+                Q15::from_bits((current + (((next - current) * fraction) >> 8)) as i16)
+            }
+            LfoShape::Triangle => {
+                let value = if position < 0x4000 {
+                    2 * position
+                } else if position < 0xC000 {
+                    0x10000 - 2 * position
+                } else {
+                    2 * position - 0x20000
+                };
+
+                Q15::from_bits(value.clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+            }
+            LfoShape::Saw => Q15::from_bits(position as u16 as i16),
+            LfoShape::Square => {
+                if position < 0x8000 {
+                    Q15::MAX
+                } else {
+                    Q15::MIN
+                }
+            }
+            LfoShape::SampleAndHold => self.held,
+        }
+    }
+
+    /// Ratio to multiply the pitch by for an LFO value already scaled by the depth
+    pub fn pitch_ratio(value: Q15) -> U8F24 {
+        // Cents to 1/2^SEMITONE_FRACTION_BITS of a semitone, and out of Q15
+        let fraction = (value.to_bits() as i32 * MAX_PITCH_DEPTH_CENTS)
+            / (100 << (15 - SEMITONE_FRACTION_BITS));
+
+        scale_by_semitone_fraction(U8F24::ONE, fraction)
+    }
+
+    /// Gain to multiply the level by for an LFO value already scaled by `depth`.
+    ///
+    /// It's full scale at the top of the LFO and `1 - depth` at the bottom.
+    pub fn tremolo_gain(value: Q15, depth: Q15) -> Q15 {
+        let gain = i16::MAX as i32 - ((depth.to_bits() as i32 - value.to_bits() as i32) >> 1);
+
+        Q15::from_bits(gain.clamp(0, i16::MAX as i32) as i16)
+    }
+}
+
+impl Default for Lfo {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+/// Keeps the LFO of a voice out for a while after every note starts, and then fades it in
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LfoFade {
+    delay_samples: u32,
+    fade_samples: u32,
+    elapsed: u32,
+}
+
+impl LfoFade {
+    /// Uses the same times as the delay and hold stages of the envelope
+    pub fn set_times(&mut self, delay_config: u8, fade_in_config: u8) {
+        self.delay_samples = DELAY_TIME_TABLE[delay_config as usize];
+        self.fade_samples = HOLD_TIME_TABLE[fade_in_config as usize];
+    }
+
+    pub fn restart(&mut self) {
+        self.elapsed = 0;
+    }
+
+    /// How much of the LFO goes through at the start of the next `samples`, moving on past them
+    pub fn next_window(&mut self, samples: u32) -> Q15 {
+        let gain = match self.elapsed.checked_sub(self.delay_samples) {
+            None => Q15::ZERO,
+            Some(fading) if fading < self.fade_samples => {
+                Q15::from_bits((((fading as u64) << 15) / self.fade_samples as u64) as i16)
+            }
+            Some(_) => Q15::MAX,
+        };
+
+        self.elapsed = self
+            .elapsed
+            .saturating_add(samples)
+            .min(self.delay_samples.saturating_add(self.fade_samples));
+
+        gain
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+
+/// An LFO with `shape` at `rate` Hz
+fn lfo_with(shape: LfoShape, rate: U16F16) -> Lfo {
+    let mut lfo = Lfo::new(1);
+    lfo.set_shape(shape);
+    lfo.set_rate(rate);
+    lfo
+}
+
+/// The next `seconds` of the LFO, sample by sample
+fn run(lfo: &mut Lfo, seconds: u32) -> Vec<Q15> {
+    let mut samples = Vec::new();
+    let mut buffer = [Q15::ZERO; 480];
+    for _ in 0..seconds * SAMPLE_RATE / 480 {
+        lfo.get_samples(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+    samples
+}
+
+fn rising_zero_crossings(samples: &[Q15]) -> usize {
+    samples
+        .windows(2)
+        .filter(|pair| pair[0] < Q15::ZERO && pair[1] >= Q15::ZERO)
+        .count()
+}
+
+#[test]
+fn test_rate_is_in_hz() {
+    for (rate, cycles) in [("1", 1), ("2", 2), ("5", 5), ("10", 10)] {
+        let rate = U16F16::from_str(rate).unwrap();
+        for shape in [LfoShape::Sine, LfoShape::Triangle, LfoShape::Square] {
+            let samples = run(&mut lfo_with(shape, rate), 4);
+            // The first cycle starts at 0, so it isn't counted
+            assert_eq!(
+                rising_zero_crossings(&samples),
+                4 * cycles - 1,
+                "{:?} at {} Hz",
+                shape,
+                rate
+            );
+        }
+    }
+}
+
+#[test]
+fn test_shapes_at_quarter_cycles() {
+    // At 0, 1/4, 1/2 and 3/4 of a cycle, or 1/8 later
+    let at_quarters = |shape, offset: usize| {
+        let samples = run(&mut lfo_with(shape, U16F16::ONE), 1);
+        [0, 2, 4, 6]
+            .map(|eighth| samples[(eighth + offset) * SAMPLE_RATE as usize / 8].to_num::<f64>())
+    };
+    let assert_close = |shape, offset, expected: [f64; 4]| {
+        let values = at_quarters(shape, offset);
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < 0.01,
+                "{:?} should be {:?}, was {:?}",
+                shape,
+                expected,
+                values
+            );
+        }
+    };
+
+    assert_close(LfoShape::Sine, 0, [0., 1., 0., -1.]);
+    assert_close(LfoShape::Triangle, 0, [0., 1., 0., -1.]);
+    // The saw and square jump halfway through, so they're checked away from the jump
+    assert_close(LfoShape::Saw, 1, [0.25, 0.75, -0.75, -0.25]);
+    assert_close(LfoShape::Square, 1, [1., 1., -1., -1.]);
+}
+
+#[test]
+fn test_sample_and_hold_changes_every_cycle() {
+    let mut lfo = lfo_with(LfoShape::SampleAndHold, U16F16::from_num(4));
+    let samples = run(&mut lfo, 2);
+
+    let mut values = samples.clone();
+    values.dedup();
+    assert_eq!(values.len(), 8, "Should hold 8 values: {:?}", values);
+
+    // Reproducible for the same seed
+    assert_eq!(
+        samples,
+        run(
+            &mut lfo_with(LfoShape::SampleAndHold, U16F16::from_num(4)),
+            2
+        )
+    );
+}
+
+#[test]
+fn test_reset_restarts_the_cycle() {
+    let mut lfo = lfo_with(LfoShape::Saw, U16F16::from_num(3));
+    let first = run(&mut lfo, 1);
+
+    run(&mut lfo, 1);
+    let mut buffer = [Q15::ZERO; 7];
+    lfo.get_samples(&mut buffer);
+
+    lfo.reset();
+    assert_eq!(first, run(&mut lfo, 1));
+}
+
+#[test]
+fn test_rate_for_encoder() {
+    assert_eq!(LfoConfig::rate_for_encoder(0), MIN_RATE);
+    assert_eq!(LfoConfig::rate_for_encoder(255), MAX_RATE);
+
+    let rates: Vec<U16F16> = (0..=255).map(LfoConfig::rate_for_encoder).collect();
+    assert!(rates.windows(2).all(|pair| pair[0] < pair[1]));
+    // A quarter of the way up is still slow enough for a vibrato that's barely moving
+    assert!(rates[64] < U16F16::from_num(1.5));
+}
+
+#[test]
+fn test_pitch_ratio_goes_up_to_a_semitone() {
+    let cents = |value: Q15| 1200. * f64::log2(Lfo::pitch_ratio(value).to_num::<f64>());
+
+    assert_eq!(Lfo::pitch_ratio(Q15::ZERO), U8F24::ONE);
+    assert!((cents(Q15::MAX) - 100.).abs() < 0.1);
+    assert!((cents(Q15::MIN) + 100.).abs() < 0.1);
+    assert!((cents(Q15::from_num(0.25)) - 25.).abs() < 0.1);
+}
+
+#[test]
+fn test_tremolo_only_takes_level_away() {
+    let depth = Q15::from_num(0.5);
+    let gain = |value: f64| Lfo::tremolo_gain(Q15::from_num(value) * depth, depth).to_num::<f64>();
+
+    assert!((gain(0.999) - 1.).abs() < 0.001);
+    assert!((gain(0.) - 0.75).abs() < 0.001);
+    assert!((gain(-1.) - 0.5).abs() < 0.001);
+
+    assert_eq!(Lfo::tremolo_gain(Q15::MIN, Q15::MAX), Q15::ZERO);
+    assert_eq!(Lfo::tremolo_gain(Q15::ZERO, Q15::ZERO), Q15::MAX);
+}
+
+#[test]
+fn test_fade_waits_for_the_delay_and_fades_in() {
+    let mut fade = LfoFade::default();
+    assert_eq!(fade.next_window(128), Q15::MAX, "No delay or fade in");
+
+    fade.set_times(128, 128);
+    fade.restart();
+    let delay = DELAY_TIME_TABLE[128];
+    let fade_in = HOLD_TIME_TABLE[128];
+
+    let gains: Vec<Q15> = (0..(delay + fade_in) / 128 + 2)
+        .map(|_| fade.next_window(128))
+        .collect();
+
+    let delay_windows = delay.div_ceil(128) as usize;
+    assert!(gains[..delay_windows].iter().all(|&gain| gain == Q15::ZERO));
+    assert!(
+        gains[delay_windows..]
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1])
+    );
+    assert_eq!(*gains.last().unwrap(), Q15::MAX);
+
+    // Every note starts over
+    fade.restart();
+    assert_eq!(fade.next_window(128), Q15::ZERO);
+}
@@ -3,9 +3,21 @@
 pub mod adsr;
 pub mod capacitor;
 pub mod db_linear_amplitude_table;
+pub mod drift;
+pub mod fm;
 pub mod generator;
+pub mod lfo;
+pub mod mod_matrix;
+pub mod noise;
 #[cfg(feature = "octave-filter")]
 pub mod octave_filter;
+pub mod phase_distortion;
+pub mod rng;
+pub mod sampler;
+pub mod shaper;
+pub mod string;
+pub mod tuning;
+pub mod velocity;
 mod voice_bank;
 pub mod wavetable;
 
@@ -19,12 +31,13 @@ pub const SAMPLE_RATE: u32 = 48000;
 use amity::triple::{TripleBuffer, TripleBufferConsumer};
 use config::Config;
 use embassy_sync::{blocking_mutex::raw::RawMutex, channel::Receiver};
-use midi::MidiEvent;
+use midi::{MidiEvent, TuningTable};
 
 pub use cmsis_interface::{CmsisOperations, Q15};
 pub use generator::Generator;
 #[cfg(feature = "octave-filter")]
 pub use octave_filter::OctaveFilterBank;
+pub use sampler::{LoopMode, Sample};
 pub use voice_bank::{Note, PlayNoteResult, Velocity, VoiceBank, VoiceStage};
 
 pub struct SynthEngine<
@@ -55,6 +68,7 @@ pub struct SynthEngine<
         Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
         &'buf TripleBuffer<Config<PAGE_AMOUNT, ENCODER_AMOUNT>>,
     >,
+    tuning_consumer: TripleBufferConsumer<TuningTable, &'buf TripleBuffer<TuningTable>>,
 }
 
 impl<
@@ -88,13 +102,18 @@ impl<
             Config<PAGE_AMOUNT, ENCODER_AMOUNT>,
             &'buf TripleBuffer<Config<PAGE_AMOUNT, ENCODER_AMOUNT>>,
         >,
+        tuning_consumer: TripleBufferConsumer<TuningTable, &'buf TripleBuffer<TuningTable>>,
     ) -> Self {
         let initial_config = config_consumer.get();
+        let mut generator = Generator::new(receiver, initial_config);
+        generator.apply_tuning(tuning_consumer.get());
+
         Self {
-            generator: Generator::new(receiver, initial_config),
+            generator,
             #[cfg(feature = "octave-filter")]
             octave_filter: OctaveFilterBank::new(),
             config_consumer,
+            tuning_consumer,
         }
     }
 
@@ -102,6 +121,12 @@ impl<
         self.generator.get_voice_bank()
     }
 
+    /// Plays `sample` instead of the oscillators from the next note on,
+    /// or goes back to the oscillators with `None`
+    pub fn set_sample(&mut self, sample: Option<Sample<'wt>>) {
+        self.generator.set_sample(sample);
+    }
+
     pub fn check_and_apply_config(&mut self) {
         if self.config_consumer.published() {
             self.config_consumer.consume();
@@ -114,8 +139,16 @@ impl<
         }
     }
 
+    pub fn check_and_apply_tuning(&mut self) {
+        if self.tuning_consumer.published() {
+            self.tuning_consumer.consume();
+            self.generator.apply_tuning(self.tuning_consumer.get());
+        }
+    }
+
     pub fn render_samples<T: CmsisOperations>(&mut self, output_samples: &mut [Q15; WINDOW_SIZE]) {
         self.check_and_apply_config();
+        self.check_and_apply_tuning();
 
         #[cfg(not(feature = "octave-filter"))]
         {
@@ -0,0 +1,200 @@
+use cmsis_interface::Q15;
+use defmt::Format;
+use fixed::types::U8F24;
+
+use crate::tuning::scale_by_semitones;
+
+#[cfg(test)]
+mod tests;
+
+pub const MOD_SLOT_AMOUNT: usize = 4;
+
+/// How far the pitch goes at full modulation, in semitones either way
+const MAX_PITCH_MODULATION_SEMITONES: i32 = 12;
+
+/// Fractions of a semitone are expressed in 1/16384ths, like the tuning does
+const SEMITONE_FRACTION_BITS: u32 = 14;
+
+/// What a slot of the matrix follows
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModSource {
+    /// The envelope of the voice, velocity included
+    Envelope,
+    /// The LFO faded in, but not scaled by its own depth
+    Lfo,
+    Velocity,
+    /// The key of the voice, 0 at middle C and full scale 64 keys away
+    Key,
+    /// The polyphonic pressure of the key of the voice, or the channel pressure,
+    /// whichever is higher
+    Aftertouch,
+    ModWheel,
+    PitchBend,
+    /// The second envelope of the voice, which leaves its level alone
+    ModEnvelope,
+}
+
+/// What a slot of the matrix moves.
+///
+/// There's no pan or filter cutoff: the voices render to a single mono buffer, so
+/// there's nothing to pan, and they have no filter of their own, as the octave filter
+/// is an equalizer over the mix of every voice.
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModDestination {
+    /// Every pitched oscillator, up to an octave either way
+    Pitch,
+    /// The level of the voice, which modulation only ever takes away from
+    Amplitude,
+    /// The level of the second oscillator, crossfading it with the first one
+    SecondOscMix,
+    /// How much noise is mixed in
+    NoiseLevel,
+    /// The depth of the LFO on its own target
+    LfoDepth,
+    /// The duty cycle of the first oscillator, when it's a pulse
+    PulseWidth,
+}
+
+impl ModDestination {
+    pub const AMOUNT: usize = 6;
+}
+
+/// Connects a source to a destination
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModSlot {
+    pub source: ModSource,
+    pub destination: ModDestination,
+    /// Negative depths move the destination the other way
+    pub depth: Q15,
+}
+
+/// The value of every source for a voice.
+///
+/// Velocity, aftertouch, mod wheel and both envelopes go from 0 to 1, and the rest from -1 to 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModSources {
+    pub envelope: Q15,
+    pub lfo: Q15,
+    pub velocity: Q15,
+    pub key: Q15,
+    pub aftertouch: Q15,
+    pub mod_wheel: Q15,
+    pub pitch_bend: Q15,
+    pub mod_envelope: Q15,
+}
+
+impl ModSources {
+    fn get(&self, source: ModSource) -> Q15 {
+        match source {
+            ModSource::Envelope => self.envelope,
+            ModSource::Lfo => self.lfo,
+            ModSource::Velocity => self.velocity,
+            ModSource::Key => self.key,
+            ModSource::Aftertouch => self.aftertouch,
+            ModSource::ModWheel => self.mod_wheel,
+            ModSource::PitchBend => self.pitch_bend,
+            ModSource::ModEnvelope => self.mod_envelope,
+        }
+    }
+
+    /// From a MIDI value of 0 to 127, to 0 to almost 1
+    pub fn from_controller(value: u8) -> Q15 {
+        Q15::from_bits((value.min(127) as i16) << 8)
+    }
+
+    /// 0 at middle C, moving 1/64 for every key and stopping at full scale
+    pub fn key_for_note(note: u8) -> Q15 {
+        let key = (note as i32 - 60) << 9;
+
+        Q15::from_bits(key.clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+    }
+}
+
+/// How far every destination is moved, in Q15 bits so that several slots can add up
+/// past full scale before being clamped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModDestinations {
+    amounts: [i32; ModDestination::AMOUNT],
+    /// Sum of the positive depths into the amplitude
+    amplitude_headroom: i32,
+}
+
+impl ModDestinations {
+    /// How far `destination` is moved, from -1 to 1
+    pub fn get(&self, destination: ModDestination) -> Q15 {
+        let amount = self.amounts[destination as usize];
+
+        Q15::from_bits(amount.clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+    }
+
+    /// Moves `base` by how far `destination` is moved, staying between 0 and 1
+    pub fn offset(&self, base: Q15, destination: ModDestination) -> Q15 {
+        let amount = base.to_bits() as i32 + self.amounts[destination as usize];
+
+        Q15::from_bits(amount.clamp(0, i16::MAX as i32) as i16)
+    }
+
+    /// Ratio to multiply the pitch by
+    pub fn pitch_ratio(&self) -> U8F24 {
+        let amount = self.get(ModDestination::Pitch).to_bits() as i32;
+        // Out of Q15 and into 1/2^SEMITONE_FRACTION_BITS of a semitone
+        let fraction = (amount * MAX_PITCH_MODULATION_SEMITONES) >> (15 - SEMITONE_FRACTION_BITS);
+
+        scale_by_semitones(U8F24::ONE, fraction)
+    }
+
+    /// Gain to multiply the level by.
+    ///
+    /// Every positive depth takes its share of the level away, and gives it back as its
+    /// source goes up. Negative depths take level away as their source goes up instead.
+    pub fn amplitude_gain(&self) -> Q15 {
+        let gain = i16::MAX as i32 - self.amplitude_headroom
+            + self.amounts[ModDestination::Amplitude as usize];
+
+        Q15::from_bits(gain.clamp(0, i16::MAX as i32) as i16)
+    }
+}
+
+/// A fixed number of slots, each moving a destination by a source times a depth.
+///
+/// Evaluated once per window for every voice, which is plenty for how fast the sources move.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModMatrix {
+    slots: [Option<ModSlot>; MOD_SLOT_AMOUNT],
+}
+
+impl ModMatrix {
+    pub fn set_slots(&mut self, slots: [Option<ModSlot>; MOD_SLOT_AMOUNT]) {
+        self.slots = slots;
+    }
+
+    pub fn uses_source(&self, source: ModSource) -> bool {
+        self.slots
+            .iter()
+            .flatten()
+            .any(|slot| slot.source == source)
+    }
+
+    pub fn routes_to(&self, destination: ModDestination) -> bool {
+        self.slots
+            .iter()
+            .flatten()
+            .any(|slot| slot.destination == destination)
+    }
+
+    pub fn evaluate(&self, sources: &ModSources) -> ModDestinations {
+        let mut destinations = ModDestinations::default();
+
+        for slot in self.slots.iter().flatten() {
+            let depth = slot.depth.to_bits() as i32;
+            let source = sources.get(slot.source).to_bits() as i32;
+
+            destinations.amounts[slot.destination as usize] += (depth * source) >> 15;
+            if slot.destination == ModDestination::Amplitude && depth > 0 {
+                destinations.amplitude_headroom += depth;
+            }
+        }
+
+        destinations
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+fn slot(source: ModSource, destination: ModDestination, depth: f64) -> Option<ModSlot> {
+    Some(ModSlot {
+        source,
+        destination,
+        depth: Q15::from_num(depth),
+    })
+}
+
+fn matrix(slots: [Option<ModSlot>; MOD_SLOT_AMOUNT]) -> ModMatrix {
+    let mut matrix = ModMatrix::default();
+    matrix.set_slots(slots);
+    matrix
+}
+
+fn close(value: Q15, expected: f64) -> bool {
+    (value.to_num::<f64>() - expected).abs() < 0.001
+}
+
+#[test]
+fn test_empty_matrix_moves_nothing() {
+    let sources = ModSources {
+        mod_wheel: Q15::MAX,
+        pitch_bend: Q15::MIN,
+        ..Default::default()
+    };
+    let destinations = ModMatrix::default().evaluate(&sources);
+
+    assert_eq!(destinations.pitch_ratio(), U8F24::ONE);
+    assert_eq!(destinations.amplitude_gain(), Q15::MAX);
+    assert_eq!(
+        destinations.offset(Q15::from_num(0.5), ModDestination::NoiseLevel),
+        Q15::from_num(0.5)
+    );
+}
+
+#[test]
+fn test_slots_add_up_with_signed_depths() {
+    let matrix = matrix([
+        slot(ModSource::ModWheel, ModDestination::NoiseLevel, 0.5),
+        slot(ModSource::Velocity, ModDestination::NoiseLevel, -0.25),
+        slot(ModSource::Lfo, ModDestination::SecondOscMix, 0.5),
+        None,
+    ]);
+    let sources = ModSources {
+        mod_wheel: Q15::from_num(0.5),
+        velocity: Q15::from_num(0.5),
+        lfo: Q15::from_num(-0.5),
+        ..Default::default()
+    };
+    let destinations = matrix.evaluate(&sources);
+
+    assert!(close(destinations.get(ModDestination::NoiseLevel), 0.125));
+    assert!(close(destinations.get(ModDestination::SecondOscMix), -0.25));
+    assert_eq!(destinations.get(ModDestination::Pitch), Q15::ZERO);
+}
+
+#[test]
+fn test_offset_stays_in_range() {
+    let matrix = matrix([
+        slot(ModSource::PitchBend, ModDestination::LfoDepth, 0.999),
+        slot(ModSource::PitchBend, ModDestination::LfoDepth, 0.999),
+        None,
+        None,
+    ]);
+
+    let up = matrix.evaluate(&ModSources {
+        pitch_bend: Q15::MAX,
+        ..Default::default()
+    });
+    assert_eq!(up.get(ModDestination::LfoDepth), Q15::MAX);
+    assert_eq!(
+        up.offset(Q15::from_num(0.5), ModDestination::LfoDepth),
+        Q15::MAX
+    );
+
+    let down = matrix.evaluate(&ModSources {
+        pitch_bend: Q15::MIN,
+        ..Default::default()
+    });
+    assert_eq!(
+        down.offset(Q15::from_num(0.5), ModDestination::LfoDepth),
+        Q15::ZERO
+    );
+}
+
+#[test]
+fn test_pitch_goes_up_to_an_octave() {
+    let matrix = matrix([
+        slot(ModSource::PitchBend, ModDestination::Pitch, 0.999_969),
+        None,
+        None,
+        None,
+    ]);
+    let semitones = |bend: Q15| {
+        let ratio = matrix
+            .evaluate(&ModSources {
+                pitch_bend: bend,
+                ..Default::default()
+            })
+            .pitch_ratio();
+        12. * f64::log2(ratio.to_num::<f64>())
+    };
+
+    assert!((semitones(Q15::MAX) - 12.).abs() < 0.01);
+    assert!((semitones(Q15::MIN) + 12.).abs() < 0.01);
+    assert!((semitones(Q15::from_num(1. / 6.)) - 2.).abs() < 0.01);
+}
+
+#[test]
+fn test_amplitude_only_takes_level_away() {
+    let up = matrix([
+        slot(ModSource::Velocity, ModDestination::Amplitude, 0.5),
+        None,
+        None,
+        None,
+    ]);
+    let down = matrix([
+        slot(ModSource::ModWheel, ModDestination::Amplitude, -0.5),
+        None,
+        None,
+        None,
+    ]);
+    let gain = |matrix: &ModMatrix, value: f64| {
+        matrix
+            .evaluate(&ModSources {
+                velocity: Q15::from_num(value),
+                mod_wheel: Q15::from_num(value),
+                ..Default::default()
+            })
+            .amplitude_gain()
+    };
+
+    // A positive depth starts from the headroom it takes and comes back up
+    assert!(close(gain(&up, 0.), 0.5));
+    assert!(close(gain(&up, 0.5), 0.75));
+    assert!(close(gain(&up, 0.999), 1.));
+
+    // A negative depth starts at full level and goes down
+    assert_eq!(gain(&down, 0.), Q15::MAX);
+    assert!(close(gain(&down, 0.5), 0.75));
+    assert!(close(gain(&down, 0.999), 0.5));
+}
+
+#[test]
+fn test_which_sources_and_destinations_are_used() {
+    let matrix = matrix([
+        None,
+        slot(ModSource::Lfo, ModDestination::Pitch, 0.5),
+        None,
+        None,
+    ]);
+
+    assert!(matrix.uses_source(ModSource::Lfo));
+    assert!(!matrix.uses_source(ModSource::Envelope));
+    assert!(matrix.routes_to(ModDestination::Pitch));
+    assert!(!matrix.routes_to(ModDestination::Amplitude));
+}
+
+#[test]
+fn test_key_and_controllers() {
+    assert_eq!(ModSources::key_for_note(60), Q15::ZERO);
+    assert!(close(ModSources::key_for_note(72), 0.1875));
+    assert!(close(ModSources::key_for_note(48), -0.1875));
+    // Stops at full scale 64 keys above middle C
+    assert_eq!(ModSources::key_for_note(127), Q15::MAX);
+    assert!(close(ModSources::key_for_note(0), -0.9375));
+
+    assert_eq!(ModSources::from_controller(0), Q15::ZERO);
+    assert!(close(ModSources::from_controller(64), 0.5));
+    assert!(close(ModSources::from_controller(127), 0.992));
+}
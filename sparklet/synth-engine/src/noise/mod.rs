@@ -0,0 +1,91 @@
+use cmsis_interface::{CmsisOperations, Q15};
+use defmt::Format;
+
+use crate::rng::Rng;
+
+/// Number of Voss-McCartney rows. The slowest one is updated every 2^PINK_ROWS
+/// samples, which keeps the -3 dB/octave slope down to ~1.5 Hz at 48 kHz.
+const PINK_ROWS: usize = 15;
+
+/// The sum of the rows and the white term is divided by 2^PINK_SHIFT.
+/// Dividing by the amount of terms would make pink noise much quieter than white
+/// noise, so we divide by roughly its standard deviation instead and saturate
+/// the (rare) peaks.
+const PINK_SHIFT: u32 = 3;
+
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseColor {
+    /// Flat spectrum
+    White,
+    /// -3 dB per octave
+    Pink,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoiseOscillator {
+    color: NoiseColor,
+    rng: Rng,
+    pink_rows: [i32; PINK_ROWS],
+    pink_sum: i32,
+    pink_counter: u32,
+}
+
+impl Format for NoiseOscillator {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "NoiseOscillator {{ color: {} }}", self.color)
+    }
+}
+
+impl NoiseOscillator {
+    pub fn new(color: NoiseColor, seed: u32) -> Self {
+        Self {
+            color,
+            rng: Rng::new(seed),
+            pink_rows: [0; PINK_ROWS],
+            pink_sum: 0,
+            pink_counter: 0,
+        }
+    }
+
+    pub fn set_color(&mut self, color: NoiseColor) {
+        self.color = color;
+    }
+
+    pub fn get_samples<T: CmsisOperations, const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        match self.color {
+            NoiseColor::White => {
+                for sample in buffer.iter_mut() {
+                    *sample = self.rng.next_q15();
+                }
+            }
+            NoiseColor::Pink => {
+                for sample in buffer.iter_mut() {
+                    *sample = self.next_pink();
+                }
+            }
+        }
+    }
+
+    /*
+     * Voss-McCartney algorithm: row k holds a random value that is only
+     * refreshed every 2^(k+1) samples. Picking the row with the number of
+     * trailing zeros of a counter refreshes exactly one row per sample,
+     * and the sum of all of them (plus a white term for the top octave)
+     * approximates a 1/f spectrum.
+     */
+    fn next_pink(&mut self) -> Q15 {
+        self.pink_counter = self.pink_counter.wrapping_add(1);
+        let row = self.pink_counter.trailing_zeros() as usize;
+
+        if row < PINK_ROWS {
+            let value = self.rng.next_q15().to_bits() as i32;
+            self.pink_sum += value - self.pink_rows[row];
+            self.pink_rows[row] = value;
+        }
+
+        let white = self.rng.next_q15().to_bits() as i32;
+        let output = (self.pink_sum + white) >> PINK_SHIFT;
+
+        Q15::from_bits(output.clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+    }
+}
@@ -0,0 +1,245 @@
+use cmsis_interface::{CmsisOperations, Q15};
+use defmt::Format;
+use fixed::types::U8F24;
+
+use crate::{
+    Note,
+    adsr::ADSR,
+    tuning::Tuning,
+    wavetable::{Interpolation, Wavetable, sine_wavetable::SINE_WAVETABLE},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The warp envelope ignores velocity, it's already applied by the voice envelope
+const WARP_ENVELOPE_VELOCITY: u8 = 127;
+
+/*
+ * Warping is done on the top 16 bits of the phase, so a whole cycle is 2^16.
+ * That still leaves 8 bits between every two samples of the wavetable.
+ */
+const FULL_CYCLE: u32 = 1 << 16;
+const HALF_CYCLE: u32 = FULL_CYCLE / 2;
+const QUARTER_CYCLE: u32 = FULL_CYCLE / 4;
+
+/// Shortest a steep segment of the warped phase can get, so it never turns into a jump
+const MIN_KNEE: u32 = FULL_CYCLE / 128;
+
+/// How many times faster than the note the resonant sine goes at full warp
+const MAX_RESONANCE: u32 = 16;
+
+/// How the phase is bent before reading the sine
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhaseDistortionShape {
+    /// Goes through the falling half of the sine faster and the rising one slower,
+    /// sweeping from a sine to a saw
+    Saw,
+    /// Rushes through the zero crossings and waits at the peaks,
+    /// sweeping from a sine to a square
+    Square,
+    /// A faster sine that restarts every cycle, faded in and out with a triangle.
+    /// Sweeping it sounds like a resonant filter.
+    Resonant,
+}
+
+/// Settings of the phase distortion, as set from the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseDistortionConfig {
+    pub shape: PhaseDistortionShape,
+    /// Warp at the peak of the envelope
+    pub amount: Q15,
+    pub decay_release: u8,
+}
+
+/// Casio CZ style oscillator: a sine read with a bent phase.
+///
+/// The warp follows an envelope of its own, so the timbre can evolve over the note.
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseDistortionOscillator {
+    phase: U8F24,
+    phase_increment: U8F24,
+    /// Phase increment of the note, before the drift
+    tuned_phase_increment: U8F24,
+    drift: U8F24,
+    shape: PhaseDistortionShape,
+    amount: Q15,
+    envelope: ADSR,
+    interpolation: Interpolation,
+}
+
+impl Format for PhaseDistortionOscillator {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "PhaseDistortionOscillator {{ shape: {}, envelope: {} }}",
+            self.shape,
+            self.envelope
+        )
+    }
+}
+
+impl PhaseDistortionOscillator {
+    pub fn new(attack: u8, sustain: u8, decay_release: u8) -> Self {
+        Self {
+            phase: U8F24::ZERO,
+            phase_increment: U8F24::ZERO,
+            tuned_phase_increment: U8F24::ZERO,
+            drift: U8F24::ONE,
+            shape: PhaseDistortionShape::Saw,
+            amount: Q15::ZERO,
+            envelope: ADSR::new(
+                sustain,
+                attack,
+                decay_release,
+                decay_release,
+                WARP_ENVELOPE_VELOCITY,
+            ),
+            interpolation: Interpolation::DEFAULT,
+        }
+    }
+
+    pub fn set_config(&mut self, config: &PhaseDistortionConfig) {
+        self.shape = config.shape;
+        self.amount = config.amount;
+        self.envelope.set_decay_release(config.decay_release);
+    }
+
+    /// Sets the attack and sustain of the warp envelope, which are shared with the voice
+    pub fn set_envelope(&mut self, attack: u8, sustain: u8) {
+        self.envelope.set_attack(attack);
+        self.envelope.set_sustain(sustain);
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Starts a note from the beginning of the cycle, with the warp envelope from the start
+    pub fn set_note(&mut self, note: &Note, tuning: &Tuning) {
+        self.phase = U8F24::ZERO;
+        self.retune(note, tuning);
+        self.envelope.play(WARP_ENVELOPE_VELOCITY);
+    }
+
+    /// Updates the pitch of the current note without touching the phase
+    pub fn retune(&mut self, note: &Note, tuning: &Tuning) {
+        self.tuned_phase_increment = tuning.phase_increment(note);
+        self.phase_increment = self.tuned_phase_increment.saturating_mul(self.drift);
+    }
+
+    /// Detunes the current note by `drift` times its frequency, and the ones after it too
+    pub fn set_drift(&mut self, drift: U8F24) {
+        self.drift = drift;
+        self.phase_increment = self.tuned_phase_increment.saturating_mul(drift);
+    }
+
+    pub fn retrigger(&mut self) {
+        self.envelope.retrigger(WARP_ENVELOPE_VELOCITY);
+    }
+
+    pub fn stop_playing(&mut self) {
+        self.envelope.stop_playing();
+    }
+
+    pub fn get_samples<T: CmsisOperations, const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        let mut envelope_buf = [Q15::ZERO; LEN];
+        let mut warps = [Q15::ZERO; LEN];
+        self.envelope.get_samples::<LEN>(&mut envelope_buf);
+        T::multiply_q15(&envelope_buf, &[self.amount; LEN], &mut warps);
+
+        let mut phases = [U8F24::ZERO; LEN];
+        let mut windows = [Q15::MAX; LEN];
+
+        for ((phase, window), warp) in phases.iter_mut().zip(windows.iter_mut()).zip(warps) {
+            let position = self.phase.to_bits() >> 16;
+            let warp = warp.to_bits().max(0) as u32;
+
+            let warped = match self.shape {
+                PhaseDistortionShape::Saw => Self::warp_saw(position, warp),
+                PhaseDistortionShape::Square => Self::warp_square(position, warp),
+                PhaseDistortionShape::Resonant => {
+                    *window = Self::triangle_window(position);
+                    Self::warp_resonant(position, warp)
+                }
+            };
+
+            *phase = U8F24::from_bits(warped << 16);
+            self.phase = self.phase.wrapping_add(self.phase_increment);
+        }
+
+        if self.shape == PhaseDistortionShape::Resonant {
+            let mut sines = [Q15::ZERO; LEN];
+            Wavetable(&SINE_WAVETABLE).interpolate::<T, LEN>(
+                self.interpolation,
+                &phases,
+                &mut sines,
+            );
+            T::multiply_q15(&sines, &windows, buffer);
+        } else {
+            Wavetable(&SINE_WAVETABLE).interpolate::<T, LEN>(self.interpolation, &phases, buffer);
+        }
+    }
+
+    /*
+     * The half of the sine that goes from the top to the bottom is squeezed into
+     * `knee`, which goes from half a cycle (no warp) down to MIN_KNEE, and the
+     * other half is stretched over the rest of the cycle.
+     *
+     * Squeezing the halves between the peaks, instead of the positive and the
+     * negative halves, keeps both of them centered around zero so there's no DC.
+     */
+    fn warp_saw(position: u32, warp: u32) -> u32 {
+        let knee = HALF_CYCLE - (((HALF_CYCLE - MIN_KNEE) * warp) >> 15);
+        let from_peak = (position + QUARTER_CYCLE) % FULL_CYCLE;
+
+        let warped = if from_peak < knee {
+            from_peak * HALF_CYCLE / knee
+        } else {
+            HALF_CYCLE + (from_peak - knee) * HALF_CYCLE / (FULL_CYCLE - knee)
+        };
+
+        (warped + FULL_CYCLE - QUARTER_CYCLE) % FULL_CYCLE
+    }
+
+    /*
+     * Every quarter of the sine that goes through a zero crossing is squeezed
+     * into `knee`, which goes from a quarter of a cycle (no warp) down to MIN_KNEE.
+     * The phase stays at the peaks for the rest of the time.
+     */
+    fn warp_square(position: u32, warp: u32) -> u32 {
+        let knee = QUARTER_CYCLE - (((QUARTER_CYCLE - MIN_KNEE) * warp) >> 15);
+
+        if position < knee {
+            position * QUARTER_CYCLE / knee
+        } else if position < HALF_CYCLE - knee {
+            QUARTER_CYCLE
+        } else if position < HALF_CYCLE + knee {
+            QUARTER_CYCLE + (position + knee - HALF_CYCLE) * HALF_CYCLE / (2 * knee)
+        } else if position < FULL_CYCLE - knee {
+            3 * QUARTER_CYCLE
+        } else {
+            3 * QUARTER_CYCLE + (position + knee - FULL_CYCLE) * QUARTER_CYCLE / knee
+        }
+    }
+
+    /// Multiplies the phase by 1 (no warp) up to MAX_RESONANCE, wrapping around
+    fn warp_resonant(position: u32, warp: u32) -> u32 {
+        // 4.12 fixed point
+        let ratio = (1 << 12) + (((MAX_RESONANCE - 1) * warp) >> 3);
+
+        ((position * ratio) >> 12) % FULL_CYCLE
+    }
+
+    /// Zero at the start and end of the cycle and full scale in the middle,
+    /// so the resonant sine restarts without clicking
+    fn triangle_window(position: u32) -> Q15 {
+        let distance_to_edge = if position < HALF_CYCLE {
+            position
+        } else {
+            FULL_CYCLE - position
+        };
+
+        Q15::from_bits(distance_to_edge.min(i16::MAX as u32) as i16)
+    }
+}
@@ -0,0 +1,226 @@
+use super::*;
+use crate::wavetable::phase_increment_table::MIDI_TO_PHASE_INCREMENT;
+
+type TestOps = cmsis_rust::CmsisRustOperations;
+
+const WINDOW_SIZE: usize = 128;
+const A3: Note = Note::new(57);
+
+// Fastest attack and a full sustain, so the warp is at its amount right away
+const FAST_ATTACK: u8 = 0;
+const FULL_SUSTAIN: u8 = 255;
+const DECAY_RELEASE: u8 = 100;
+
+fn create_oscillator(shape: PhaseDistortionShape, amount: Q15) -> PhaseDistortionOscillator {
+    let mut osc = PhaseDistortionOscillator::new(FAST_ATTACK, FULL_SUSTAIN, DECAY_RELEASE);
+    osc.set_config(&PhaseDistortionConfig {
+        shape,
+        amount,
+        decay_release: DECAY_RELEASE,
+    });
+    osc.set_note(&A3, &Tuning::default());
+    osc
+}
+
+/// Renders `windows` windows, after skipping the attack
+fn render(osc: &mut PhaseDistortionOscillator, windows: usize) -> Vec<Q15> {
+    let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+    for _ in 0..4 {
+        osc.get_samples::<TestOps, WINDOW_SIZE>(&mut buffer);
+    }
+
+    let mut samples = Vec::with_capacity(windows * WINDOW_SIZE);
+    for _ in 0..windows {
+        osc.get_samples::<TestOps, WINDOW_SIZE>(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+    samples
+}
+
+/// Magnitude of the DFT of `samples` at `harmonic` times the frequency of A3.
+///
+/// The samples go through a Hann window, so the fundamental doesn't leak into
+/// the harmonics even though the buffer isn't a whole number of cycles.
+fn harmonic_magnitude(samples: &[Q15], harmonic: usize) -> f64 {
+    let cycles_per_sample =
+        MIDI_TO_PHASE_INCREMENT[A3.as_u8() as usize].to_num::<f64>() / 256.0 * harmonic as f64;
+    let length = samples.len() as f64;
+
+    let (re, im) = samples
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(re, im), (n, sample)| {
+            let angle = 2.0 * core::f64::consts::PI * cycles_per_sample * n as f64;
+            let window = 1.0 - (2.0 * core::f64::consts::PI * n as f64 / length).cos();
+            let sample = sample.to_num::<f64>() * window;
+            (re + sample * angle.cos(), im - sample * angle.sin())
+        });
+
+    2. * (re * re + im * im).sqrt() / length
+}
+
+/// Energy of the harmonics above the fundamental, relative to the fundamental
+fn harmonic_content(samples: &[Q15]) -> f64 {
+    let fundamental = harmonic_magnitude(samples, 1);
+    (2..16)
+        .map(|harmonic| harmonic_magnitude(samples, harmonic).powi(2))
+        .sum::<f64>()
+        / fundamental.powi(2)
+}
+
+#[test]
+fn test_no_warp_is_a_sine() {
+    for shape in [PhaseDistortionShape::Saw, PhaseDistortionShape::Square] {
+        let samples = render(&mut create_oscillator(shape, Q15::ZERO), 40);
+
+        let fundamental = harmonic_magnitude(&samples, 1);
+        assert!(
+            fundamental > 0.95,
+            "{:?}: should be a full scale sine, got {}",
+            shape,
+            fundamental
+        );
+        assert!(
+            harmonic_content(&samples) < 1e-5,
+            "{:?}: shouldn't have harmonics without warp",
+            shape
+        );
+    }
+}
+
+#[test]
+fn test_warp_adds_harmonics() {
+    for shape in [PhaseDistortionShape::Saw, PhaseDistortionShape::Square] {
+        let contents: Vec<f64> = [0.25, 0.5, 0.75, 0.99]
+            .iter()
+            .map(|amount| {
+                harmonic_content(&render(
+                    &mut create_oscillator(shape, Q15::from_num(*amount)),
+                    40,
+                ))
+            })
+            .collect();
+
+        assert!(
+            contents.windows(2).all(|w| w[0] < w[1]),
+            "{:?}: harmonics should grow with the warp: {:?}",
+            shape,
+            contents
+        );
+    }
+}
+
+#[test]
+fn test_saw_has_every_harmonic_and_square_only_odd_ones() {
+    let saw = render(
+        &mut create_oscillator(PhaseDistortionShape::Saw, Q15::from_num(0.9)),
+        40,
+    );
+    let square = render(
+        &mut create_oscillator(PhaseDistortionShape::Square, Q15::from_num(0.9)),
+        40,
+    );
+
+    for harmonic in 2..6 {
+        let saw_magnitude = harmonic_magnitude(&saw, harmonic);
+        assert!(
+            saw_magnitude > 0.02,
+            "The saw should have harmonic {}, got {}",
+            harmonic,
+            saw_magnitude
+        );
+
+        let square_magnitude = harmonic_magnitude(&square, harmonic);
+        if harmonic % 2 == 0 {
+            assert!(
+                square_magnitude < 0.001,
+                "The square shouldn't have harmonic {}, got {}",
+                harmonic,
+                square_magnitude
+            );
+        } else {
+            assert!(
+                square_magnitude > 0.02,
+                "The square should have harmonic {}, got {}",
+                harmonic,
+                square_magnitude
+            );
+        }
+    }
+}
+
+#[test]
+fn test_resonance_moves_up_with_warp() {
+    let loudest_harmonic = |amount: f64| {
+        let samples = render(
+            &mut create_oscillator(PhaseDistortionShape::Resonant, Q15::from_num(amount)),
+            40,
+        );
+        (1..20)
+            .max_by(|a, b| {
+                harmonic_magnitude(&samples, *a).total_cmp(&harmonic_magnitude(&samples, *b))
+            })
+            .unwrap()
+    };
+
+    let low = loudest_harmonic(0.2);
+    let high = loudest_harmonic(0.8);
+    assert!(
+        high > low + 4,
+        "Expected the resonance to go up, got harmonic {} and then {}",
+        low,
+        high
+    );
+}
+
+#[test]
+fn test_warp_follows_its_envelope() {
+    // No sustain, so the warp decays away
+    let mut osc = PhaseDistortionOscillator::new(FAST_ATTACK, 0, DECAY_RELEASE);
+    osc.set_config(&PhaseDistortionConfig {
+        shape: PhaseDistortionShape::Saw,
+        amount: Q15::MAX,
+        decay_release: 50,
+    });
+    osc.set_note(&A3, &Tuning::default());
+
+    let start = harmonic_content(&render(&mut osc, 8));
+    render(&mut osc, 400);
+    let end = harmonic_content(&render(&mut osc, 40));
+
+    assert!(
+        end < start / 10.,
+        "The warp should decay: {} at the start, {} at the end",
+        start,
+        end
+    );
+    assert!(end < 1e-3, "Should be back to a sine, got {}", end);
+
+    // Playing a note starts the envelope over
+    osc.set_note(&A3, &Tuning::default());
+    let restarted = harmonic_content(&render(&mut osc, 8));
+    assert!((restarted - start).abs() < start / 10.);
+}
+
+#[test]
+fn test_phase_distortion_has_no_dc() {
+    for shape in [
+        PhaseDistortionShape::Saw,
+        PhaseDistortionShape::Square,
+        PhaseDistortionShape::Resonant,
+    ] {
+        for amount in [0.3, 0.9] {
+            let samples = render(&mut create_oscillator(shape, Q15::from_num(amount)), 40);
+            // Harmonic 0 counts the magnitude twice
+            let mean = harmonic_magnitude(&samples, 0) / 2.;
+
+            assert!(
+                mean.abs() < 0.01,
+                "{:?} at {}: mean of {}",
+                shape,
+                amount,
+                mean
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use cmsis_interface::Q15;
+
+/// Seed used when asked for a seed of zero, which xorshift cannot escape from
+const FALLBACK_SEED: u32 = 0x2545_F491;
+
+/// Small xorshift32 pseudo random number generator.
+///
+/// Not suitable for anything but audio, but it's cheap and fully deterministic
+/// for a given seed, so renders and tests are reproducible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rng {
+    state: u32,
+}
+
+impl Rng {
+    pub const fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 { FALLBACK_SEED } else { seed },
+        }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Uniformly distributed over the whole Q15 range.
+    /// Uses the upper bits, which are the better ones in xorshift.
+    pub fn next_q15(&mut self) -> Q15 {
+        Q15::from_bits((self.next_u32() >> 16) as i16)
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(FALLBACK_SEED)
+    }
+}
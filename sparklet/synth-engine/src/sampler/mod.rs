@@ -0,0 +1,246 @@
+use cmsis_interface::{CmsisOperations, Q15};
+use defmt::Format;
+use fixed::types::U32F32;
+
+use crate::{Note, tuning::Tuning, wavetable::phase_increment_table::MIDI_TO_PHASE_INCREMENT};
+
+#[cfg(test)]
+mod tests;
+
+/// What happens when playback reaches the end of the loop
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Plays until the end of the data once, then goes silent
+    OneShot,
+    /// Jumps back to the loop start for as long as the note plays
+    Loop,
+}
+
+/// A recording, played at its original speed on `root_note`.
+///
+/// The data is expected to be recorded at the sample rate of the engine.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample<'a> {
+    data: &'a [Q15],
+    root_note: Note,
+    start: u32,
+    loop_start: u32,
+    loop_end: u32,
+    loop_mode: LoopMode,
+}
+
+impl<'a> Format for Sample<'a> {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "Sample {{ length: {}, root_note: {}, start: {}, loop: {}..{} ({}) }}",
+            self.data.len(),
+            self.root_note,
+            self.start,
+            self.loop_start,
+            self.loop_end,
+            self.loop_mode
+        )
+    }
+}
+
+impl<'a> Sample<'a> {
+    /// Playback begins at `start`. The loop goes from `loop_start` up to, but not
+    /// including, `loop_end`, and is ignored by [`LoopMode::OneShot`].
+    ///
+    /// The points are clamped to the data, and the loop is at least one sample long.
+    pub fn new(
+        data: &'a [Q15],
+        root_note: Note,
+        start: u32,
+        loop_start: u32,
+        loop_end: u32,
+        loop_mode: LoopMode,
+    ) -> Self {
+        let length = data.len() as u32;
+        let loop_end = loop_end.clamp(1, length.max(1));
+
+        Self {
+            data,
+            root_note,
+            start: start.min(length),
+            loop_start: loop_start.min(loop_end - 1),
+            loop_end,
+            loop_mode,
+        }
+    }
+
+    /// Plays all of `data` once
+    pub fn one_shot(data: &'a [Q15], root_note: Note) -> Self {
+        Self::new(data, root_note, 0, 0, data.len() as u32, LoopMode::OneShot)
+    }
+
+    /// Plays all of `data`, looping all of it
+    pub fn looped(data: &'a [Q15], root_note: Note) -> Self {
+        Self::new(data, root_note, 0, 0, data.len() as u32, LoopMode::Loop)
+    }
+
+    /// The sample after `index`, going back to the loop start if it's looped
+    fn next_index(&self, index: u32) -> Option<u32> {
+        match self.loop_mode {
+            LoopMode::Loop if index + 1 == self.loop_end => Some(self.loop_start),
+            _ if index + 1 < self.data.len() as u32 => Some(index + 1),
+            _ => None,
+        }
+    }
+}
+
+/// Plays a [`Sample`] at a rate that depends on the note
+#[derive(Debug, Clone, Copy)]
+pub struct SampleOscillator<'a> {
+    sample: Option<Sample<'a>>,
+    /// Index into the data, with the fraction used to interpolate
+    position: U32F32,
+    /// How much the position moves every output sample
+    increment: U32F32,
+    /// A one shot sample stops playing at the end of its data
+    playing: bool,
+}
+
+impl<'a> Format for SampleOscillator<'a> {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "SampleOscillator {{ sample: {}, playing: {} }}",
+            self.sample,
+            self.playing
+        )
+    }
+}
+
+impl<'a> Default for SampleOscillator<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> SampleOscillator<'a> {
+    pub fn new() -> Self {
+        Self {
+            sample: None,
+            position: U32F32::ZERO,
+            increment: U32F32::ONE,
+            playing: false,
+        }
+    }
+
+    /// Takes effect on the next [`Self::set_note`] or [`Self::restart`]
+    pub fn set_sample(&mut self, sample: Option<Sample<'a>>) {
+        self.sample = sample;
+        self.playing = false;
+    }
+
+    pub fn has_sample(&self) -> bool {
+        self.sample.is_some()
+    }
+
+    /// Whether a one shot sample has played all of its data
+    pub fn is_finished(&self) -> bool {
+        !self.playing
+    }
+
+    pub fn set_note(&mut self, note: &Note, tuning: &Tuning) {
+        self.restart();
+        self.retune(note, tuning);
+    }
+
+    /// Plays the sample again from its start
+    pub fn restart(&mut self) {
+        if let Some(sample) = self.sample {
+            self.position = U32F32::from_num(sample.start);
+            self.playing = sample.start < sample.data.len() as u32;
+        }
+    }
+
+    /// Updates the playback rate of the current note without moving the position.
+    ///
+    /// The rate is the pitch of `note` in `tuning` relative to the standard pitch of
+    /// the root note, so a custom tuning moves the sample like any other oscillator.
+    pub fn retune(&mut self, note: &Note, tuning: &Tuning) {
+        let Some(sample) = self.sample else {
+            return;
+        };
+
+        let note_increment = tuning.phase_increment(note).to_bits() as u64;
+        let root_increment =
+            MIDI_TO_PHASE_INCREMENT[sample.root_note.as_u8() as usize & 0x7F].to_bits() as u64;
+
+        self.increment = (note_increment << 32)
+            .checked_div(root_increment)
+            .map_or(U32F32::ONE, U32F32::from_bits);
+    }
+
+    pub fn get_samples<T: CmsisOperations, const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        let Some(sample) = self.sample else {
+            buffer.fill(Q15::ZERO);
+            return;
+        };
+
+        /*
+         * Same as the wavetables, the samples are collected first and linearly
+         * interpolated with SIMD afterwards. Past the end of a one shot sample
+         * both readings are 0.
+         */
+        let mut sample_current = [Q15::ZERO; LEN];
+        let mut sample_next = [Q15::ZERO; LEN];
+        let mut weight_current = [Q15::ZERO; LEN];
+        let mut weight_next = [Q15::ZERO; LEN];
+
+        let loop_length = U32F32::from_num(sample.loop_end - sample.loop_start);
+        let loop_end = U32F32::from_num(sample.loop_end);
+        let data_end = U32F32::from_num(sample.data.len() as u32);
+
+        for ((s_current, s_next), w_next) in sample_current
+            .iter_mut()
+            .zip(sample_next.iter_mut())
+            .zip(weight_next.iter_mut())
+        {
+            if !self.playing {
+                break;
+            }
+
+            let index: u32 = self.position.to_num();
+            *s_current = sample.data[index as usize];
+            *s_next = sample
+                .next_index(index)
+                .map_or(Q15::ZERO, |next| sample.data[next as usize]);
+
+            // The first 15 bits of the fraction, like the wavetable phase
+            let fraction = self.position.to_bits() as u32;
+            *w_next = Q15::from_bits((fraction >> 17) as i16);
+
+            self.position = self.position.saturating_add(self.increment);
+
+            match sample.loop_mode {
+                LoopMode::Loop => {
+                    while self.position >= loop_end {
+                        self.position -= loop_length;
+                    }
+                }
+                LoopMode::OneShot => {
+                    if self.position >= data_end {
+                        self.playing = false;
+                    }
+                }
+            }
+        }
+
+        // output = s_current * (MAX - w_next) + s_next * w_next
+        T::negate_q15(&weight_next, buffer);
+        T::add_q15(buffer, &[Q15::MAX; LEN], &mut weight_current);
+
+        T::multiply_q15(
+            &sample_current.clone(),
+            &weight_current,
+            &mut sample_current,
+        );
+        T::multiply_q15(&sample_next.clone(), &weight_next, &mut sample_next);
+
+        T::add_q15(&sample_current, &sample_next, buffer);
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+
+type TestOps = cmsis_rust::CmsisRustOperations;
+
+const WINDOW_SIZE: usize = 128;
+const ROOT: Note = Note::new(60);
+
+/// A ramp that's easy to recognise, going up by 1/1024 every sample
+fn ramp(length: usize) -> Vec<Q15> {
+    (0..length)
+        .map(|i| Q15::from_bits((i as i16) << 5))
+        .collect()
+}
+
+fn render(osc: &mut SampleOscillator, windows: usize) -> Vec<Q15> {
+    let mut samples = Vec::with_capacity(windows * WINDOW_SIZE);
+    for _ in 0..windows {
+        let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+        osc.get_samples::<TestOps, WINDOW_SIZE>(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+    samples
+}
+
+fn play(sample: Sample, note: u8) -> SampleOscillator {
+    let mut osc = SampleOscillator::new();
+    osc.set_sample(Some(sample));
+    osc.set_note(&Note::new(note), &Tuning::default());
+    osc
+}
+
+/// Interpolating with a weight of `Q15::MAX` instead of 1 can be off by a bit
+fn assert_close(actual: &[Q15], expected: &[Q15]) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (actual, expected)) in actual.iter().zip(expected.iter()).enumerate() {
+        assert!(
+            (*actual - *expected).abs() <= Q15::from_bits(1),
+            "Sample {}: expected {}, got {}",
+            i,
+            expected,
+            actual
+        );
+    }
+}
+
+#[test]
+fn test_root_note_plays_at_original_speed() {
+    let data = ramp(512);
+    let mut osc = play(Sample::one_shot(&data, ROOT), 60);
+
+    assert_close(&render(&mut osc, 4), &data);
+}
+
+#[test]
+fn test_octave_up_plays_twice_as_fast() {
+    let data = ramp(512);
+    let mut osc = play(Sample::one_shot(&data, ROOT), 72);
+
+    let expected: Vec<Q15> = data.iter().step_by(2).copied().collect();
+    assert_close(&render(&mut osc, 2), &expected);
+}
+
+#[test]
+fn test_octave_down_interpolates() {
+    let data = ramp(512);
+    let mut osc = play(Sample::one_shot(&data, ROOT), 48);
+
+    let samples = render(&mut osc, 1);
+    for i in 0..WINDOW_SIZE / 2 {
+        assert_close(&samples[2 * i..2 * i + 1], &data[i..i + 1]);
+        // Halfway between two samples of the ramp
+        let halfway = Q15::from_bits(((i as i16) << 5) + 16);
+        assert_close(&samples[2 * i + 1..2 * i + 2], &[halfway]);
+    }
+}
+
+#[test]
+fn test_one_shot_ends_in_silence() {
+    let data = ramp(200);
+    let mut osc = play(Sample::one_shot(&data, ROOT), 60);
+
+    let samples = render(&mut osc, 2);
+    assert!(osc.is_finished());
+    assert_close(&samples[..200], &data);
+    assert!(samples[200..].iter().all(|&s| s == Q15::ZERO));
+
+    // Until it's played again
+    osc.restart();
+    assert!(!osc.is_finished());
+    assert_close(&render(&mut osc, 1), &data[..WINDOW_SIZE]);
+}
+
+#[test]
+fn test_loop_points() {
+    let data = ramp(300);
+    let sample = Sample::new(&data, ROOT, 10, 100, 200, LoopMode::Loop);
+    let mut osc = play(sample, 60);
+
+    let samples = render(&mut osc, 4);
+    assert!(!osc.is_finished());
+
+    // Plays from the start up to the loop end, and then the loop over and over
+    let mut expected: Vec<Q15> = data[10..200].to_vec();
+    while expected.len() < samples.len() {
+        expected.extend_from_slice(&data[100..200]);
+    }
+    expected.truncate(samples.len());
+
+    assert_close(&samples, &expected);
+}
+
+#[test]
+fn test_loop_faster_than_loop_length() {
+    let data = ramp(64);
+    let sample = Sample::new(&data, ROOT, 0, 60, 62, LoopMode::Loop);
+    // Four octaves up skips more than the whole loop every sample
+    let mut osc = play(sample, 108);
+
+    let samples = render(&mut osc, 2);
+    assert!(
+        samples[WINDOW_SIZE..]
+            .iter()
+            .all(|&s| s >= data[60] - Q15::from_bits(1) && s <= data[61]),
+        "Should stay inside the loop"
+    );
+}
+
+#[test]
+fn test_points_are_clamped() {
+    let data = ramp(100);
+    let sample = Sample::new(&data, ROOT, 500, 400, 300, LoopMode::Loop);
+
+    assert_eq!(sample.start, 100);
+    assert_eq!(sample.loop_end, 100);
+    assert_eq!(sample.loop_start, 99);
+
+    // Starting past the end of a one shot sample plays nothing
+    let mut osc = play(Sample::new(&data, ROOT, 500, 0, 100, LoopMode::OneShot), 60);
+    assert!(osc.is_finished());
+    assert!(render(&mut osc, 1).iter().all(|&s| s == Q15::ZERO));
+}
+
+#[test]
+fn test_no_sample_is_silent() {
+    let mut osc = SampleOscillator::new();
+    osc.set_note(&ROOT, &Tuning::default());
+
+    assert!(!osc.has_sample());
+    assert!(render(&mut osc, 1).iter().all(|&s| s == Q15::ZERO));
+}
@@ -0,0 +1,180 @@
+use cmsis_interface::Q15;
+use defmt::Format;
+
+pub mod saturation_table;
+
+#[cfg(test)]
+mod tests;
+
+/// Transfer curves go from an input of -1.0 to 1.0 in 256 steps
+pub const SHAPER_TABLE_SIZE: usize = 257;
+
+/// A transfer curve, where the first entry is the output for -1.0 and the last one for 1.0
+pub type ShaperTable = [Q15; SHAPER_TABLE_SIZE];
+
+/// 1.0 in the Q15 samples, widened so it can be gone past
+const ONE: i32 = 1 << 15;
+
+/// Gain at full drive
+const MAX_DRIVE: i32 = 16;
+
+/// Every stage of the wavefolder folds the signal back from one of the rails, and a
+/// full drive and symmetry can take it this many times past them
+const FOLD_STAGES: usize = (MAX_DRIVE as usize).div_ceil(2) + 1;
+
+/// The DC blocker loses 2^-DC_BLOCKER_SHIFT of its state every sample, ~7.5 Hz at 48 kHz
+const DC_BLOCKER_SHIFT: u32 = 10;
+/// Extra bits the DC blocker keeps below the Q15 samples, so it doesn't drift
+const DC_BLOCKER_PRECISION: u32 = 8;
+
+/// What the shaper does to the driven signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaperMode {
+    /// Reads the signal through a transfer curve, clamped to its ends
+    Table(&'static ShaperTable),
+    /// Folds the signal back every time it goes past the rails
+    Fold,
+}
+
+/// Settings of the shaper, as set from the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShaperConfig {
+    pub mode: ShaperMode,
+    /// Gain before shaping, from 1 at zero up to MAX_DRIVE
+    pub drive: Q15,
+    /// Offset added before shaping, where a full scale offset is half of full scale.
+    /// Anything but zero bends both halves differently and adds even harmonics.
+    pub symmetry: Q15,
+}
+
+impl Default for ShaperConfig {
+    fn default() -> Self {
+        Self {
+            mode: ShaperMode::Fold,
+            drive: Q15::ZERO,
+            symmetry: Q15::ZERO,
+        }
+    }
+}
+
+/// Nonlinear stage between the oscillators and the envelope of a voice
+#[derive(Debug, Clone, Copy)]
+pub struct Shaper {
+    mode: ShaperMode,
+    /// 4.12 fixed point
+    gain: i32,
+    bias: i32,
+    /// Previous input of the DC blocker
+    previous: i32,
+    /// Output of the DC blocker, with DC_BLOCKER_PRECISION extra bits
+    accumulator: i32,
+}
+
+impl Format for Shaper {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "Shaper {{ fold: {}, gain: {}, bias: {} }}",
+            self.mode == ShaperMode::Fold,
+            self.gain,
+            self.bias
+        )
+    }
+}
+
+impl Shaper {
+    pub fn new(config: &ShaperConfig) -> Self {
+        let mut shaper = Self {
+            mode: config.mode,
+            gain: 1 << 12,
+            bias: 0,
+            previous: 0,
+            accumulator: 0,
+        };
+        shaper.set_config(config);
+        shaper
+    }
+
+    pub fn set_config(&mut self, config: &ShaperConfig) {
+        self.mode = config.mode;
+        self.gain = (1 << 12) + (((MAX_DRIVE - 1) * config.drive.to_bits().max(0) as i32) >> 3);
+        self.bias = config.symmetry.to_bits() as i32 / 2;
+    }
+
+    /// Forgets the DC blocker state, for a new note
+    pub fn reset(&mut self) {
+        self.previous = 0;
+        self.accumulator = 0;
+    }
+
+    pub fn process<const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        for sample in buffer.iter_mut() {
+            let driven = ((sample.to_bits() as i32 * self.gain) >> 12) + self.bias;
+
+            let shaped = match self.mode {
+                ShaperMode::Table(table) => Self::read_table(table, driven),
+                ShaperMode::Fold => Self::fold(driven),
+            };
+
+            /*
+             * A symmetric curve on a signal without DC doesn't add any, so the
+             * DC blocker is only needed when there's an offset
+             */
+            let output = if self.bias == 0 {
+                shaped
+            } else {
+                self.block_dc(shaped)
+            };
+
+            *sample = Q15::from_bits(output.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+        }
+    }
+
+    /// Linear interpolation between the two entries around `input`
+    fn read_table(table: &ShaperTable, input: i32) -> i32 {
+        // 0 to 2^16, where every entry is 2^8 apart
+        let position = (input.clamp(-ONE, ONE) + ONE) as usize;
+        let index = position >> 8;
+        let fraction = (position & 0xFF) as i32;
+
+        let current = table[index].to_bits() as i32;
+        if index == SHAPER_TABLE_SIZE - 1 {
+            return current;
+        }
+        let next = table[index + 1].to_bits() as i32;
+
+        current + (((next - current) * fraction) >> 8)
+    }
+
+    /// Reflects the signal back from whichever rail it went past, one stage at a time
+    fn fold(input: i32) -> i32 {
+        let mut folded = input;
+
+        for _ in 0..FOLD_STAGES {
+            if folded > ONE {
+                folded = 2 * ONE - folded;
+            } else if folded < -ONE {
+                folded = -2 * ONE - folded;
+            } else {
+                break;
+            }
+        }
+
+        folded
+    }
+
+    /// One pole highpass, y[n] = x[n] - x[n - 1] + (1 - 2^-DC_BLOCKER_SHIFT) y[n - 1]
+    fn block_dc(&mut self, input: i32) -> i32 {
+        let difference = (input - self.previous) << DC_BLOCKER_PRECISION;
+        self.previous = input;
+        self.accumulator += difference - (self.accumulator >> DC_BLOCKER_SHIFT);
+
+        (self.accumulator + (1 << (DC_BLOCKER_PRECISION - 1))) >> DC_BLOCKER_PRECISION
+    }
+}
+
+impl Default for Shaper {
+    fn default() -> Self {
+        Self::new(&ShaperConfig::default())
+    }
+}
@@ -0,0 +1,261 @@
+use cmsis_interface::Q15;
+
+pub static SATURATION_TABLE: [Q15; 257] = [
+    Q15::from_bits(0x8000_u16 as i16),
+    Q15::from_bits(0x8008_u16 as i16),
+    Q15::from_bits(0x8010_u16 as i16),
+    Q15::from_bits(0x8019_u16 as i16),
+    Q15::from_bits(0x8021_u16 as i16),
+    Q15::from_bits(0x802b_u16 as i16),
+    Q15::from_bits(0x8035_u16 as i16),
+    Q15::from_bits(0x803f_u16 as i16),
+    Q15::from_bits(0x804a_u16 as i16),
+    Q15::from_bits(0x8055_u16 as i16),
+    Q15::from_bits(0x8061_u16 as i16),
+    Q15::from_bits(0x806d_u16 as i16),
+    Q15::from_bits(0x807a_u16 as i16),
+    Q15::from_bits(0x8088_u16 as i16),
+    Q15::from_bits(0x8096_u16 as i16),
+    Q15::from_bits(0x80a5_u16 as i16),
+    Q15::from_bits(0x80b5_u16 as i16),
+    Q15::from_bits(0x80c5_u16 as i16),
+    Q15::from_bits(0x80d7_u16 as i16),
+    Q15::from_bits(0x80e9_u16 as i16),
+    Q15::from_bits(0x80fb_u16 as i16),
+    Q15::from_bits(0x810f_u16 as i16),
+    Q15::from_bits(0x8124_u16 as i16),
+    Q15::from_bits(0x813a_u16 as i16),
+    Q15::from_bits(0x8150_u16 as i16),
+    Q15::from_bits(0x8168_u16 as i16),
+    Q15::from_bits(0x8181_u16 as i16),
+    Q15::from_bits(0x819b_u16 as i16),
+    Q15::from_bits(0x81b6_u16 as i16),
+    Q15::from_bits(0x81d3_u16 as i16),
+    Q15::from_bits(0x81f1_u16 as i16),
+    Q15::from_bits(0x8210_u16 as i16),
+    Q15::from_bits(0x8231_u16 as i16),
+    Q15::from_bits(0x8253_u16 as i16),
+    Q15::from_bits(0x8277_u16 as i16),
+    Q15::from_bits(0x829d_u16 as i16),
+    Q15::from_bits(0x82c4_u16 as i16),
+    Q15::from_bits(0x82ed_u16 as i16),
+    Q15::from_bits(0x8318_u16 as i16),
+    Q15::from_bits(0x8346_u16 as i16),
+    Q15::from_bits(0x8375_u16 as i16),
+    Q15::from_bits(0x83a6_u16 as i16),
+    Q15::from_bits(0x83da_u16 as i16),
+    Q15::from_bits(0x8410_u16 as i16),
+    Q15::from_bits(0x8449_u16 as i16),
+    Q15::from_bits(0x8484_u16 as i16),
+    Q15::from_bits(0x84c2_u16 as i16),
+    Q15::from_bits(0x8503_u16 as i16),
+    Q15::from_bits(0x8546_u16 as i16),
+    Q15::from_bits(0x858d_u16 as i16),
+    Q15::from_bits(0x85d7_u16 as i16),
+    Q15::from_bits(0x8625_u16 as i16),
+    Q15::from_bits(0x8676_u16 as i16),
+    Q15::from_bits(0x86cb_u16 as i16),
+    Q15::from_bits(0x8723_u16 as i16),
+    Q15::from_bits(0x8780_u16 as i16),
+    Q15::from_bits(0x87e0_u16 as i16),
+    Q15::from_bits(0x8845_u16 as i16),
+    Q15::from_bits(0x88af_u16 as i16),
+    Q15::from_bits(0x891d_u16 as i16),
+    Q15::from_bits(0x8990_u16 as i16),
+    Q15::from_bits(0x8a08_u16 as i16),
+    Q15::from_bits(0x8a85_u16 as i16),
+    Q15::from_bits(0x8b08_u16 as i16),
+    Q15::from_bits(0x8b91_u16 as i16),
+    Q15::from_bits(0x8c1f_u16 as i16),
+    Q15::from_bits(0x8cb4_u16 as i16),
+    Q15::from_bits(0x8d4f_u16 as i16),
+    Q15::from_bits(0x8df0_u16 as i16),
+    Q15::from_bits(0x8e99_u16 as i16),
+    Q15::from_bits(0x8f48_u16 as i16),
+    Q15::from_bits(0x8fff_u16 as i16),
+    Q15::from_bits(0x90be_u16 as i16),
+    Q15::from_bits(0x9184_u16 as i16),
+    Q15::from_bits(0x9253_u16 as i16),
+    Q15::from_bits(0x932a_u16 as i16),
+    Q15::from_bits(0x940a_u16 as i16),
+    Q15::from_bits(0x94f2_u16 as i16),
+    Q15::from_bits(0x95e4_u16 as i16),
+    Q15::from_bits(0x96e0_u16 as i16),
+    Q15::from_bits(0x97e5_u16 as i16),
+    Q15::from_bits(0x98f4_u16 as i16),
+    Q15::from_bits(0x9a0e_u16 as i16),
+    Q15::from_bits(0x9b33_u16 as i16),
+    Q15::from_bits(0x9c62_u16 as i16),
+    Q15::from_bits(0x9d9d_u16 as i16),
+    Q15::from_bits(0x9ee3_u16 as i16),
+    Q15::from_bits(0xa035_u16 as i16),
+    Q15::from_bits(0xa192_u16 as i16),
+    Q15::from_bits(0xa2fc_u16 as i16),
+    Q15::from_bits(0xa473_u16 as i16),
+    Q15::from_bits(0xa5f6_u16 as i16),
+    Q15::from_bits(0xa786_u16 as i16),
+    Q15::from_bits(0xa924_u16 as i16),
+    Q15::from_bits(0xaace_u16 as i16),
+    Q15::from_bits(0xac86_u16 as i16),
+    Q15::from_bits(0xae4c_u16 as i16),
+    Q15::from_bits(0xb01f_u16 as i16),
+    Q15::from_bits(0xb200_u16 as i16),
+    Q15::from_bits(0xb3ef_u16 as i16),
+    Q15::from_bits(0xb5ec_u16 as i16),
+    Q15::from_bits(0xb7f7_u16 as i16),
+    Q15::from_bits(0xba10_u16 as i16),
+    Q15::from_bits(0xbc37_u16 as i16),
+    Q15::from_bits(0xbe6b_u16 as i16),
+    Q15::from_bits(0xc0ad_u16 as i16),
+    Q15::from_bits(0xc2fc_u16 as i16),
+    Q15::from_bits(0xc559_u16 as i16),
+    Q15::from_bits(0xc7c3_u16 as i16),
+    Q15::from_bits(0xca3a_u16 as i16),
+    Q15::from_bits(0xccbd_u16 as i16),
+    Q15::from_bits(0xcf4c_u16 as i16),
+    Q15::from_bits(0xd1e7_u16 as i16),
+    Q15::from_bits(0xd48d_u16 as i16),
+    Q15::from_bits(0xd73e_u16 as i16),
+    Q15::from_bits(0xd9fa_u16 as i16),
+    Q15::from_bits(0xdcbf_u16 as i16),
+    Q15::from_bits(0xdf8d_u16 as i16),
+    Q15::from_bits(0xe264_u16 as i16),
+    Q15::from_bits(0xe543_u16 as i16),
+    Q15::from_bits(0xe829_u16 as i16),
+    Q15::from_bits(0xeb15_u16 as i16),
+    Q15::from_bits(0xee07_u16 as i16),
+    Q15::from_bits(0xf0fe_u16 as i16),
+    Q15::from_bits(0xf3fa_u16 as i16),
+    Q15::from_bits(0xf6f8_u16 as i16),
+    Q15::from_bits(0xf9f9_u16 as i16),
+    Q15::from_bits(0xfcfc_u16 as i16),
+    Q15::from_bits(0x0000_u16 as i16),
+    Q15::from_bits(0x0304_u16 as i16),
+    Q15::from_bits(0x0607_u16 as i16),
+    Q15::from_bits(0x0908_u16 as i16),
+    Q15::from_bits(0x0c06_u16 as i16),
+    Q15::from_bits(0x0f02_u16 as i16),
+    Q15::from_bits(0x11f9_u16 as i16),
+    Q15::from_bits(0x14eb_u16 as i16),
+    Q15::from_bits(0x17d7_u16 as i16),
+    Q15::from_bits(0x1abd_u16 as i16),
+    Q15::from_bits(0x1d9c_u16 as i16),
+    Q15::from_bits(0x2073_u16 as i16),
+    Q15::from_bits(0x2341_u16 as i16),
+    Q15::from_bits(0x2606_u16 as i16),
+    Q15::from_bits(0x28c2_u16 as i16),
+    Q15::from_bits(0x2b73_u16 as i16),
+    Q15::from_bits(0x2e19_u16 as i16),
+    Q15::from_bits(0x30b4_u16 as i16),
+    Q15::from_bits(0x3343_u16 as i16),
+    Q15::from_bits(0x35c6_u16 as i16),
+    Q15::from_bits(0x383d_u16 as i16),
+    Q15::from_bits(0x3aa7_u16 as i16),
+    Q15::from_bits(0x3d04_u16 as i16),
+    Q15::from_bits(0x3f53_u16 as i16),
+    Q15::from_bits(0x4195_u16 as i16),
+    Q15::from_bits(0x43c9_u16 as i16),
+    Q15::from_bits(0x45f0_u16 as i16),
+    Q15::from_bits(0x4809_u16 as i16),
+    Q15::from_bits(0x4a14_u16 as i16),
+    Q15::from_bits(0x4c11_u16 as i16),
+    Q15::from_bits(0x4e00_u16 as i16),
+    Q15::from_bits(0x4fe1_u16 as i16),
+    Q15::from_bits(0x51b4_u16 as i16),
+    Q15::from_bits(0x537a_u16 as i16),
+    Q15::from_bits(0x5532_u16 as i16),
+    Q15::from_bits(0x56dc_u16 as i16),
+    Q15::from_bits(0x587a_u16 as i16),
+    Q15::from_bits(0x5a0a_u16 as i16),
+    Q15::from_bits(0x5b8d_u16 as i16),
+    Q15::from_bits(0x5d04_u16 as i16),
+    Q15::from_bits(0x5e6e_u16 as i16),
+    Q15::from_bits(0x5fcb_u16 as i16),
+    Q15::from_bits(0x611d_u16 as i16),
+    Q15::from_bits(0x6263_u16 as i16),
+    Q15::from_bits(0x639e_u16 as i16),
+    Q15::from_bits(0x64cd_u16 as i16),
+    Q15::from_bits(0x65f2_u16 as i16),
+    Q15::from_bits(0x670c_u16 as i16),
+    Q15::from_bits(0x681b_u16 as i16),
+    Q15::from_bits(0x6920_u16 as i16),
+    Q15::from_bits(0x6a1c_u16 as i16),
+    Q15::from_bits(0x6b0e_u16 as i16),
+    Q15::from_bits(0x6bf6_u16 as i16),
+    Q15::from_bits(0x6cd6_u16 as i16),
+    Q15::from_bits(0x6dad_u16 as i16),
+    Q15::from_bits(0x6e7c_u16 as i16),
+    Q15::from_bits(0x6f42_u16 as i16),
+    Q15::from_bits(0x7001_u16 as i16),
+    Q15::from_bits(0x70b8_u16 as i16),
+    Q15::from_bits(0x7167_u16 as i16),
+    Q15::from_bits(0x7210_u16 as i16),
+    Q15::from_bits(0x72b1_u16 as i16),
+    Q15::from_bits(0x734c_u16 as i16),
+    Q15::from_bits(0x73e1_u16 as i16),
+    Q15::from_bits(0x746f_u16 as i16),
+    Q15::from_bits(0x74f8_u16 as i16),
+    Q15::from_bits(0x757b_u16 as i16),
+    Q15::from_bits(0x75f8_u16 as i16),
+    Q15::from_bits(0x7670_u16 as i16),
+    Q15::from_bits(0x76e3_u16 as i16),
+    Q15::from_bits(0x7751_u16 as i16),
+    Q15::from_bits(0x77bb_u16 as i16),
+    Q15::from_bits(0x7820_u16 as i16),
+    Q15::from_bits(0x7880_u16 as i16),
+    Q15::from_bits(0x78dd_u16 as i16),
+    Q15::from_bits(0x7935_u16 as i16),
+    Q15::from_bits(0x798a_u16 as i16),
+    Q15::from_bits(0x79db_u16 as i16),
+    Q15::from_bits(0x7a29_u16 as i16),
+    Q15::from_bits(0x7a73_u16 as i16),
+    Q15::from_bits(0x7aba_u16 as i16),
+    Q15::from_bits(0x7afd_u16 as i16),
+    Q15::from_bits(0x7b3e_u16 as i16),
+    Q15::from_bits(0x7b7c_u16 as i16),
+    Q15::from_bits(0x7bb7_u16 as i16),
+    Q15::from_bits(0x7bf0_u16 as i16),
+    Q15::from_bits(0x7c26_u16 as i16),
+    Q15::from_bits(0x7c5a_u16 as i16),
+    Q15::from_bits(0x7c8b_u16 as i16),
+    Q15::from_bits(0x7cba_u16 as i16),
+    Q15::from_bits(0x7ce8_u16 as i16),
+    Q15::from_bits(0x7d13_u16 as i16),
+    Q15::from_bits(0x7d3c_u16 as i16),
+    Q15::from_bits(0x7d63_u16 as i16),
+    Q15::from_bits(0x7d89_u16 as i16),
+    Q15::from_bits(0x7dad_u16 as i16),
+    Q15::from_bits(0x7dcf_u16 as i16),
+    Q15::from_bits(0x7df0_u16 as i16),
+    Q15::from_bits(0x7e0f_u16 as i16),
+    Q15::from_bits(0x7e2d_u16 as i16),
+    Q15::from_bits(0x7e4a_u16 as i16),
+    Q15::from_bits(0x7e65_u16 as i16),
+    Q15::from_bits(0x7e7f_u16 as i16),
+    Q15::from_bits(0x7e98_u16 as i16),
+    Q15::from_bits(0x7eb0_u16 as i16),
+    Q15::from_bits(0x7ec6_u16 as i16),
+    Q15::from_bits(0x7edc_u16 as i16),
+    Q15::from_bits(0x7ef1_u16 as i16),
+    Q15::from_bits(0x7f05_u16 as i16),
+    Q15::from_bits(0x7f17_u16 as i16),
+    Q15::from_bits(0x7f29_u16 as i16),
+    Q15::from_bits(0x7f3b_u16 as i16),
+    Q15::from_bits(0x7f4b_u16 as i16),
+    Q15::from_bits(0x7f5b_u16 as i16),
+    Q15::from_bits(0x7f6a_u16 as i16),
+    Q15::from_bits(0x7f78_u16 as i16),
+    Q15::from_bits(0x7f86_u16 as i16),
+    Q15::from_bits(0x7f93_u16 as i16),
+    Q15::from_bits(0x7f9f_u16 as i16),
+    Q15::from_bits(0x7fab_u16 as i16),
+    Q15::from_bits(0x7fb6_u16 as i16),
+    Q15::from_bits(0x7fc1_u16 as i16),
+    Q15::from_bits(0x7fcb_u16 as i16),
+    Q15::from_bits(0x7fd5_u16 as i16),
+    Q15::from_bits(0x7fdf_u16 as i16),
+    Q15::from_bits(0x7fe7_u16 as i16),
+    Q15::from_bits(0x7ff0_u16 as i16),
+    Q15::from_bits(0x7ff8_u16 as i16),
+    Q15::from_bits(0x7fff_u16 as i16),
+];
@@ -0,0 +1,176 @@
+use super::saturation_table::SATURATION_TABLE;
+use super::*;
+
+const WINDOW_SIZE: usize = 128;
+/// Samples per cycle of the test sine, so every window holds two whole cycles
+const PERIOD: usize = 64;
+
+const MODES: [(&str, ShaperMode); 2] = [
+    ("Fold", ShaperMode::Fold),
+    ("Saturation", ShaperMode::Table(&SATURATION_TABLE)),
+];
+
+fn sine_window(amplitude: f64) -> [Q15; WINDOW_SIZE] {
+    core::array::from_fn(|n| {
+        let angle = 2.0 * core::f64::consts::PI * n as f64 / PERIOD as f64;
+        Q15::from_num(amplitude * angle.sin())
+    })
+}
+
+fn shaper(mode: ShaperMode, drive: f64, symmetry: f64) -> Shaper {
+    Shaper::new(&ShaperConfig {
+        mode,
+        drive: Q15::from_num(drive),
+        symmetry: Q15::from_num(symmetry),
+    })
+}
+
+/// Shapes `windows` windows of a sine and keeps the last one
+fn shape_sine(shaper: &mut Shaper, amplitude: f64, windows: usize) -> [Q15; WINDOW_SIZE] {
+    let mut buffer = sine_window(amplitude);
+    for _ in 0..windows {
+        buffer = sine_window(amplitude);
+        shaper.process::<WINDOW_SIZE>(&mut buffer);
+    }
+    buffer
+}
+
+/// Magnitude of `harmonic` times the frequency of the test sine. The window holds
+/// whole cycles, so there's no leakage between harmonics.
+fn harmonic_magnitude(samples: &[Q15], harmonic: usize) -> f64 {
+    let (re, im) = samples
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(re, im), (n, sample)| {
+            let angle = 2.0 * core::f64::consts::PI * (harmonic * n) as f64 / PERIOD as f64;
+            let sample = sample.to_num::<f64>();
+            (re + sample * angle.cos(), im - sample * angle.sin())
+        });
+
+    2. * (re * re + im * im).sqrt() / samples.len() as f64
+}
+
+/// Energy of the harmonics above the fundamental, relative to the fundamental
+fn harmonic_content(samples: &[Q15]) -> f64 {
+    let fundamental = harmonic_magnitude(samples, 1);
+    (2..PERIOD / 2)
+        .map(|harmonic| harmonic_magnitude(samples, harmonic).powi(2))
+        .sum::<f64>()
+        / fundamental.powi(2)
+}
+
+#[test]
+fn test_fold_without_drive_leaves_the_signal_alone() {
+    let mut shaper = shaper(ShaperMode::Fold, 0., 0.);
+    assert_eq!(shape_sine(&mut shaper, 0.9, 1), sine_window(0.9));
+}
+
+#[test]
+fn test_fold_reflects_from_the_rails() {
+    assert_eq!(Shaper::fold(ONE / 2), ONE / 2);
+    assert_eq!(Shaper::fold(ONE + ONE / 4), ONE - ONE / 4);
+    assert_eq!(Shaper::fold(-ONE - ONE / 4), -ONE + ONE / 4);
+    // Past the opposite rail, so it's folded twice
+    assert_eq!(Shaper::fold(3 * ONE + ONE / 4), -ONE + ONE / 4);
+
+    // The loudest input, at full drive and symmetry, still ends up in range
+    let loudest = ((i16::MAX as i32 * ((1 << 12) + (((MAX_DRIVE - 1) * i16::MAX as i32) >> 3)))
+        >> 12)
+        + i16::MAX as i32 / 2;
+    assert!(Shaper::fold(loudest).abs() <= ONE);
+    assert!(Shaper::fold(-loudest).abs() <= ONE);
+}
+
+#[test]
+fn test_drive_adds_harmonics() {
+    for (name, mode) in MODES {
+        let contents: Vec<f64> = [0., 0.2, 0.5, 0.99]
+            .iter()
+            .map(|drive| harmonic_content(&shape_sine(&mut shaper(mode, *drive, 0.), 0.9, 1)))
+            .collect();
+
+        assert!(
+            contents.windows(2).all(|w| w[0] < w[1]),
+            "{}: harmonics should grow with the drive: {:?}",
+            name,
+            contents
+        );
+    }
+}
+
+#[test]
+fn test_symmetry_adds_even_harmonics() {
+    for (name, mode) in MODES {
+        let symmetric = shape_sine(&mut shaper(mode, 0.3, 0.), 0.9, 1);
+        let asymmetric = shape_sine(&mut shaper(mode, 0.3, 0.5), 0.9, 200);
+
+        let even_harmonics = |samples: &[Q15]| -> f64 {
+            (2..PERIOD / 2)
+                .step_by(2)
+                .map(|harmonic| harmonic_magnitude(samples, harmonic).powi(2))
+                .sum()
+        };
+
+        assert!(
+            even_harmonics(&symmetric) < 1e-6,
+            "{}: a symmetric curve shouldn't have even harmonics",
+            name
+        );
+        assert!(
+            even_harmonics(&asymmetric) > 1e-3,
+            "{}: an asymmetric curve should have even harmonics",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_symmetry_has_no_dc() {
+    for (name, mode) in MODES {
+        for symmetry in [-1., -0.3, 0.5, 0.99] {
+            let samples = shape_sine(&mut shaper(mode, 0.5, symmetry), 0.9, 200);
+            let mean = harmonic_magnitude(&samples, 0) / 2.;
+
+            // Clipping the peaks after taking the DC out leaves a little of it
+            assert!(
+                mean.abs() < 0.02,
+                "{} with symmetry {}: mean of {}",
+                name,
+                symmetry,
+                mean
+            );
+        }
+    }
+}
+
+#[test]
+fn test_table_is_clamped_to_its_ends() {
+    assert_eq!(
+        Shaper::read_table(&SATURATION_TABLE, 4 * ONE),
+        SATURATION_TABLE[SHAPER_TABLE_SIZE - 1].to_bits() as i32
+    );
+    assert_eq!(
+        Shaper::read_table(&SATURATION_TABLE, -4 * ONE),
+        SATURATION_TABLE[0].to_bits() as i32
+    );
+
+    // Halfway between two entries
+    let between = Shaper::read_table(&SATURATION_TABLE, 128);
+    let expected =
+        (SATURATION_TABLE[128].to_bits() as i32 + SATURATION_TABLE[129].to_bits() as i32) / 2;
+    assert!((between - expected).abs() <= 1);
+}
+
+#[test]
+fn test_saturation_compresses_without_folding() {
+    let mut previous = i32::MIN;
+    for input in (-2 * ONE..=2 * ONE).step_by(64) {
+        let output = Shaper::read_table(&SATURATION_TABLE, input);
+        assert!(output >= previous, "Saturation should never fold back");
+        previous = output;
+    }
+
+    // Quiet signals get louder and loud ones are held under full scale
+    assert!(Shaper::read_table(&SATURATION_TABLE, ONE / 8) > ONE / 8);
+    assert!(Shaper::read_table(&SATURATION_TABLE, 2 * ONE) < ONE);
+}
@@ -0,0 +1,239 @@
+use cmsis_interface::Q15;
+use defmt::Format;
+
+use crate::{Note, rng::Rng, tuning::Tuning};
+
+#[cfg(test)]
+mod tests;
+
+/// Longest delay the string can hold. At 48 kHz that's a period of ~47 Hz (about G1),
+/// lower notes play at that pitch instead.
+pub const DELAY_LINE_LENGTH: usize = 1024;
+
+/// Seed used when none is given
+const DEFAULT_SEED: u32 = 0x57A1_46ED;
+
+/// Brightness of the softest pluck, as the coefficient of the one-pole lowpass
+/// the noise burst goes through. The hardest pluck isn't filtered at all.
+const MIN_PLUCK_BRIGHTNESS: i32 = 1 << 11;
+
+/// How the string is set in motion
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringExcitation {
+    /// A burst of noise, darker the softer the note is played
+    Pluck,
+    /// A smooth hammer pulse, narrower the harder the note is played
+    Strike,
+}
+
+/// Settings of the string, as set from the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringConfig {
+    pub excitation: StringExcitation,
+    /// Feedback while the note is held
+    pub decay: Q15,
+    /// Feedback once the note is released
+    pub damping: Q15,
+}
+
+impl StringConfig {
+    /// Maps an encoder to how much of the signal survives a trip around the string.
+    ///
+    /// The loss goes with the square of the distance to 255, so the long decays at
+    /// the top of the range have more resolution. 255 never decays on its own.
+    pub fn feedback_for_encoder(encoder: u8) -> Q15 {
+        let distance = (u8::MAX - encoder) as i32;
+        Q15::from_bits((i16::MAX as i32 - ((distance * distance) >> 4)) as i16)
+    }
+}
+
+impl Default for StringConfig {
+    fn default() -> Self {
+        Self {
+            excitation: StringExcitation::Pluck,
+            decay: Self::feedback_for_encoder(u8::MAX),
+            damping: Self::feedback_for_encoder(0),
+        }
+    }
+}
+
+/// Karplus-Strong string model.
+///
+/// A delay line one period long holds the shape of the string, and every sample goes
+/// back into it through a two point average, which loses the high harmonics first
+/// like a real string does.
+#[derive(Debug, Clone, Copy)]
+pub struct StringVoice {
+    delay_line: [Q15; DELAY_LINE_LENGTH],
+    write_index: usize,
+    /// Delay of the read tap, as a 16.16 fixed point number of samples
+    delay: u32,
+    /// Previous read, for the averaging filter
+    previous: i32,
+    config: StringConfig,
+    held: bool,
+    rng: Rng,
+}
+
+impl Format for StringVoice {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "StringVoice {{ excitation: {}, held: {} }}",
+            self.config.excitation,
+            self.held
+        )
+    }
+}
+
+impl StringVoice {
+    pub fn new(seed: u32) -> Self {
+        Self {
+            delay_line: [Q15::ZERO; DELAY_LINE_LENGTH],
+            write_index: 0,
+            delay: 1 << 16,
+            previous: 0,
+            config: StringConfig::default(),
+            held: false,
+            rng: Rng::new(seed),
+        }
+    }
+
+    pub fn set_config(&mut self, config: &StringConfig) {
+        self.config = *config;
+    }
+
+    /// Sets the length of the string for `note` without touching what's on it
+    pub fn retune(&mut self, note: &Note, tuning: &Tuning) {
+        let phase_increment = tuning.phase_increment(note).to_bits() as u64;
+
+        /*
+         * A phase increment of 256 is a cycle per sample, so the period is 256 / increment
+         * samples, which in 16.16 is 2^48 / bits. The averaging filter delays by half a
+         * sample, so the read tap goes half a sample earlier.
+         */
+        let period = (1u64 << 48) / phase_increment.max(1);
+        let max_delay = ((DELAY_LINE_LENGTH as u64 - 2) << 16) + (1 << 15);
+        let delay = period.saturating_sub(1 << 15).clamp(1 << 16, max_delay);
+
+        self.delay = delay as u32;
+    }
+
+    /// Tunes the string to `note` and sets it in motion
+    pub fn play(&mut self, note: &Note, tuning: &Tuning, velocity: u8) {
+        self.retune(note, tuning);
+        self.excite(velocity);
+    }
+
+    /// Sets the string in motion again, replacing whatever it was doing
+    pub fn excite(&mut self, velocity: u8) {
+        let velocity = velocity.min(127) as i32;
+
+        self.delay_line = [Q15::ZERO; DELAY_LINE_LENGTH];
+        self.write_index = 0;
+        self.previous = 0;
+        self.held = true;
+
+        // The first period is read from the end of the delay line
+        let length = (self.delay >> 16) as usize + 2;
+        let burst = &mut self.delay_line[DELAY_LINE_LENGTH - length..];
+
+        match self.config.excitation {
+            StringExcitation::Pluck => {
+                let brightness = MIN_PLUCK_BRIGHTNESS
+                    + ((i16::MAX as i32 - MIN_PLUCK_BRIGHTNESS) * velocity) / 127;
+                let mut lowpass = 0;
+
+                for sample in burst.iter_mut() {
+                    let noise = self.rng.next_q15().to_bits() as i32;
+                    lowpass += ((noise - lowpass) * brightness) >> 15;
+                    *sample = Q15::from_bits(lowpass as i16);
+                }
+            }
+            StringExcitation::Strike => {
+                // From half the string for the softest note down to a tenth for the hardest
+                let width = ((length * (160 - velocity as usize)) / 320).max(2);
+                let half_width = width as i32 / 2;
+
+                for (index, sample) in burst.iter_mut().take(width).enumerate() {
+                    let distance = (index as i32 - half_width).abs();
+                    let height = ((half_width - distance) * i16::MAX as i32) / half_width.max(1);
+                    *sample = Q15::from_bits(height as i16);
+                }
+            }
+        }
+
+        Self::normalize(burst);
+    }
+
+    /// Removes the DC of the excitation, which would otherwise stay on the string
+    /// forever, and brings its peak to full scale
+    fn normalize(burst: &mut [Q15]) {
+        let sum: i32 = burst.iter().map(|s| s.to_bits() as i32).sum();
+        let mean = sum / burst.len() as i32;
+
+        let peak = burst
+            .iter()
+            .map(|s| (s.to_bits() as i32 - mean).abs())
+            .max()
+            .unwrap_or(0);
+
+        if peak == 0 {
+            return;
+        }
+
+        for sample in burst.iter_mut() {
+            let centered = sample.to_bits() as i32 - mean;
+            let scaled = (centered * i16::MAX as i32) / peak;
+            *sample = Q15::from_bits(scaled.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+        }
+    }
+
+    /// Switches the feedback to the damping
+    pub fn stop_playing(&mut self) {
+        self.held = false;
+    }
+
+    pub fn get_samples<const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        let feedback = if self.held {
+            self.config.decay
+        } else {
+            self.config.damping
+        }
+        .to_bits() as i32;
+
+        let whole = (self.delay >> 16) as usize;
+        let fraction = ((self.delay & 0xFFFF) >> 1) as i32;
+
+        for sample in buffer.iter_mut() {
+            // Linear interpolation between the two samples around the read tap
+            let newer = self.delay_line
+                [(self.write_index + DELAY_LINE_LENGTH - whole) % DELAY_LINE_LENGTH]
+                .to_bits() as i32;
+            let older = self.delay_line
+                [(self.write_index + DELAY_LINE_LENGTH - whole - 1) % DELAY_LINE_LENGTH]
+                .to_bits() as i32;
+            let delayed = newer + (((older - newer) * fraction) >> 15);
+
+            /*
+             * (delayed + previous) / 2 * feedback, all in one rounded shift. Truncating
+             * would bias every trip around the string down by half a bit, which builds
+             * up into a large DC offset when the feedback is close to 1.
+             */
+            let filtered = ((delayed + self.previous) * feedback + (1 << 15)) >> 16;
+            self.previous = delayed;
+
+            let output = Q15::from_bits(filtered.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+            self.delay_line[self.write_index] = output;
+            self.write_index = (self.write_index + 1) % DELAY_LINE_LENGTH;
+
+            *sample = output;
+        }
+    }
+}
+
+impl Default for StringVoice {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
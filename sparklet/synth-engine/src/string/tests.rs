@@ -0,0 +1,227 @@
+use super::*;
+use crate::{SAMPLE_RATE, wavetable::phase_increment_table::MIDI_TO_PHASE_INCREMENT};
+
+const WINDOW_SIZE: usize = 128;
+const A3: Note = Note::new(57);
+
+fn config(excitation: StringExcitation, decay: u8, damping: u8) -> StringConfig {
+    StringConfig {
+        excitation,
+        decay: StringConfig::feedback_for_encoder(decay),
+        damping: StringConfig::feedback_for_encoder(damping),
+    }
+}
+
+fn play(config: StringConfig, note: Note, velocity: u8) -> StringVoice {
+    let mut voice = StringVoice::new(1);
+    voice.set_config(&config);
+    voice.play(&note, &Tuning::default(), velocity);
+    voice
+}
+
+fn render(voice: &mut StringVoice, windows: usize) -> Vec<Q15> {
+    let mut samples = Vec::with_capacity(windows * WINDOW_SIZE);
+    for _ in 0..windows {
+        let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+        voice.get_samples::<WINDOW_SIZE>(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+    samples
+}
+
+fn energy(samples: &[Q15]) -> f64 {
+    samples.iter().map(|s| s.to_num::<f64>().powi(2)).sum()
+}
+
+/// Energy of the first difference over the energy of the signal, which grows with brightness
+fn brightness(samples: &[Q15]) -> f64 {
+    let differences: f64 = samples
+        .windows(2)
+        .map(|w| (w[1].to_num::<f64>() - w[0].to_num::<f64>()).powi(2))
+        .sum();
+    differences / energy(samples)
+}
+
+/// Lag with the highest autocorrelation between `min_lag` and `max_lag`,
+/// refined with a parabola through its neighbours
+fn estimate_period(samples: &[Q15], min_lag: usize, max_lag: usize) -> f64 {
+    let correlation = |lag: usize| -> f64 {
+        samples
+            .iter()
+            .zip(samples[lag..].iter())
+            .map(|(a, b)| a.to_num::<f64>() * b.to_num::<f64>())
+            .sum()
+    };
+
+    let best = (min_lag..=max_lag)
+        .max_by(|a, b| correlation(*a).total_cmp(&correlation(*b)))
+        .unwrap();
+
+    let (left, center, right) = (
+        correlation(best - 1),
+        correlation(best),
+        correlation(best + 1),
+    );
+    best as f64 + 0.5 * (left - right) / (left - 2. * center + right)
+}
+
+#[test]
+fn test_string_plays_in_tune() {
+    for excitation in [StringExcitation::Pluck, StringExcitation::Strike] {
+        for note in [45, 57, 69, 81] {
+            let note = Note::new(note);
+            let mut voice = play(config(excitation, 240, 0), note, 100);
+            let samples = render(&mut voice, 64);
+
+            let expected = 256. / MIDI_TO_PHASE_INCREMENT[note.as_u8() as usize].to_num::<f64>();
+            let period = estimate_period(
+                &samples[2048..],
+                (expected * 0.75) as usize,
+                (expected * 1.25) as usize,
+            );
+            let cents = 1200. * f64::log2(period / expected);
+
+            assert!(
+                cents.abs() < 5.,
+                "{} with {:?}: expected a period of {}, got {} ({} cents off)",
+                note.as_u8(),
+                excitation,
+                expected,
+                period,
+                cents
+            );
+        }
+    }
+}
+
+#[test]
+fn test_notes_below_the_delay_line_are_clamped() {
+    let mut voice = play(config(StringExcitation::Pluck, 240, 0), Note::new(0), 127);
+    let samples = render(&mut voice, 64);
+
+    let longest = SAMPLE_RATE as f64 / (DELAY_LINE_LENGTH as f64 - 1.5);
+    let period = estimate_period(&samples, DELAY_LINE_LENGTH / 2, DELAY_LINE_LENGTH * 2);
+
+    assert!(
+        (SAMPLE_RATE as f64 / period - longest).abs() < 1.,
+        "Expected {} Hz, got {} Hz",
+        longest,
+        SAMPLE_RATE as f64 / period
+    );
+}
+
+#[test]
+fn test_string_has_no_dc() {
+    for excitation in [StringExcitation::Pluck, StringExcitation::Strike] {
+        for velocity in [1, 64, 127] {
+            let mut voice = play(config(excitation, 255, 0), A3, velocity);
+            let samples = render(&mut voice, 256);
+
+            let mean =
+                samples.iter().map(|s| s.to_num::<f64>()).sum::<f64>() / samples.len() as f64;
+            assert!(
+                mean.abs() < 0.005,
+                "{:?} at velocity {}: mean of {}",
+                excitation,
+                velocity,
+                mean
+            );
+        }
+    }
+}
+
+#[test]
+fn test_velocity_controls_brightness() {
+    for excitation in [StringExcitation::Pluck, StringExcitation::Strike] {
+        let mut brightnesses = Vec::new();
+
+        for velocity in [10, 64, 127] {
+            let mut voice = play(config(excitation, 240, 0), A3, velocity);
+            brightnesses.push(brightness(&render(&mut voice, 4)));
+        }
+
+        assert!(
+            brightnesses.windows(2).all(|w| w[0] < w[1]),
+            "{:?} should get brighter with velocity: {:?}",
+            excitation,
+            brightnesses
+        );
+    }
+}
+
+#[test]
+fn test_high_harmonics_decay_first() {
+    let mut voice = play(config(StringExcitation::Pluck, 250, 0), A3, 127);
+
+    let attack = brightness(&render(&mut voice, 4));
+    render(&mut voice, 64);
+    let tail = brightness(&render(&mut voice, 4));
+
+    assert!(
+        tail < attack / 4.,
+        "Expected the tail ({}) to be much darker than the attack ({})",
+        tail,
+        attack
+    );
+}
+
+#[test]
+fn test_releasing_damps_the_string() {
+    let mut held = play(config(StringExcitation::Pluck, 240, 0), A3, 100);
+    let mut released = held;
+    released.stop_playing();
+
+    let held_energy = energy(&render(&mut held, 32)[3072..]);
+    let released_energy = energy(&render(&mut released, 32)[3072..]);
+
+    assert!(
+        released_energy < held_energy / 10.,
+        "Damping should silence the string: held {}, released {}",
+        held_energy,
+        released_energy
+    );
+}
+
+#[test]
+fn test_longer_decay_rings_longer() {
+    let mut energies = Vec::new();
+
+    for decay in [100, 200, 250, 255] {
+        let mut voice = play(config(StringExcitation::Pluck, decay, 0), A3, 100);
+        energies.push(energy(&render(&mut voice, 64)[6144..]));
+    }
+
+    assert!(
+        energies.windows(2).all(|w| w[0] < w[1]),
+        "Energy should grow with the decay: {:?}",
+        energies
+    );
+}
+
+#[test]
+fn test_feedback_for_encoder() {
+    assert_eq!(StringConfig::feedback_for_encoder(u8::MAX), Q15::MAX);
+
+    assert!(StringConfig::feedback_for_encoder(0) > Q15::from_num(0.85));
+
+    for encoder in 0..u8::MAX {
+        assert!(
+            StringConfig::feedback_for_encoder(encoder)
+                <= StringConfig::feedback_for_encoder(encoder + 1)
+        );
+    }
+}
+
+#[test]
+fn test_excitation_is_deterministic() {
+    let config = config(StringExcitation::Pluck, 240, 0);
+
+    let first = render(&mut play(config, A3, 100), 8);
+    let second = render(&mut play(config, A3, 100), 8);
+    assert_eq!(first, second);
+
+    // Plucking again uses new noise
+    let mut third = play(config, A3, 100);
+    third.excite(100);
+    assert_ne!(first, render(&mut third, 8));
+}
@@ -0,0 +1,276 @@
+use defmt::Format;
+use fixed::types::U8F24;
+use midi::{NoteTuning, TuningTable};
+
+use crate::Note;
+use crate::wavetable::phase_increment_table::MIDI_TO_PHASE_INCREMENT;
+
+/// ln(2) / 12 as a 0.32 fixed point number, so that e^(x * LN_2_OVER_12) = 2^(x / 12)
+const LN_2_OVER_12: u64 = 248_087_039;
+
+/// Fractions of a semitone are expressed in 1/16384ths, like MTS does
+const SEMITONE_FRACTION_BITS: u32 = 14;
+
+/// 2^(k / 12) for every semitone k of an octave
+const SEMITONE_RATIOS: [U8F24; 12] = [
+    U8F24::lit("1"),
+    U8F24::lit("1.059463094"),
+    U8F24::lit("1.122462048"),
+    U8F24::lit("1.189207115"),
+    U8F24::lit("1.259921050"),
+    U8F24::lit("1.334839854"),
+    U8F24::lit("1.414213562"),
+    U8F24::lit("1.498307077"),
+    U8F24::lit("1.587401052"),
+    U8F24::lit("1.681792831"),
+    U8F24::lit("1.781797436"),
+    U8F24::lit("1.887748625"),
+];
+
+/// Transposition of an oscillator relative to the note being played.
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PitchOffset {
+    pub semitones: i8,
+    /// Fine tuning, in the range -100 to 100
+    pub cents: i8,
+}
+
+impl PitchOffset {
+    pub const UNISON: Self = Self::new(0, 0);
+
+    pub const fn new(semitones: i8, cents: i8) -> Self {
+        Self { semitones, cents }
+    }
+}
+
+impl Default for PitchOffset {
+    fn default() -> Self {
+        Self::UNISON
+    }
+}
+
+/// Phase increment for every MIDI key, derived from a [`TuningTable`].
+///
+/// Defaults to 12-TET, which is exactly `MIDI_TO_PHASE_INCREMENT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tuning {
+    phase_increments: [U8F24; 128],
+}
+
+impl Tuning {
+    pub fn new() -> Self {
+        Self {
+            phase_increments: MIDI_TO_PHASE_INCREMENT,
+        }
+    }
+
+    pub fn phase_increment(&self, note: &Note) -> U8F24 {
+        self.phase_increments[note.as_u8() as usize & 0x7F]
+    }
+
+    /// Phase increment of `note` transposed by `offset`.
+    ///
+    /// Semitones move to another key of the tuning, so transposed oscillators
+    /// follow microtunings. Past the ends of the MIDI key range, the rest of the
+    /// transposition is taken in equal temperament from the last key.
+    pub fn phase_increment_with_offset(&self, note: &Note, offset: &PitchOffset) -> U8F24 {
+        let transposed = note.as_u8() as i32 + offset.semitones as i32;
+        let key = transposed.clamp(0, 127);
+        let fraction = ((transposed - key) << SEMITONE_FRACTION_BITS)
+            + ((offset.cents as i32) << SEMITONE_FRACTION_BITS) / 100;
+
+        scale_by_semitones(self.phase_increments[key as usize], fraction)
+    }
+
+    pub fn apply_tuning_table(&mut self, table: &TuningTable) {
+        for (phase_increment, note_tuning) in
+            self.phase_increments.iter_mut().zip(table.notes.iter())
+        {
+            *phase_increment = Self::phase_increment_for(note_tuning);
+        }
+    }
+
+    fn phase_increment_for(note_tuning: &NoteTuning) -> U8F24 {
+        let base = MIDI_TO_PHASE_INCREMENT[note_tuning.semitone as usize & 0x7F];
+
+        scale_by_semitone_fraction(base, note_tuning.fraction as i32)
+    }
+}
+
+/// Scales a phase increment by `fraction` / 16384 semitones, with `fraction` in (-16384, 16384).
+pub(crate) fn scale_by_semitone_fraction(base: U8F24, fraction: i32) -> U8F24 {
+    if fraction == 0 {
+        return base;
+    }
+
+    /*
+     * The fraction is at most one semitone, so we need to scale by 2^(f/12) with
+     * f in (-1, 1). With |x| = |f| * ln(2) / 12 < 0.058 a third order Taylor expansion
+     * of e^x is accurate to well under a thousandth of a cent.
+     *
+     * Everything is in 32.32 fixed point.
+     */
+    let one: i64 = 1 << 32;
+    let x = ((fraction as i64) * LN_2_OVER_12 as i64) >> SEMITONE_FRACTION_BITS;
+    let x2 = (x * x) >> 32;
+    let x3 = (x2 * x) >> 32;
+    let factor = (one + x + x2 / 2 + x3 / 6) as u64;
+
+    let scaled = ((base.to_bits() as u64) * factor) >> 32;
+
+    U8F24::from_bits(scaled.min(u32::MAX as u64) as u32)
+}
+
+/// Scales a phase increment by `fraction` / 16384 semitones, however many semitones that is.
+pub(crate) fn scale_by_semitones(base: U8F24, fraction: i32) -> U8F24 {
+    let semitones = fraction >> SEMITONE_FRACTION_BITS;
+    let fraction = fraction & ((1 << SEMITONE_FRACTION_BITS) - 1);
+    let octaves = semitones.div_euclid(12);
+
+    let scaled = scale_by_semitone_fraction(
+        base.saturating_mul(SEMITONE_RATIOS[semitones.rem_euclid(12) as usize]),
+        fraction,
+    );
+
+    let bits = scaled.to_bits() as u64;
+    let bits = if octaves >= 0 {
+        bits << octaves.min(32)
+    } else {
+        bits >> (-octaves).min(32)
+    };
+
+    U8F24::from_bits(bits.min(u32::MAX as u64) as u32)
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cents_between(a: U8F24, b: U8F24) -> f64 {
+        1200. * f64::log2(a.to_num::<f64>() / b.to_num::<f64>())
+    }
+
+    #[test]
+    fn test_default_tuning_matches_equal_temperament() {
+        let mut tuning = Tuning::new();
+        let default = tuning;
+
+        tuning.apply_tuning_table(&TuningTable::equal_temperament());
+
+        assert_eq!(tuning, default);
+    }
+
+    #[test]
+    fn test_fraction_interpolates_between_semitones() {
+        let mut table = TuningTable::equal_temperament();
+        // A quarter tone above A4
+        table.notes[69] = NoteTuning::new(69, 8192);
+        // Almost a semitone above A4
+        table.notes[70] = NoteTuning::new(69, 16383);
+
+        let mut tuning = Tuning::new();
+        tuning.apply_tuning_table(&table);
+
+        let a4 = MIDI_TO_PHASE_INCREMENT[69];
+        let a_sharp_4 = MIDI_TO_PHASE_INCREMENT[70];
+
+        let quarter_tone = cents_between(tuning.phase_increment(&Note::new(69)), a4);
+        assert!(
+            (quarter_tone - 50.).abs() < 0.01,
+            "Expected 50 cents, got {}",
+            quarter_tone
+        );
+
+        let almost_semitone = cents_between(tuning.phase_increment(&Note::new(70)), a_sharp_4);
+        assert!(
+            almost_semitone.abs() < 0.02,
+            "Expected ~0 cents from A#4, got {}",
+            almost_semitone
+        );
+    }
+
+    #[test]
+    fn test_pitch_offset_transposes_and_detunes() {
+        let tuning = Tuning::new();
+        let c4 = Note::new(60);
+
+        assert_eq!(
+            tuning.phase_increment_with_offset(&c4, &PitchOffset::UNISON),
+            MIDI_TO_PHASE_INCREMENT[60]
+        );
+        assert_eq!(
+            tuning.phase_increment_with_offset(&c4, &PitchOffset::new(-12, 0)),
+            MIDI_TO_PHASE_INCREMENT[48]
+        );
+
+        for cents in [-50, -7, 7, 50] {
+            let detuned = tuning.phase_increment_with_offset(&c4, &PitchOffset::new(7, cents));
+            let error = cents_between(detuned, MIDI_TO_PHASE_INCREMENT[67]) - cents as f64;
+            assert!(error.abs() < 0.01, "Detune off by {} cents", error);
+        }
+    }
+
+    #[test]
+    fn test_pitch_offset_past_the_keyboard() {
+        let tuning = Tuning::new();
+
+        // An octave up from the top keys, and two down from the bottom ones
+        for (key, semitones, cents) in [(120, 12, 0), (127, 12, 30), (3, -24, 0), (0, -12, -30)] {
+            let transposed = tuning
+                .phase_increment_with_offset(&Note::new(key), &PitchOffset::new(semitones, cents));
+            let error = cents_between(transposed, MIDI_TO_PHASE_INCREMENT[key as usize])
+                - (100 * semitones as i32 + cents as i32) as f64;
+            assert!(
+                error.abs() < 0.01,
+                "Key {} by {} semitones off by {} cents",
+                key,
+                semitones,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_scale_by_semitones_goes_past_a_semitone() {
+        let a4 = MIDI_TO_PHASE_INCREMENT[69];
+
+        for semitones in [-24, -13, -12, -1, 0, 1, 7, 12, 19] {
+            let scaled = scale_by_semitones(a4, semitones << SEMITONE_FRACTION_BITS);
+            let error = cents_between(scaled, a4) - 100. * semitones as f64;
+            assert!(
+                error.abs() < 0.01,
+                "{} semitones off by {} cents",
+                semitones,
+                error
+            );
+        }
+
+        // A quarter tone down, which goes a semitone down and then a quarter tone up
+        let quarter_tone_down = scale_by_semitones(a4, -(1 << (SEMITONE_FRACTION_BITS - 1)));
+        assert!((cents_between(quarter_tone_down, a4) + 50.).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_keys_can_be_remapped() {
+        let mut table = TuningTable::equal_temperament();
+        table.notes[60] = NoteTuning::new(72, 0);
+
+        let mut tuning = Tuning::new();
+        tuning.apply_tuning_table(&table);
+
+        assert_eq!(
+            tuning.phase_increment(&Note::new(60)),
+            MIDI_TO_PHASE_INCREMENT[72]
+        );
+        assert_eq!(
+            tuning.phase_increment(&Note::new(61)),
+            MIDI_TO_PHASE_INCREMENT[61]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use defmt::Format;
+
+#[cfg(test)]
+mod tests;
+
+/// Highest MIDI velocity
+const MAX_VELOCITY: u8 = 127;
+/// Sensitivity at which the curve goes through untouched
+const FULL_SENSITIVITY: u8 = 255;
+
+/// How the velocity a key is played at maps to the velocity the voice gets
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VelocityCurve {
+    Linear,
+    /// Louder for a light touch, reaching the top sooner
+    Soft,
+    /// Quieter for a heavy touch, needing harder playing to reach the top
+    Hard,
+    /// Every note at the same velocity
+    Fixed(u8),
+}
+
+/// Shapes the velocity of every note before it reaches the voice.
+///
+/// The curve is applied first, and the sensitivity then pulls the result towards the top,
+/// so that at no sensitivity every note plays at full velocity.
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VelocityResponse {
+    curve: VelocityCurve,
+    sensitivity: u8,
+}
+
+impl Default for VelocityResponse {
+    fn default() -> Self {
+        Self {
+            curve: VelocityCurve::Linear,
+            sensitivity: FULL_SENSITIVITY,
+        }
+    }
+}
+
+impl VelocityResponse {
+    pub fn set_curve(&mut self, curve: VelocityCurve) {
+        self.curve = curve;
+    }
+
+    /// From 0, where every note plays at full velocity, to 255, where the curve goes through
+    pub fn set_sensitivity(&mut self, sensitivity: u8) {
+        self.sensitivity = sensitivity;
+    }
+
+    /// Maps a velocity from 1 to 127, never going silent for a note that was played
+    pub fn apply(&self, velocity: u8) -> u8 {
+        let velocity = velocity.min(MAX_VELOCITY) as u32;
+        let max = MAX_VELOCITY as u32;
+
+        let curved = match self.curve {
+            VelocityCurve::Linear => velocity,
+            VelocityCurve::Soft => max - (max - velocity) * (max - velocity) / max,
+            VelocityCurve::Hard => velocity * velocity / max,
+            VelocityCurve::Fixed(fixed) => fixed.min(MAX_VELOCITY) as u32,
+        };
+        let scaled = max - (max - curved) * self.sensitivity as u32 / FULL_SENSITIVITY as u32;
+
+        scaled.max(1) as u8
+    }
+}
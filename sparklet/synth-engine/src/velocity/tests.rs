@@ -0,0 +1,105 @@
+use super::*;
+
+const CURVES: [VelocityCurve; 4] = [
+    VelocityCurve::Linear,
+    VelocityCurve::Soft,
+    VelocityCurve::Hard,
+    VelocityCurve::Fixed(90),
+];
+
+fn response(curve: VelocityCurve, sensitivity: u8) -> VelocityResponse {
+    let mut response = VelocityResponse::default();
+    response.set_curve(curve);
+    response.set_sensitivity(sensitivity);
+    response
+}
+
+fn velocities(response: &VelocityResponse) -> Vec<u8> {
+    (1..=127).map(|velocity| response.apply(velocity)).collect()
+}
+
+#[test]
+fn test_default_leaves_velocity_alone() {
+    let response = VelocityResponse::default();
+
+    for velocity in 1..=127 {
+        assert_eq!(response.apply(velocity), velocity);
+    }
+}
+
+#[test]
+fn test_every_curve_is_monotonic() {
+    for curve in CURVES {
+        for sensitivity in [0, 1, 64, 128, 200, 255] {
+            let velocities = velocities(&response(curve, sensitivity));
+
+            assert!(
+                velocities.windows(2).all(|pair| pair[0] <= pair[1]),
+                "{:?} at sensitivity {} went down: {:?}",
+                curve,
+                sensitivity,
+                velocities
+            );
+            assert!(
+                velocities
+                    .iter()
+                    .all(|&velocity| (1..=127).contains(&velocity))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_curves_share_their_ends() {
+    for curve in [
+        VelocityCurve::Linear,
+        VelocityCurve::Soft,
+        VelocityCurve::Hard,
+    ] {
+        let response = response(curve, 255);
+        assert_eq!(response.apply(127), 127, "{:?}", curve);
+        assert!(response.apply(1) <= 2, "{:?}", curve);
+    }
+}
+
+#[test]
+fn test_soft_is_above_linear_and_hard_below() {
+    let linear = velocities(&response(VelocityCurve::Linear, 255));
+    let soft = velocities(&response(VelocityCurve::Soft, 255));
+    let hard = velocities(&response(VelocityCurve::Hard, 255));
+
+    for index in 0..linear.len() {
+        assert!(soft[index] >= linear[index]);
+        assert!(hard[index] <= linear[index]);
+    }
+    assert!(soft[63] > linear[63] + 20);
+    assert!(hard[63] + 20 < linear[63]);
+}
+
+#[test]
+fn test_fixed_ignores_the_key() {
+    let response = response(VelocityCurve::Fixed(90), 255);
+
+    assert!(velocities(&response).iter().all(|&velocity| velocity == 90));
+}
+
+#[test]
+fn test_sensitivity_pulls_towards_the_top() {
+    for curve in CURVES {
+        assert!(
+            velocities(&response(curve, 0))
+                .iter()
+                .all(|&velocity| velocity == 127)
+        );
+    }
+
+    let half = response(VelocityCurve::Linear, 128);
+    assert_eq!(half.apply(127), 127);
+    assert!((62..=65).contains(&half.apply(1)));
+
+    // More sensitivity never makes a note louder
+    for velocity in 1..=127 {
+        let louder = response(VelocityCurve::Hard, 100).apply(velocity);
+        assert!(response(VelocityCurve::Hard, 200).apply(velocity) <= louder);
+    }
+}
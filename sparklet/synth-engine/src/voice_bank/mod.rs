@@ -1,9 +1,42 @@
+use cmsis_interface::Q15;
 use defmt::Format;
 use embassy_sync::{blocking_mutex::raw::RawMutex, channel::Receiver};
+use fixed::types::U8F24;
 use heapless::Deque;
-use midi::MidiEvent;
-
-use crate::{SAMPLE_RATE, adsr::ADSR, wavetable::WavetableOscillator};
+use midi::{MOD_WHEEL_CONTROLLER, MidiEvent, TuningTable};
+
+use crate::{
+    SAMPLE_RATE,
+    adsr::{ADSR, EnvelopeLoop, key_tracking::KeyTracking},
+    drift::Drift,
+    fm::{FM_OPERATOR_AMOUNT, FmAlgorithm, FmOperatorConfig, FmVoice},
+    lfo::{Lfo, LfoConfig, LfoFade, LfoPhase},
+    mod_matrix::{MOD_SLOT_AMOUNT, ModMatrix, ModSlot, ModSources},
+    noise::{NoiseColor, NoiseOscillator},
+    phase_distortion::{PhaseDistortionConfig, PhaseDistortionOscillator},
+    rng::Rng,
+    sampler::{Sample, SampleOscillator},
+    shaper::{Shaper, ShaperConfig},
+    string::{StringConfig, StringVoice},
+    tuning::{PitchOffset, Tuning},
+    velocity::{VelocityCurve, VelocityResponse},
+    wavetable::{
+        PhaseMode, SubOscillator, SubOscillatorShape, WavetableOscillator,
+        saw_wavetable::SAW_WAVETABLE,
+    },
+};
+
+/// Seeds the random starting phases of every oscillator in the bank
+const PHASE_SEED: u32 = 0x5EED_0F05;
+/// Seeds the noise that plucks the strings of every voice
+const STRING_SEED: u32 = 0x5EED_57A1;
+/// Seeds the analog drift of every voice
+const DRIFT_SEED: u32 = 0x5EED_D21F;
+/// Seeds the sample and hold of the LFO of every voice, and the shared one
+const LFO_SEED: u32 = 0x5EED_1F05;
+
+/// The modulation envelope ignores velocity, which the matrix can route on its own
+const MOD_ENVELOPE_VELOCITY: u8 = 127;
 
 /// A MIDI note number (0-127)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -128,6 +161,17 @@ pub enum PlayNoteResult {
     AllVoicesBusy,
 }
 
+/// How the second oscillator of a voice interacts with the first one
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecondOscillatorMode {
+    /// Both oscillators run independently
+    Mix,
+    /// The second oscillator restarts its cycle whenever the first one does
+    HardSync,
+    /// The second oscillator is multiplied by the first one
+    RingModulation,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VoiceStage {
     Free,
@@ -141,22 +185,115 @@ pub(crate) struct Voice<'a> {
     pub(crate) velocity: Velocity,
     pub(crate) adsr: ADSR,
     pub(crate) wavetable_osc: WavetableOscillator<'a, SAMPLE_RATE>,
+    pub(crate) second_wavetable_osc: WavetableOscillator<'a, SAMPLE_RATE>,
+    /// How much of the second oscillator is mixed into the first one
+    pub(crate) second_osc_mix: Q15,
+    pub(crate) second_osc_mode: SecondOscillatorMode,
+    /// Duty cycle of the first oscillator before modulation, if it's a pulse
+    pub(crate) pulse_width: Option<Q15>,
+    pub(crate) sub_osc: SubOscillator,
+    /// How much of the sub oscillator is mixed into the other two
+    pub(crate) sub_osc_mix: Q15,
+    pub(crate) noise_osc: NoiseOscillator,
+    /// How much of the noise oscillator is mixed into the wavetable oscillator
+    pub(crate) noise_mix: Q15,
+    /// When enabled the FM operators are played instead of the wavetable oscillators
+    pub(crate) fm_enabled: bool,
+    pub(crate) fm_voice: FmVoice,
+    /// When it has a sample it's played instead of the wavetable and FM oscillators
+    pub(crate) sample_osc: SampleOscillator<'a>,
+    /// When enabled the string model is played instead of the FM and wavetable oscillators
+    pub(crate) string_enabled: bool,
+    pub(crate) string_voice: StringVoice,
+    /// When enabled the phase distortion oscillator is played instead of the FM
+    /// and wavetable oscillators
+    pub(crate) phase_distortion_enabled: bool,
+    pub(crate) phase_distortion_osc: PhaseDistortionOscillator,
+    /// When enabled the oscillators go through the shaper before the envelope
+    pub(crate) shaper_enabled: bool,
+    pub(crate) shaper: Shaper,
+    /// When enabled the pitch and level of the voice wander slowly
+    pub(crate) drift_enabled: bool,
+    pub(crate) drift: Drift,
+    /// Only used when the LFO runs per voice
+    pub(crate) lfo: Lfo,
+    pub(crate) lfo_fade: LfoFade,
+    /// Only feeds the modulation matrix, never the level of the voice
+    pub(crate) mod_envelope: ADSR,
+    /// Polyphonic aftertouch on the key of the voice
+    pub(crate) pressure: Q15,
+    /// Gain the modulation matrix left the level at by the end of the last window,
+    /// or `None` when the note just started
+    pub(crate) mod_gain: Option<Q15>,
 }
 
 impl<'a> Voice<'a> {
+    /// Detunes every pitched oscillator of the voice by `drift` times its frequency
+    pub(crate) fn set_pitch_drift(&mut self, drift: U8F24) {
+        self.wavetable_osc.set_drift(drift);
+        self.second_wavetable_osc.set_drift(drift);
+        self.fm_voice.set_drift(drift);
+        self.phase_distortion_osc.set_drift(drift);
+    }
+
+    /// Restarts the fade in of the LFO, and its cycle if it's synced to the keys
+    pub(crate) fn start_lfo(&mut self, config: &Option<LfoConfig>, global_lfo: &mut Lfo) {
+        self.lfo_fade.restart();
+
+        match config {
+            Some(LfoConfig {
+                key_sync: true,
+                phase: LfoPhase::Global,
+                ..
+            }) => global_lfo.reset(),
+            Some(LfoConfig {
+                key_sync: true,
+                phase: LfoPhase::PerVoice,
+                ..
+            }) => self.lfo.reset(),
+            _ => {}
+        }
+    }
+
     pub(crate) fn retrigger(&mut self, timestamp: u32, velocity: Velocity) {
         self.timestamp = timestamp;
         //  TODO: Velocity change is sudden, can lead to popping
         self.velocity = velocity;
         self.adsr.retrigger(velocity.as_u8());
+        self.mod_envelope.retrigger(MOD_ENVELOPE_VELOCITY);
+        self.fm_voice.retrigger();
+        self.phase_distortion_osc.retrigger();
+        self.sample_osc.restart();
+        if self.string_enabled {
+            self.string_voice.excite(velocity.as_u8());
+        }
     }
 
-    pub(crate) fn play_note(&mut self, timestamp: u32, note: Note, velocity: Velocity) {
+    pub(crate) fn play_note(
+        &mut self,
+        timestamp: u32,
+        note: Note,
+        velocity: Velocity,
+        tuning: &Tuning,
+        key_tracking: &KeyTracking,
+    ) {
         self.timestamp = timestamp;
         self.note = note;
         self.velocity = velocity;
-        self.wavetable_osc.set_note(&note);
+        self.wavetable_osc.set_note(&note, tuning);
+        self.second_wavetable_osc.set_note(&note, tuning);
+        self.fm_voice.set_note(&note, tuning);
+        self.phase_distortion_osc.set_note(&note, tuning);
+        self.sample_osc.set_note(&note, tuning);
+        if self.string_enabled {
+            self.string_voice.play(&note, tuning, velocity.as_u8());
+        }
+        self.shaper.reset();
+        self.pressure = Q15::ZERO;
+        self.mod_gain = None;
+        self.adsr.set_key_tracking(key_tracking, note.as_u8());
         self.adsr.play(velocity.as_u8());
+        self.mod_envelope.play(MOD_ENVELOPE_VELOCITY);
     }
 }
 
@@ -169,6 +306,17 @@ where
     pub(crate) timestamp_counter: u32,
     receiver: Receiver<'ac, M, MidiEvent, CHANNEL_SIZE>,
     note_queue: Deque<PendingNote, N>,
+    tuning: Tuning,
+    pub(crate) lfo_config: Option<LfoConfig>,
+    /// Shared by every voice when the LFO is global
+    pub(crate) global_lfo: Lfo,
+    pub(crate) mod_matrix: ModMatrix,
+    pub(crate) mod_wheel: Q15,
+    /// Aftertouch on the whole keyboard
+    pub(crate) channel_pressure: Q15,
+    pub(crate) pitch_bend: Q15,
+    velocity_response: VelocityResponse,
+    key_tracking: KeyTracking,
 }
 
 impl<'a, 'ac, M, const N: usize, const CHANNEL_SIZE: usize> Format for VoiceBank<'a, 'ac, M, N, CHANNEL_SIZE>
@@ -197,23 +345,92 @@ where
     M: RawMutex,
 {
     pub fn new(
-        wavetable: &'a [cmsis_interface::Q15; 256],
+        wavetable: &'a [Q15; 256],
         sustain_config: u8,
         attack_config: u8,
-        decay_release_config: u8,
+        decay_config: u8,
+        release_config: u8,
         receiver: Receiver<'ac, M, MidiEvent, CHANNEL_SIZE>,
     ) -> Self {
+        let mut voices = [Voice {
+            timestamp: 0,
+            note: Note(0),
+            velocity: Velocity(0),
+            adsr: ADSR::new(
+                sustain_config,
+                attack_config,
+                decay_config,
+                release_config,
+                0,
+            ),
+            wavetable_osc: WavetableOscillator::new(wavetable),
+            second_wavetable_osc: WavetableOscillator::new(wavetable),
+            second_osc_mix: Q15::ZERO,
+            second_osc_mode: SecondOscillatorMode::Mix,
+            pulse_width: None,
+            sub_osc: SubOscillator::new(SubOscillatorShape::Square, 1),
+            sub_osc_mix: Q15::ZERO,
+            noise_osc: NoiseOscillator::new(NoiseColor::White, 0),
+            noise_mix: Q15::ZERO,
+            fm_enabled: false,
+            fm_voice: FmVoice::new(attack_config, sustain_config, decay_config),
+            sample_osc: SampleOscillator::new(),
+            string_enabled: false,
+            string_voice: StringVoice::default(),
+            phase_distortion_enabled: false,
+            phase_distortion_osc: PhaseDistortionOscillator::new(
+                attack_config,
+                sustain_config,
+                decay_config,
+            ),
+            shaper_enabled: false,
+            shaper: Shaper::default(),
+            drift_enabled: false,
+            drift: Drift::default(),
+            lfo: Lfo::default(),
+            lfo_fade: LfoFade::default(),
+            mod_envelope: ADSR::new(
+                sustain_config,
+                attack_config,
+                decay_config,
+                release_config,
+                MOD_ENVELOPE_VELOCITY,
+            ),
+            pressure: Q15::ZERO,
+            mod_gain: None,
+        }; N];
+
+        // Every voice gets its own seed so stacked voices aren't correlated
+        let mut phase_seeds = Rng::new(PHASE_SEED);
+        let mut string_seeds = Rng::new(STRING_SEED);
+        let mut drift_seeds = Rng::new(DRIFT_SEED);
+        let mut lfo_seeds = Rng::new(LFO_SEED);
+        for (index, voice) in voices.iter_mut().enumerate() {
+            voice.noise_osc = NoiseOscillator::new(NoiseColor::White, index as u32 + 1);
+            voice.wavetable_osc.set_phase_seed(phase_seeds.next_u32());
+            voice
+                .second_wavetable_osc
+                .set_phase_seed(phase_seeds.next_u32());
+            voice.fm_voice.set_phase_seed(phase_seeds.next_u32());
+            voice.string_voice = StringVoice::new(string_seeds.next_u32());
+            voice.drift = Drift::new(drift_seeds.next_u32());
+            voice.lfo = Lfo::new(lfo_seeds.next_u32());
+        }
+
         Self {
-            voices: [Voice {
-                timestamp: 0,
-                note: Note(0),
-                velocity: Velocity(0),
-                adsr: ADSR::new(sustain_config, attack_config, decay_release_config, 0),
-                wavetable_osc: WavetableOscillator::new(wavetable),
-            }; N],
+            voices,
             timestamp_counter: 0,
             receiver,
             note_queue: Deque::new(),
+            tuning: Tuning::default(),
+            lfo_config: None,
+            global_lfo: Lfo::new(lfo_seeds.next_u32()),
+            mod_matrix: ModMatrix::default(),
+            mod_wheel: Q15::ZERO,
+            channel_pressure: Q15::ZERO,
+            pitch_bend: Q15::ZERO,
+            velocity_response: VelocityResponse::default(),
+            key_tracking: KeyTracking::default(),
         }
     }
 
@@ -227,12 +444,15 @@ where
         velocity: Velocity,
         retrigger: bool,
     ) -> PlayNoteResult {
+        let velocity = Velocity(self.velocity_response.apply(velocity.as_u8()));
+
         // Check for retriggering first
         if retrigger {
             for voice in self.voices.iter_mut() {
                 if voice.note == note && !voice.adsr.is_idle() {
                     self.timestamp_counter = self.timestamp_counter.wrapping_add(1);
                     voice.retrigger(self.timestamp_counter, velocity);
+                    voice.start_lfo(&self.lfo_config, &mut self.global_lfo);
                     return PlayNoteResult::Success;
                 }
             }
@@ -242,7 +462,14 @@ where
         for voice in self.voices.iter_mut() {
             if voice.adsr.is_idle() {
                 self.timestamp_counter = self.timestamp_counter.wrapping_add(1);
-                voice.play_note(self.timestamp_counter, note, velocity);
+                voice.play_note(
+                    self.timestamp_counter,
+                    note,
+                    velocity,
+                    &self.tuning,
+                    &self.key_tracking,
+                );
+                voice.start_lfo(&self.lfo_config, &mut self.global_lfo);
                 return PlayNoteResult::Success;
             }
         }
@@ -255,6 +482,10 @@ where
         for voice in self.voices.iter_mut() {
             if voice.note == note && !voice.adsr.is_idle() {
                 voice.adsr.stop_playing();
+                voice.mod_envelope.stop_playing();
+                voice.fm_voice.stop_playing();
+                voice.string_voice.stop_playing();
+                voice.phase_distortion_osc.stop_playing();
             }
         }
     }
@@ -295,17 +526,240 @@ where
             .count()
     }
 
-    pub fn set_wavetable_all_voices(&mut self, wavetable: &'a [cmsis_interface::Q15; 256]) {
+    pub fn set_wavetable_all_voices(&mut self, wavetable: &'a [Q15; 256]) {
         for voice in self.voices.iter_mut() {
             voice.wavetable_osc.set_wavetable(wavetable);
         }
     }
 
-    pub fn set_adsr_config_all_voices(&mut self, sustain: u8, attack: u8, decay_release: u8) {
+    /// Turns the first oscillator into a pulse made from the saw wavetable, or back
+    /// into a plain wavetable oscillator with `None`.
+    ///
+    /// A pulse replaces the wavetable, so call this after [`Self::set_wavetable_all_voices`]
+    pub fn set_pulse_width_all_voices(&mut self, pulse_width: Option<Q15>) {
+        for voice in self.voices.iter_mut() {
+            if pulse_width.is_some() {
+                voice.wavetable_osc.set_wavetable(&SAW_WAVETABLE);
+            }
+            voice.pulse_width = pulse_width;
+            voice.wavetable_osc.set_pulse_width(pulse_width);
+        }
+    }
+
+    /// Sets where the oscillators start on every new note
+    pub fn set_phase_mode_all_voices(&mut self, phase_mode: PhaseMode) {
+        for voice in self.voices.iter_mut() {
+            voice.wavetable_osc.set_phase_mode(phase_mode);
+            voice.second_wavetable_osc.set_phase_mode(phase_mode);
+            voice.fm_voice.set_phase_mode(phase_mode);
+        }
+    }
+
+    pub fn set_sub_oscillator_all_voices(&mut self, sub_osc: SubOscillator, mix: Q15) {
+        for voice in self.voices.iter_mut() {
+            voice.sub_osc = sub_osc;
+            voice.sub_osc_mix = mix;
+        }
+    }
+
+    /// Sets up the second oscillator, retuning the voices that are currently sounding
+    pub fn set_second_oscillator_all_voices(
+        &mut self,
+        wavetable: &'a [Q15; 256],
+        mix: Q15,
+        mode: SecondOscillatorMode,
+        pitch_offset: PitchOffset,
+    ) {
+        for voice in self.voices.iter_mut() {
+            voice.second_wavetable_osc.set_wavetable(wavetable);
+            voice.second_wavetable_osc.set_pitch_offset(pitch_offset);
+            voice.second_osc_mix = mix;
+            voice.second_osc_mode = mode;
+
+            if !voice.adsr.is_idle() {
+                voice.second_wavetable_osc.retune(&voice.note, &self.tuning);
+            }
+        }
+    }
+
+    /// Plays the FM operators with `algorithm` instead of the wavetable oscillators,
+    /// or goes back to the wavetable oscillators with `None`
+    pub fn set_fm_all_voices(
+        &mut self,
+        algorithm: Option<FmAlgorithm>,
+        operators: &[FmOperatorConfig; FM_OPERATOR_AMOUNT],
+    ) {
+        for voice in self.voices.iter_mut() {
+            voice.fm_enabled = algorithm.is_some();
+            if let Some(algorithm) = algorithm {
+                voice.fm_voice.set_algorithm(algorithm);
+            }
+            voice.fm_voice.set_operators(operators);
+
+            if !voice.adsr.is_idle() {
+                voice.fm_voice.retune(&voice.note, &self.tuning);
+            }
+        }
+    }
+
+    /// Plays `sample` instead of the oscillators, or goes back to them with `None`.
+    ///
+    /// Takes effect on the next note.
+    pub fn set_sample_all_voices(&mut self, sample: Option<Sample<'a>>) {
+        for voice in self.voices.iter_mut() {
+            voice.sample_osc.set_sample(sample);
+        }
+    }
+
+    /// Plays the string model instead of the FM and wavetable oscillators,
+    /// or goes back to them with `None`.
+    ///
+    /// Voices that are already sounding keep their string as it is, the new
+    /// excitation is heard from the next note.
+    pub fn set_string_all_voices(&mut self, config: Option<StringConfig>) {
+        for voice in self.voices.iter_mut() {
+            voice.string_enabled = config.is_some();
+            if let Some(config) = config {
+                voice.string_voice.set_config(&config);
+            }
+        }
+    }
+
+    /// Plays the phase distortion oscillator instead of the FM and wavetable
+    /// oscillators, or goes back to them with `None`
+    pub fn set_phase_distortion_all_voices(&mut self, config: Option<PhaseDistortionConfig>) {
+        for voice in self.voices.iter_mut() {
+            voice.phase_distortion_enabled = config.is_some();
+            if let Some(config) = config {
+                voice.phase_distortion_osc.set_config(&config);
+            }
+        }
+    }
+
+    /// Sends every voice through the shaper, or skips it with `None`
+    pub fn set_shaper_all_voices(&mut self, config: Option<ShaperConfig>) {
+        for voice in self.voices.iter_mut() {
+            voice.shaper_enabled = config.is_some();
+            if let Some(config) = config {
+                voice.shaper.set_config(&config);
+            }
+        }
+    }
+
+    /// Lets the pitch and level of every voice wander by up to `depth`,
+    /// or keeps them steady with `None`
+    pub fn set_drift_all_voices(&mut self, depth: Option<Q15>) {
+        for voice in self.voices.iter_mut() {
+            voice.drift_enabled = depth.is_some();
+            match depth {
+                Some(depth) => voice.drift.set_depth(depth),
+                None => voice.set_pitch_drift(U8F24::ONE),
+            }
+        }
+    }
+
+    /// Modulates every voice with the LFO, or leaves them alone with `None`
+    pub fn set_lfo_all_voices(&mut self, config: Option<LfoConfig>) {
+        self.lfo_config = config;
+        if let Some(config) = config {
+            self.global_lfo.set_shape(config.shape);
+            self.global_lfo.set_rate(config.rate);
+        }
+
+        for voice in self.voices.iter_mut() {
+            if let Some(config) = config {
+                voice.lfo.set_shape(config.shape);
+                voice.lfo.set_rate(config.rate);
+                voice.lfo_fade.set_times(config.delay, config.fade_in);
+            }
+        }
+    }
+
+    pub fn set_mod_envelope_all_voices(&mut self, attack: u8, sustain: u8, decay: u8, release: u8) {
+        for voice in self.voices.iter_mut() {
+            voice.mod_envelope.set_attack(attack);
+            voice.mod_envelope.set_sustain(sustain);
+            voice.mod_envelope.set_decay(decay);
+            voice.mod_envelope.set_release(release);
+        }
+    }
+
+    /// The times follow the keys of the voices right away, and the level from their next note
+    pub fn set_key_tracking_all_voices(&mut self, key_tracking: KeyTracking) {
+        self.key_tracking = key_tracking;
+
+        for voice in self.voices.iter_mut() {
+            voice
+                .adsr
+                .set_key_tracking(&self.key_tracking, voice.note.as_u8());
+        }
+    }
+
+    /// Applies to the notes played from now on
+    pub fn set_velocity_response(&mut self, curve: VelocityCurve, sensitivity: u8) {
+        self.velocity_response.set_curve(curve);
+        self.velocity_response.set_sensitivity(sensitivity);
+    }
+
+    pub fn set_mod_matrix(&mut self, slots: [Option<ModSlot>; MOD_SLOT_AMOUNT]) {
+        self.mod_matrix.set_slots(slots);
+    }
+
+    pub fn set_noise_all_voices(&mut self, color: NoiseColor, mix: Q15) {
+        for voice in self.voices.iter_mut() {
+            voice.noise_osc.set_color(color);
+            voice.noise_mix = mix;
+        }
+    }
+
+    /// Replaces the tuning, retuning the voices that are currently sounding
+    pub fn set_tuning_table(&mut self, table: &TuningTable) {
+        self.tuning.apply_tuning_table(table);
+
+        for voice in self.voices.iter_mut().filter(|v| !v.adsr.is_idle()) {
+            voice.wavetable_osc.retune(&voice.note, &self.tuning);
+            voice.second_wavetable_osc.retune(&voice.note, &self.tuning);
+            voice.fm_voice.retune(&voice.note, &self.tuning);
+            voice.sample_osc.retune(&voice.note, &self.tuning);
+            voice.string_voice.retune(&voice.note, &self.tuning);
+            voice.phase_distortion_osc.retune(&voice.note, &self.tuning);
+        }
+    }
+
+    pub fn set_adsr_config_all_voices(&mut self, sustain: u8, attack: u8, decay: u8, release: u8) {
         for voice in self.voices.iter_mut() {
             voice.adsr.set_sustain(sustain);
             voice.adsr.set_attack(attack);
-            voice.adsr.set_decay_release(decay_release);
+            voice.adsr.set_decay(decay);
+            voice.adsr.set_release(release);
+            voice.fm_voice.set_envelope(attack, sustain);
+            voice.phase_distortion_osc.set_envelope(attack, sustain);
+        }
+    }
+
+    pub fn set_envelope_curves_all_voices(&mut self, attack: u8, decay: u8, release: u8) {
+        for voice in self.voices.iter_mut() {
+            voice.adsr.set_attack_curve(attack);
+            voice.adsr.set_decay_curve(decay);
+            voice.adsr.set_release_curve(release);
+        }
+    }
+
+    pub fn set_delay_and_hold_all_voices(&mut self, delay: u8, hold: u8) {
+        for voice in self.voices.iter_mut() {
+            voice.adsr.set_delay(delay);
+            voice.adsr.set_hold(hold);
+        }
+    }
+
+    pub fn set_envelope_loops_all_voices(
+        &mut self,
+        envelope_loop: EnvelopeLoop,
+        mod_envelope_loop: EnvelopeLoop,
+    ) {
+        for voice in self.voices.iter_mut() {
+            voice.adsr.set_loop(envelope_loop);
+            voice.mod_envelope.set_loop(mod_envelope_loop);
         }
     }
 
@@ -331,6 +785,22 @@ where
                         let _ = self.note_queue.push_back(pending);
                     }
                 }
+                MidiEvent::Aftertouch { key, vel } => {
+                    for voice in self.voices.iter_mut().filter(|v| v.note.as_u8() == key) {
+                        voice.pressure = ModSources::from_controller(vel);
+                    }
+                }
+                MidiEvent::Controller { controller, value } => {
+                    if controller == MOD_WHEEL_CONTROLLER {
+                        self.mod_wheel = ModSources::from_controller(value);
+                    }
+                }
+                MidiEvent::ChannelAftertouch { vel } => {
+                    self.channel_pressure = ModSources::from_controller(vel);
+                }
+                MidiEvent::PitchBend { bend } => {
+                    self.pitch_bend = Q15::from_bits(bend << 2);
+                }
             }
         }
 
@@ -22,7 +22,8 @@ macro_rules! setup_voice_bank {
             &SINE_WAVETABLE,
             200, // sustain
             50,  // attack
-            100, // decay_release
+            100, // decay
+            120, // release
             receiver,
         );
     };
@@ -272,8 +273,8 @@ fn test_set_adsr_config_all_voices() {
         .map(|v| v.adsr.capacitor.get_level())
         .collect();
 
-    // Change config: sustain=150, attack=75, decay_release=125
-    vb.set_adsr_config_all_voices(150, 75, 125);
+    // Change config: sustain=150, attack=75, decay=125, release=25
+    vb.set_adsr_config_all_voices(150, 75, 125, 25);
 
     // Verify config was applied by checking that behavior changes
     // Generate more samples - the new attack/decay/sustain settings should be in effect
@@ -0,0 +1,122 @@
+use cmsis_interface::Q15;
+use defmt::Format;
+use fixed::types::U8F24;
+
+use crate::wavetable::sine_wavetable::SINE_WAVETABLE;
+
+/// A sine at a whole multiple of the fundamental
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Harmonic {
+    pub amplitude: Q15,
+    /// Where the sine starts, where 256 is a whole cycle of the harmonic
+    pub phase: U8F24,
+}
+
+impl Format for Harmonic {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "Harmonic {{ amplitude: {}, phase: {} }}",
+            self.amplitude.to_bits(),
+            self.phase.to_bits()
+        )
+    }
+}
+
+impl Harmonic {
+    pub const SILENT: Self = Self::new(Q15::ZERO);
+
+    /// A harmonic in sine phase with the fundamental
+    pub const fn new(amplitude: Q15) -> Self {
+        Self {
+            amplitude,
+            phase: U8F24::ZERO,
+        }
+    }
+
+    pub const fn with_phase(amplitude: Q15, phase: U8F24) -> Self {
+        Self { amplitude, phase }
+    }
+}
+
+impl Default for Harmonic {
+    fn default() -> Self {
+        Self::SILENT
+    }
+}
+
+/// How the sum of the harmonics is brought into the Q15 range
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// The loudest sample is full scale, whatever the harmonics are
+    Peak,
+    /// Divides by the sum of the amplitudes, which can never clip. Adding a
+    /// harmonic makes the others quieter, like pulling out a drawbar on an
+    /// organ with a fixed output level.
+    Sum,
+}
+
+/// Builds a wavetable out of sines, drawbar style.
+///
+/// The first harmonic is the fundamental, the second one is an octave up, and so on.
+/// Harmonics from `N / 2` on don't fit in the table and are left out.
+pub fn additive_wavetable<const N: usize>(
+    harmonics: &[Harmonic],
+    normalization: Normalization,
+) -> [Q15; N] {
+    let mut sums = [0i64; N];
+
+    for (index, harmonic) in harmonics.iter().enumerate().take(N / 2) {
+        if harmonic.amplitude == Q15::ZERO {
+            continue;
+        }
+
+        let number = index as u64 + 1;
+        let amplitude = harmonic.amplitude.to_bits() as i64;
+
+        for (sample, sum) in sums.iter_mut().enumerate() {
+            // Phase of the harmonic at this sample, where 2^32 is a whole cycle
+            let cycle_position = ((number * sample as u64) % N as u64) << 32;
+            let phase = ((cycle_position / N as u64) as u32).wrapping_add(harmonic.phase.to_bits());
+
+            *sum += amplitude * read_sine(phase) as i64;
+        }
+    }
+
+    // Every sum is in Q30, so scaling the peak to full scale or dividing by the
+    // Q15 sum of the amplitudes leaves Q15 samples
+    let (scale, divisor) = match normalization {
+        Normalization::Peak => (
+            i16::MAX as i64,
+            sums.iter().map(|sum| sum.abs()).max().unwrap_or(0),
+        ),
+        Normalization::Sum => (
+            1,
+            harmonics
+                .iter()
+                .take(N / 2)
+                .map(|harmonic| (harmonic.amplitude.to_bits() as i64).abs())
+                .sum(),
+        ),
+    };
+
+    if divisor == 0 {
+        return [Q15::ZERO; N];
+    }
+
+    sums.map(|sum| {
+        let sample = sum * scale / divisor;
+        Q15::from_bits(sample.clamp(i16::MIN as i64, i16::MAX as i64) as i16)
+    })
+}
+
+/// Reads the sine wavetable with linear interpolation, where 2^32 is a whole cycle
+fn read_sine(phase: u32) -> i32 {
+    let index = (phase >> 24) as u8;
+    let fraction = ((phase >> 9) & 0x7FFF) as i32;
+
+    let current = SINE_WAVETABLE[index as usize].to_bits() as i32;
+    let next = SINE_WAVETABLE[index.wrapping_add(1) as usize].to_bits() as i32;
+
+    current + (((next - current) * fraction) >> 15)
+}
@@ -1,6 +1,7 @@
 use defmt::Format;
 
-mod phase_increment_table;
+pub mod additive;
+pub(crate) mod phase_increment_table;
 pub mod saw_wavetable;
 pub mod sine_wavetable;
 pub mod square_wavetable;
@@ -12,37 +13,71 @@ mod tests;
 use cmsis_interface::{CmsisOperations, Q15};
 use fixed::types::U8F24;
 
-use crate::Note;
-use phase_increment_table::MIDI_TO_PHASE_INCREMENT;
+use crate::{
+    Note,
+    rng::Rng,
+    tuning::{PitchOffset, Tuning},
+    wavetable::{sine_wavetable::SINE_WAVETABLE, square_wavetable::SQUARE_WAVETABLE},
+};
+
+/// A full scale phase modulation input moves the phase by a whole cycle
+const PHASE_MODULATION_SHIFT: u32 = 17;
+
+/// Narrowest pulse, as a fraction of the cycle. Anything thinner is barely audible.
+pub const MIN_PULSE_WIDTH: Q15 = Q15::lit("0.03125");
+/// Widest pulse, as a fraction of the cycle
+pub const MAX_PULSE_WIDTH: Q15 = Q15::lit("0.96875");
+
+/// Length of the crossfade that smooths out hard sync resets
+pub const SYNC_FADE_SAMPLES: u8 = 8;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Wavetable<'a>(pub &'a [Q15; 256]);
 
-#[derive(Debug, Clone, Copy)]
-pub struct WavetableOscillator<'a, const SAMPLE_RATE: u32> {
-    phase: U8F24,
-    phase_increment: U8F24,
-    wavetable: Wavetable<'a>,
+/// How the wavetable is read between two of its samples
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Straight line between the two closest samples
+    Linear,
+    /// Catmull-Rom spline through the four closest samples. Costs a bit more,
+    /// but smooth wavetables come out with less distortion.
+    Hermite,
 }
 
-impl<'a, const SAMPLE_RATE: u32> Format for WavetableOscillator<'a, SAMPLE_RATE> {
-    fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "WavetableOscillator")
+impl Interpolation {
+    /// Picked at compile time with the `hermite-interpolation` feature
+    #[cfg(not(feature = "hermite-interpolation"))]
+    pub const DEFAULT: Self = Self::Linear;
+    /// Picked at compile time with the `hermite-interpolation` feature
+    #[cfg(feature = "hermite-interpolation")]
+    pub const DEFAULT: Self = Self::Hermite;
+}
+
+impl Default for Interpolation {
+    fn default() -> Self {
+        Self::DEFAULT
     }
 }
 
-impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
-    pub fn new(wavetable: &'a [Q15; 256]) -> Self {
-        Self {
-            phase: U8F24::ZERO,
-            phase_increment: U8F24::ZERO,
-            wavetable: Wavetable(wavetable),
+impl<'a> Wavetable<'a> {
+    /// Reads the wavetable at every phase in `phases`
+    pub(crate) fn interpolate<T: CmsisOperations, const LEN: usize>(
+        &self,
+        interpolation: Interpolation,
+        phases: &[U8F24; LEN],
+        buffer: &mut [Q15; LEN],
+    ) {
+        match interpolation {
+            Interpolation::Linear => self.interpolate_linear::<T, LEN>(phases, buffer),
+            Interpolation::Hermite => self.interpolate_hermite::<T, LEN>(phases, buffer),
         }
     }
-}
 
-impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
-    pub fn get_samples<T: CmsisOperations, const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+    fn interpolate_linear<T: CmsisOperations, const LEN: usize>(
+        &self,
+        phases: &[U8F24; LEN],
+        buffer: &mut [Q15; LEN],
+    ) {
         /*
          * We're gonna use SIMD to calculate for efficienty
          * So we'll be collecting things into arrays first
@@ -56,15 +91,16 @@ impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
         let mut weight_current = [Q15::ZERO; LEN];
         let mut weight_next = [Q15::ZERO; LEN];
 
-        for (s_current, (s_next, w_next)) in sample_current
-            .iter_mut()
-            .zip(sample_next.iter_mut().zip(weight_next.iter_mut()))
-        {
+        for (phase, (s_current, (s_next, w_next))) in phases.iter().zip(
+            sample_current
+                .iter_mut()
+                .zip(sample_next.iter_mut().zip(weight_next.iter_mut())),
+        ) {
             // Use the integer part (first 8 bits) as the index
-            let index: u8 = self.phase.to_num();
+            let index: u8 = phase.to_num();
 
-            *s_current = self.wavetable.0[index as usize];
-            *s_next = self.wavetable.0[index.wrapping_add(1) as usize];
+            *s_current = self.0[index as usize];
+            *s_next = self.0[index.wrapping_add(1) as usize];
 
             /*
              * We're gonna convert the fractional part of the phase into Q15
@@ -91,13 +127,11 @@ impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
              * and AND with 0x7FFF.
              */
 
-            let bits = self.phase.to_bits();
+            let bits = phase.to_bits();
             let weight_aligned = bits.unbounded_shr(9) as u16;
             let weight_masked = weight_aligned & 0x7FFF;
 
             *w_next = Q15::from_bits(weight_masked as i16);
-
-            self.phase = self.phase.wrapping_add(self.phase_increment);
         }
 
         // w_current = MAX - w_next
@@ -120,9 +154,402 @@ impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
         T::add_q15(&sample_current, &sample_next, buffer);
     }
 
-    pub fn set_note(&mut self, note: &Note) {
-        self.phase = U8F24::ZERO;
-        self.phase_increment = MIDI_TO_PHASE_INCREMENT[note.as_u8() as usize]
+    fn interpolate_hermite<T: CmsisOperations, const LEN: usize>(
+        &self,
+        phases: &[U8F24; LEN],
+        buffer: &mut [Q15; LEN],
+    ) {
+        /*
+         * The spline through y0, y1, y2 and y3, between y1 and y2, is
+         *
+         *   c0 = y1
+         *   c1 = (y2 - y0) / 2
+         *   c2 = y0 - 5 / 2 * y1 + 2 * y2 - y3 / 2
+         *   c3 = (y3 - y0) / 2 + 3 / 2 * (y1 - y2)
+         *
+         *   output = ((c3 * t + c2) * t + c1) * t + c0
+         *
+         * The coefficients are worked out per sample with integers while
+         * collecting the samples, and the polynomial is evaluated with SIMD.
+         *
+         * On smooth wavetables the coefficients are tiny, but around the jumps
+         * of the saw and square they can go past 1 and saturate. That only
+         * changes the shape of the overshoot right at the jump.
+         */
+
+        let mut c0 = [Q15::ZERO; LEN];
+        let mut c1 = [Q15::ZERO; LEN];
+        let mut c2 = [Q15::ZERO; LEN];
+        let mut c3 = [Q15::ZERO; LEN];
+        let mut t = [Q15::ZERO; LEN];
+
+        for (i, phase) in phases.iter().enumerate() {
+            let index: u8 = phase.to_num();
+            let y0 = self.0[index.wrapping_sub(1) as usize].to_bits() as i32;
+            let y1 = self.0[index as usize].to_bits() as i32;
+            let y2 = self.0[index.wrapping_add(1) as usize].to_bits() as i32;
+            let y3 = self.0[index.wrapping_add(2) as usize].to_bits() as i32;
+
+            let saturate =
+                |bits: i32| Q15::from_bits(bits.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+
+            c0[i] = Q15::from_bits(y1 as i16);
+            c1[i] = saturate((y2 - y0) / 2);
+            c2[i] = saturate((2 * y0 - 5 * y1 + 4 * y2 - y3) / 2);
+            c3[i] = saturate((y3 - y0 + 3 * (y1 - y2)) / 2);
+
+            // Same fractional part as the weight of the linear interpolation
+            t[i] = Q15::from_bits((phase.to_bits().unbounded_shr(9) as u16 & 0x7FFF) as i16);
+        }
+
+        let mut acc = [Q15::ZERO; LEN];
+
+        T::multiply_q15(&c3, &t, &mut acc);
+        T::add_q15(&acc.clone(), &c2, &mut acc);
+        T::multiply_q15(&acc.clone(), &t, &mut acc);
+        T::add_q15(&acc.clone(), &c1, &mut acc);
+        T::multiply_q15(&acc.clone(), &t, &mut acc);
+        T::add_q15(&acc, &c0, buffer);
+    }
+}
+
+/// Where the phase of an oscillator starts when a note is played
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhaseMode {
+    /// Every note starts at the beginning of the cycle, so every attack sounds the same
+    Reset,
+    /// The phase carries on from wherever the previous note left it
+    FreeRunning,
+    /// Every note starts at a random phase, so layered notes don't sum in phase
+    Random,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WavetableOscillator<'a, const SAMPLE_RATE: u32> {
+    phase: U8F24,
+    phase_increment: U8F24,
+    /// Phase increment of the note, before the drift
+    tuned_phase_increment: U8F24,
+    pitch_offset: PitchOffset,
+    /// Multiplies the frequency of the note, after the pitch offset
+    frequency_ratio: U8F24,
+    /// Multiplies the frequency of the note, changing slowly while it plays
+    drift: U8F24,
+    /// Times the phase wrapped since the note started, so that a [`SubOscillator`]
+    /// can run at a fraction of the frequency off the same phase
+    cycle: u8,
+    wavetable: Wavetable<'a>,
+    /// Phase the oscillator would have had without the last hard sync reset
+    sync_fade_phase: U8F24,
+    /// Samples left of the crossfade out of `sync_fade_phase`
+    sync_fade_remaining: u8,
+    /// When set, the wavetable is read twice this fraction of a cycle apart and the
+    /// readings are subtracted, which turns a saw into a pulse with this duty cycle
+    pulse_width: Option<Q15>,
+    /// Pulse width at the end of the next buffer, so that changes ramp in without clicks
+    target_pulse_width: Q15,
+    phase_mode: PhaseMode,
+    /// Picks the starting phases in [`PhaseMode::Random`]
+    phase_rng: Rng,
+    interpolation: Interpolation,
+}
+
+impl<'a, const SAMPLE_RATE: u32> Format for WavetableOscillator<'a, SAMPLE_RATE> {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "WavetableOscillator")
+    }
+}
+
+impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
+    pub fn new(wavetable: &'a [Q15; 256]) -> Self {
+        Self {
+            phase: U8F24::ZERO,
+            phase_increment: U8F24::ZERO,
+            tuned_phase_increment: U8F24::ZERO,
+            pitch_offset: PitchOffset::UNISON,
+            frequency_ratio: U8F24::ONE,
+            drift: U8F24::ONE,
+            cycle: 0,
+            wavetable: Wavetable(wavetable),
+            sync_fade_phase: U8F24::ZERO,
+            sync_fade_remaining: 0,
+            pulse_width: None,
+            target_pulse_width: Q15::ZERO,
+            phase_mode: PhaseMode::Reset,
+            phase_rng: Rng::default(),
+            interpolation: Interpolation::DEFAULT,
+        }
+    }
+}
+
+impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
+    pub fn get_samples<T: CmsisOperations, const LEN: usize>(&mut self, buffer: &mut [Q15; LEN]) {
+        let mut phases = [U8F24::ZERO; LEN];
+
+        for phase in phases.iter_mut() {
+            *phase = self.phase;
+            self.advance();
+        }
+
+        let pulse_widths = self.next_pulse_widths::<LEN>();
+        self.read::<T, LEN>(&phases, pulse_widths.as_ref(), buffer);
+    }
+
+    /// Like [`Self::get_samples`], but also marks in `sync_out` the samples after which the
+    /// phase wrapped, so that this oscillator can act as the master of a hard sync
+    pub fn get_samples_with_sync_out<T: CmsisOperations, const LEN: usize>(
+        &mut self,
+        buffer: &mut [Q15; LEN],
+        sync_out: &mut [bool; LEN],
+    ) {
+        let mut phases = [U8F24::ZERO; LEN];
+
+        for (phase, wrapped) in phases.iter_mut().zip(sync_out.iter_mut()) {
+            *phase = self.phase;
+            self.advance();
+            *wrapped = self.phase < *phase;
+        }
+
+        let pulse_widths = self.next_pulse_widths::<LEN>();
+        self.read::<T, LEN>(&phases, pulse_widths.as_ref(), buffer);
+    }
+
+    /// Like [`Self::get_samples`], but resets the phase after every sample marked in `sync_in`.
+    ///
+    /// Resetting the phase makes a step in the output, so for [`SYNC_FADE_SAMPLES`] after
+    /// every reset the output crossfades from where the oscillator would have been
+    /// without the reset.
+    pub fn get_synced_samples<T: CmsisOperations, const LEN: usize>(
+        &mut self,
+        buffer: &mut [Q15; LEN],
+        sync_in: &[bool; LEN],
+    ) {
+        let mut phases = [U8F24::ZERO; LEN];
+        let mut fade_phases = [U8F24::ZERO; LEN];
+        let mut fade_weights = [Q15::ZERO; LEN];
+        let mut fading = false;
+
+        for ((phase, sync), (fade_phase, fade_weight)) in phases
+            .iter_mut()
+            .zip(sync_in.iter())
+            .zip(fade_phases.iter_mut().zip(fade_weights.iter_mut()))
+        {
+            *phase = self.phase;
+
+            if self.sync_fade_remaining > 0 {
+                *fade_phase = self.sync_fade_phase;
+                *fade_weight = Q15::from_bits(
+                    (self.sync_fade_remaining as i32 * i16::MAX as i32
+                        / (SYNC_FADE_SAMPLES as i32 + 1)) as i16,
+                );
+                fading = true;
+
+                self.sync_fade_phase = self.sync_fade_phase.wrapping_add(self.phase_increment);
+                self.sync_fade_remaining -= 1;
+            }
+
+            if *sync {
+                self.sync_fade_phase = self.phase.wrapping_add(self.phase_increment);
+                self.sync_fade_remaining = SYNC_FADE_SAMPLES;
+                self.phase = U8F24::ZERO;
+                self.cycle = 0;
+            } else {
+                self.advance();
+            }
+        }
+
+        let pulse_widths = self.next_pulse_widths::<LEN>();
+        self.read::<T, LEN>(&phases, pulse_widths.as_ref(), buffer);
+
+        if !fading {
+            return;
+        }
+
+        let mut fade_buf = [Q15::ZERO; LEN];
+        let mut remaining_weights = [Q15::ZERO; LEN];
+        self.read::<T, LEN>(&fade_phases, pulse_widths.as_ref(), &mut fade_buf);
+
+        // output = output * (MAX - weight) + fade * weight
+        T::negate_q15(&fade_weights, &mut remaining_weights);
+        T::add_q15(
+            &remaining_weights.clone(),
+            &[Q15::MAX; LEN],
+            &mut remaining_weights,
+        );
+        T::multiply_q15(&buffer.clone(), &remaining_weights, buffer);
+        T::multiply_q15(&fade_buf.clone(), &fade_weights, &mut fade_buf);
+        T::add_q15(&buffer.clone(), &fade_buf, buffer);
+    }
+
+    /// Like [`Self::get_samples`], but offsets the phase of every sample by the sum of
+    /// `modulators`.
+    ///
+    /// A modulation of 1 moves the phase by a whole cycle. This is the "FM" of FM synthesis.
+    pub fn get_phase_modulated_samples<T: CmsisOperations, const LEN: usize>(
+        &mut self,
+        buffer: &mut [Q15; LEN],
+        modulators: &[&[Q15; LEN]],
+    ) {
+        let mut phases = [U8F24::ZERO; LEN];
+
+        for (i, phase) in phases.iter_mut().enumerate() {
+            /*
+             * The phase wraps around, so the sign extended modulation can be
+             * shifted into place and added with wrapping arithmetic. That also
+             * means that several modulators can't saturate.
+             */
+            let offset = modulators.iter().fold(0u32, |offset, modulator| {
+                let modulation = modulator[i].to_bits() as i32 as u32;
+                offset.wrapping_add(modulation.wrapping_shl(PHASE_MODULATION_SHIFT))
+            });
+            *phase = U8F24::from_bits(self.phase.to_bits().wrapping_add(offset));
+
+            self.advance();
+        }
+
+        let pulse_widths = self.next_pulse_widths::<LEN>();
+        self.read::<T, LEN>(&phases, pulse_widths.as_ref(), buffer);
+    }
+
+    fn advance(&mut self) {
+        let next = self.phase.wrapping_add(self.phase_increment);
+        if next < self.phase {
+            self.cycle = self.cycle.wrapping_add(1);
+        }
+        self.phase = next;
+    }
+
+    /// Pulse width of every sample of the next buffer, ramping towards the target
+    fn next_pulse_widths<const LEN: usize>(&mut self) -> Option<[Q15; LEN]> {
+        let start = self.pulse_width?;
+        let step = (self.target_pulse_width.to_bits() as i32 - start.to_bits() as i32) / LEN as i32;
+
+        let widths = core::array::from_fn(|i| {
+            Q15::from_bits((start.to_bits() as i32 + step * (i as i32 + 1)) as i16)
+        });
+
+        self.pulse_width = Some(self.target_pulse_width);
+
+        Some(widths)
+    }
+
+    /// Reads the wavetable at every phase in `phases`, or the pulse made from it if
+    /// there are `pulse_widths`
+    fn read<T: CmsisOperations, const LEN: usize>(
+        &self,
+        phases: &[U8F24; LEN],
+        pulse_widths: Option<&[Q15; LEN]>,
+        buffer: &mut [Q15; LEN],
+    ) {
+        let Some(pulse_widths) = pulse_widths else {
+            self.wavetable
+                .interpolate::<T, LEN>(self.interpolation, phases, buffer);
+            return;
+        };
+
+        /*
+         * With a saw wavetable going from -1 to 1, saw(phase) - saw(phase + width)
+         * is -2 * width for most of the cycle, and 2 - 2 * width for the last
+         * `width` of it, after the second reading wraps around. That's a pulse
+         * whose average is always 0, whatever its width.
+         *
+         * Both readings are halved first so that the difference can't clip,
+         * which puts the pulse between -width and 1 - width.
+         */
+        let mut offset_phases = [U8F24::ZERO; LEN];
+        for ((offset_phase, phase), width) in offset_phases
+            .iter_mut()
+            .zip(phases.iter())
+            .zip(pulse_widths.iter())
+        {
+            let offset = (width.to_bits() as u32).wrapping_shl(PHASE_MODULATION_SHIFT);
+            *offset_phase = U8F24::from_bits(phase.to_bits().wrapping_add(offset));
+        }
+
+        let mut leading = [Q15::ZERO; LEN];
+        let mut trailing = [Q15::ZERO; LEN];
+        self.wavetable
+            .interpolate::<T, LEN>(self.interpolation, phases, &mut leading);
+        self.wavetable
+            .interpolate::<T, LEN>(self.interpolation, &offset_phases, &mut trailing);
+
+        T::shift_in_place_q15(&mut leading, -1);
+        T::shift_in_place_q15(&mut trailing, -1);
+        T::negate_in_place_q15(&mut trailing);
+        T::add_q15(&leading, &trailing, buffer);
+    }
+
+    /// Starts a note, with the phase set by the [`PhaseMode`]
+    pub fn set_note(&mut self, note: &Note, tuning: &Tuning) {
+        match self.phase_mode {
+            PhaseMode::Reset => {
+                self.phase = U8F24::ZERO;
+                self.cycle = 0;
+            }
+            PhaseMode::FreeRunning => {}
+            PhaseMode::Random => {
+                self.phase = U8F24::from_bits(self.phase_rng.next_u32());
+                self.cycle = 0;
+            }
+        }
+        self.sync_fade_remaining = 0;
+        self.retune(note, tuning);
+    }
+
+    /// Updates the pitch of the current note without touching the phase
+    pub fn retune(&mut self, note: &Note, tuning: &Tuning) {
+        self.tuned_phase_increment = tuning
+            .phase_increment_with_offset(note, &self.pitch_offset)
+            .saturating_mul(self.frequency_ratio);
+        self.phase_increment = self.tuned_phase_increment.saturating_mul(self.drift);
+    }
+
+    /// Detunes the current note by `drift` times its frequency, and the ones after it too.
+    /// Unlike the frequency ratio it takes effect right away, so it can be moved every buffer.
+    pub fn set_drift(&mut self, drift: U8F24) {
+        self.drift = drift;
+        self.phase_increment = self.tuned_phase_increment.saturating_mul(drift);
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Takes effect on the next [`Self::set_note`]
+    pub fn set_phase_mode(&mut self, phase_mode: PhaseMode) {
+        self.phase_mode = phase_mode;
+    }
+
+    /// Restarts the sequence of random phases used by [`PhaseMode::Random`]
+    pub fn set_phase_seed(&mut self, seed: u32) {
+        self.phase_rng = Rng::new(seed);
+    }
+
+    /// Takes effect on the next [`Self::set_note`] or [`Self::retune`]
+    pub fn set_frequency_ratio(&mut self, frequency_ratio: U8F24) {
+        self.frequency_ratio = frequency_ratio;
+    }
+
+    /// Takes effect on the next [`Self::set_note`] or [`Self::retune`]
+    pub fn set_pitch_offset(&mut self, pitch_offset: PitchOffset) {
+        self.pitch_offset = pitch_offset;
+    }
+
+    /// Turns the oscillator into a pulse oscillator, or back into a plain wavetable
+    /// oscillator with `None`. The pulse is only a pulse with a saw wavetable.
+    ///
+    /// The width is clamped between [`MIN_PULSE_WIDTH`] and [`MAX_PULSE_WIDTH`].
+    /// Changes while the oscillator is already a pulse ramp in over the next buffer,
+    /// so the width can be modulated by calling this once per buffer.
+    pub fn set_pulse_width(&mut self, pulse_width: Option<Q15>) {
+        match pulse_width {
+            Some(width) => {
+                let width = width.clamp(MIN_PULSE_WIDTH, MAX_PULSE_WIDTH);
+                self.target_pulse_width = width;
+                self.pulse_width.get_or_insert(width);
+            }
+            None => self.pulse_width = None,
+        }
     }
 
     pub fn set_wavetable(&mut self, wavetable: &'a [Q15; 256]) {
@@ -130,3 +557,58 @@ impl<'a, const SAMPLE_RATE: u32> WavetableOscillator<'a, SAMPLE_RATE> {
         // Phase preserved - smooth transition without clicks
     }
 }
+
+/// Waveform of a [`SubOscillator`]
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubOscillatorShape {
+    Square,
+    Sine,
+}
+
+/// An oscillator one or more octaves below a [`WavetableOscillator`], that runs off
+/// its phase instead of having its own.
+///
+/// Since it has no phase of its own it can't drift away from the main oscillator:
+/// it restarts with it on [`WavetableOscillator::set_note`] and follows any change
+/// of its pitch.
+#[derive(Format, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubOscillator {
+    pub shape: SubOscillatorShape,
+    pub octaves: u8,
+}
+
+impl SubOscillator {
+    pub fn new(shape: SubOscillatorShape, octaves: u8) -> Self {
+        Self { shape, octaves }
+    }
+
+    /// Renders the samples for the phases `main` will go through on its next call.
+    ///
+    /// Has to be called before rendering `main` for the window.
+    pub fn get_samples<T: CmsisOperations, const LEN: usize, const SAMPLE_RATE: u32>(
+        &self,
+        main: &WavetableOscillator<'_, SAMPLE_RATE>,
+        buffer: &mut [Q15; LEN],
+    ) {
+        let wavetable = match self.shape {
+            SubOscillatorShape::Square => Wavetable(&SQUARE_WAVETABLE),
+            SubOscillatorShape::Sine => Wavetable(&SINE_WAVETABLE),
+        };
+
+        /*
+         * Counting the cycles on top of the phase gives a phase with extra
+         * integer bits, that divided by 2^octaves is the phase of the sub
+         * oscillator. Only the lowest bits of the count matter, so it's fine
+         * that it wraps.
+         */
+        let mut extended_phase = ((main.cycle as u64) << 32) | main.phase.to_bits() as u64;
+        let mut phases = [U8F24::ZERO; LEN];
+
+        for phase in phases.iter_mut() {
+            *phase = U8F24::from_bits((extended_phase >> self.octaves) as u32);
+            extended_phase += main.phase_increment.to_bits() as u64;
+        }
+
+        wavetable.interpolate::<T, LEN>(main.interpolation, &phases, buffer);
+    }
+}
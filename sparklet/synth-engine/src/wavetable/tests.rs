@@ -1,4 +1,6 @@
 use super::*;
+use additive::{Harmonic, Normalization, additive_wavetable};
+use phase_increment_table::MIDI_TO_PHASE_INCREMENT;
 use saw_wavetable::SAW_WAVETABLE;
 use sine_wavetable::SINE_WAVETABLE;
 use square_wavetable::SQUARE_WAVETABLE;
@@ -63,7 +65,19 @@ mod utils {
         WavetableOscillator {
             phase: U8F24::ZERO,
             phase_increment: MIDI_TO_PHASE_INCREMENT[note.as_u8() as usize],
+            tuned_phase_increment: MIDI_TO_PHASE_INCREMENT[note.as_u8() as usize],
+            pitch_offset: PitchOffset::UNISON,
+            frequency_ratio: U8F24::ONE,
+            drift: U8F24::ONE,
+            cycle: 0,
             wavetable: Wavetable(wavetable),
+            sync_fade_phase: U8F24::ZERO,
+            sync_fade_remaining: 0,
+            pulse_width: None,
+            target_pulse_width: Q15::ZERO,
+            phase_mode: PhaseMode::Reset,
+            phase_rng: Rng::default(),
+            interpolation: Interpolation::Linear,
         }
     }
 }
@@ -94,7 +108,7 @@ fn test_phase_resets_on_set_note() {
     assert_ne!(osc.phase, U8F24::ZERO);
 
     // Set new note should reset phase
-    osc.set_note(&note2);
+    osc.set_note(&note2, &Tuning::default());
     assert_eq!(osc.phase, U8F24::ZERO);
 }
 
@@ -373,3 +387,600 @@ fn test_set_wavetable_preserves_phase() {
         max_expected_jump
     );
 }
+
+#[test]
+fn test_sync_out_marks_phase_wraps() {
+    let mut osc = utils::create_osc(&SINE_WAVETABLE, Note::new(69)); // A4, 440 Hz
+
+    let mut buffer = [Q15::ZERO; 4800];
+    let mut sync_out = [false; 4800];
+    osc.get_samples_with_sync_out::<TestOps, 4800>(&mut buffer, &mut sync_out);
+
+    // 0.1 seconds of 440 Hz
+    let wraps = sync_out.iter().filter(|&&wrapped| wrapped).count();
+    assert!(
+        (43..=45).contains(&wraps),
+        "Expected ~44 wraps, got {}",
+        wraps
+    );
+
+    // The output is the same as without sync
+    let mut plain_osc = utils::create_osc(&SINE_WAVETABLE, Note::new(69));
+    let mut plain_buffer = [Q15::ZERO; 4800];
+    plain_osc.get_samples::<TestOps, 4800>(&mut plain_buffer);
+    assert_eq!(buffer, plain_buffer);
+}
+
+#[test]
+fn test_synced_oscillator_follows_master_period() {
+    let mut master = utils::create_osc(&SINE_WAVETABLE, Note::new(57)); // A3
+    let mut slave = utils::create_osc(&SAW_WAVETABLE, Note::new(64)); // E4
+
+    let mut master_buffer = [Q15::ZERO; 1024];
+    let mut slave_buffer = [Q15::ZERO; 1024];
+    let mut sync = [false; 1024];
+    master.get_samples_with_sync_out::<TestOps, 1024>(&mut master_buffer, &mut sync);
+    slave.get_synced_samples::<TestOps, 1024>(&mut slave_buffer, &sync);
+
+    let resets: Vec<usize> = (0..1024).filter(|&i| sync[i]).collect();
+    let [first, second, ..] = resets[..] else {
+        panic!("Master should wrap at least twice");
+    };
+
+    // Once the crossfade is over, every master period starts the slave over the same way
+    let start = SYNC_FADE_SAMPLES as usize + 1;
+    for offset in start..(second - first) {
+        assert_eq!(
+            slave_buffer[first + offset],
+            slave_buffer[second + offset],
+            "Slave should repeat with the master period at offset {}",
+            offset
+        );
+    }
+}
+
+#[test]
+fn test_sync_reset_is_smoothed() {
+    // An octave and a tritone up the slave is far from the start of a cycle whenever
+    // the master wraps, so resetting it without smoothing would jump by ~0.85
+    let mut master = utils::create_osc(&SINE_WAVETABLE, Note::new(57));
+    let mut slave = utils::create_osc(&SINE_WAVETABLE, Note::new(57 + 12 + 6));
+
+    let mut master_buffer = [Q15::ZERO; 2048];
+    let mut slave_buffer = [Q15::ZERO; 2048];
+    let mut sync = [false; 2048];
+    master.get_samples_with_sync_out::<TestOps, 2048>(&mut master_buffer, &mut sync);
+    slave.get_synced_samples::<TestOps, 2048>(&mut slave_buffer, &sync);
+
+    assert!(sync.iter().any(|&wrapped| wrapped));
+    assert!(utils::all_in_range(&slave_buffer));
+    assert!(
+        utils::check_continuity(&slave_buffer, Q15::from_num(0.25)),
+        "Sync resets should be crossfaded"
+    );
+}
+
+/// Renders `windows` windows of a pulse from the saw wavetable
+fn render_pulse(
+    osc: &mut WavetableOscillator<'static, TEST_SAMPLE_RATE>,
+    windows: usize,
+) -> Vec<Q15> {
+    let mut samples = Vec::with_capacity(windows * 128);
+    for _ in 0..windows {
+        let mut buffer = [Q15::ZERO; 128];
+        osc.get_samples::<TestOps, 128>(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+    samples
+}
+
+#[test]
+fn test_pulse_duty_cycle_follows_width() {
+    for width in [0.1, 0.25, 0.5, 0.75, 0.9] {
+        let mut osc = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+        osc.set_pulse_width(Some(Q15::from_num(width)));
+
+        let samples = render_pulse(&mut osc, 64);
+        let high = samples.iter().filter(|&&s| s > Q15::ZERO).count();
+        let duty = high as f64 / samples.len() as f64;
+
+        assert!(
+            (duty - width).abs() < 0.02,
+            "Width {} should give that duty cycle, got {}",
+            width,
+            duty
+        );
+    }
+}
+
+#[test]
+fn test_pulse_is_dc_balanced() {
+    for width in [0.05, 0.25, 0.5, 0.75, 0.95] {
+        let mut osc = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+        osc.set_pulse_width(Some(Q15::from_num(width)));
+
+        let dc = utils::calculate_dc_offset(&render_pulse(&mut osc, 64));
+        assert!(
+            dc.abs() < 0.01,
+            "Pulse with width {} should have no DC offset, got {}",
+            width,
+            dc
+        );
+    }
+}
+
+#[test]
+fn test_half_width_pulse_is_a_square() {
+    let mut pulse = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+    pulse.set_pulse_width(Some(Q15::from_num(0.5)));
+    let mut square = utils::create_osc(&SQUARE_WAVETABLE, Note::new(60));
+
+    let pulse_samples = render_pulse(&mut pulse, 8);
+    let square_samples = render_pulse(&mut square, 8);
+
+    // Same zero crossings as the square, at half the amplitude
+    assert_eq!(
+        utils::count_zero_crossings(&pulse_samples),
+        utils::count_zero_crossings(&square_samples)
+    );
+    let max = utils::find_max_abs(&pulse_samples);
+    assert!(max > Q15::from_num(0.45) && max <= Q15::from_num(0.5));
+}
+
+#[test]
+fn test_pulse_width_changes_ramp_in() {
+    let mut osc = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+    osc.set_pulse_width(Some(Q15::from_num(0.1)));
+    render_pulse(&mut osc, 1);
+
+    osc.set_pulse_width(Some(Q15::from_num(0.9)));
+    let mut buffer = [Q15::ZERO; 128];
+    osc.get_samples::<TestOps, 128>(&mut buffer);
+
+    // The low level of the pulse is -width, so it should move gradually
+    let lows: Vec<Q15> = buffer.iter().copied().filter(|&s| s < Q15::ZERO).collect();
+    assert!(lows.first().unwrap() > &Q15::from_num(-0.2));
+    assert!(lows.windows(2).all(|w| w[1] <= w[0]));
+
+    // And it should have reached the new width by the next buffer, except for the
+    // samples interpolated across the edges of the pulse
+    let off_target = render_pulse(&mut osc, 2)
+        .into_iter()
+        .filter(|&s| s < Q15::ZERO && (s - Q15::from_num(-0.9)).abs() > Q15::from_num(0.02))
+        .count();
+    assert!(off_target <= 4, "Got {} samples off the target", off_target);
+}
+
+#[test]
+fn test_pulse_width_is_clamped() {
+    let mut osc = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+    osc.set_pulse_width(Some(Q15::ZERO));
+
+    let samples = render_pulse(&mut osc, 64);
+    assert!(
+        samples.iter().any(|&s| s > Q15::ZERO),
+        "A zero width pulse should be clamped instead of going silent"
+    );
+
+    osc.set_pulse_width(None);
+    let mut saw = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+    saw.phase = osc.phase;
+    assert_eq!(render_pulse(&mut osc, 1), render_pulse(&mut saw, 1));
+}
+
+/// Renders a window of `sub` and then of `main`, returning both
+fn render_with_sub(
+    main: &mut WavetableOscillator<'static, TEST_SAMPLE_RATE>,
+    sub: &SubOscillator,
+) -> ([Q15; 128], [Q15; 128], [bool; 128]) {
+    let mut main_buf = [Q15::ZERO; 128];
+    let mut sub_buf = [Q15::ZERO; 128];
+    let mut wrapped = [false; 128];
+    sub.get_samples::<TestOps, 128, TEST_SAMPLE_RATE>(main, &mut sub_buf);
+    main.get_samples_with_sync_out::<TestOps, 128>(&mut main_buf, &mut wrapped);
+    (main_buf, sub_buf, wrapped)
+}
+
+#[test]
+fn test_sub_oscillator_frequency() {
+    for octaves in [1, 2] {
+        let mut main = utils::create_osc(&SINE_WAVETABLE, Note::new(72));
+        let sub = SubOscillator::new(SubOscillatorShape::Sine, octaves);
+
+        let mut main_samples = Vec::new();
+        let mut sub_samples = Vec::new();
+        for _ in 0..64 {
+            let (main_buf, sub_buf, _) = render_with_sub(&mut main, &sub);
+            main_samples.extend_from_slice(&main_buf);
+            sub_samples.extend_from_slice(&sub_buf);
+        }
+
+        let ratio = utils::count_zero_crossings(&main_samples) as f64
+            / utils::count_zero_crossings(&sub_samples) as f64;
+        let expected = (1 << octaves) as f64;
+        assert!(
+            (ratio - expected).abs() < 0.1,
+            "Sub {} octaves down should be {} times slower, got {}",
+            octaves,
+            expected,
+            ratio
+        );
+    }
+}
+
+#[test]
+fn test_sub_oscillator_stays_in_phase() {
+    let mut main = utils::create_osc(&SAW_WAVETABLE, Note::new(72));
+    let sub = SubOscillator::new(SubOscillatorShape::Square, 1);
+    let tuning = Tuning::default();
+
+    let check_edges = |main: &mut WavetableOscillator<'static, TEST_SAMPLE_RATE>| {
+        let mut sub_samples = Vec::new();
+        let mut wraps = Vec::new();
+        for _ in 0..16 {
+            let (_, sub_buf, wrapped) = render_with_sub(main, &sub);
+            sub_samples.extend_from_slice(&sub_buf);
+            wraps.extend_from_slice(&wrapped);
+        }
+
+        // An octave down, the square flips every time the main oscillator wraps.
+        // Its edge is interpolated, so it can land a sample early.
+        let flips: Vec<usize> = sub_samples
+            .windows(2)
+            .enumerate()
+            .filter(|(_, w)| (w[0] > Q15::ZERO) != (w[1] > Q15::ZERO))
+            .map(|(i, _)| i)
+            .collect();
+        let wraps: Vec<usize> = wraps
+            .iter()
+            .enumerate()
+            .filter(|(_, wrapped)| **wrapped)
+            .map(|(i, _)| i)
+            .collect();
+
+        assert!(!wraps.is_empty());
+        assert_eq!(flips.len(), wraps.len());
+        for (flip, wrap) in flips.iter().zip(wraps.iter()) {
+            assert!(
+                flip.abs_diff(*wrap) <= 1,
+                "Sub oscillator flipped at {} but the main oscillator wrapped at {}",
+                flip,
+                wrap
+            );
+        }
+    };
+
+    main.set_note(&Note::new(72), &tuning);
+    check_edges(&mut main);
+
+    // Changing the pitch mid note, like a glide does, keeps them together
+    main.retune(&Note::new(79), &tuning);
+    check_edges(&mut main);
+
+    // And a new note restarts both
+    main.set_note(&Note::new(60), &tuning);
+    let mut fresh = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+    assert_eq!(
+        render_with_sub(&mut main, &sub),
+        render_with_sub(&mut fresh, &sub)
+    );
+}
+
+/// Plays `notes` one after the other, rendering a window of each
+fn render_notes(
+    osc: &mut WavetableOscillator<'static, TEST_SAMPLE_RATE>,
+    notes: &[u8],
+) -> Vec<[Q15; 128]> {
+    let tuning = Tuning::default();
+    notes
+        .iter()
+        .map(|&note| {
+            osc.set_note(&Note::new(note), &tuning);
+            let mut buffer = [Q15::ZERO; 128];
+            osc.get_samples::<TestOps, 128>(&mut buffer);
+            buffer
+        })
+        .collect()
+}
+
+fn create_osc_with_phase_mode(
+    phase_mode: PhaseMode,
+    seed: u32,
+) -> WavetableOscillator<'static, TEST_SAMPLE_RATE> {
+    let mut osc = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+    osc.set_phase_mode(phase_mode);
+    osc.set_phase_seed(seed);
+    osc
+}
+
+#[test]
+fn test_phase_mode_reset_restarts_every_note() {
+    let mut osc = create_osc_with_phase_mode(PhaseMode::Reset, 1);
+    let windows = render_notes(&mut osc, &[60, 60, 60]);
+
+    assert_eq!(windows[0], windows[1]);
+    assert_eq!(windows[1], windows[2]);
+}
+
+#[test]
+fn test_phase_mode_free_running_keeps_phase() {
+    let mut osc = create_osc_with_phase_mode(PhaseMode::FreeRunning, 1);
+    let windows = render_notes(&mut osc, &[60, 60]);
+
+    // The second note carries on from where the first one stopped
+    let mut continued = utils::create_osc(&SAW_WAVETABLE, Note::new(60));
+    let mut expected = [[Q15::ZERO; 128]; 2];
+    for window in expected.iter_mut() {
+        continued.get_samples::<TestOps, 128>(window);
+    }
+    assert_eq!(windows, expected);
+}
+
+#[test]
+fn test_phase_mode_random_is_deterministic_for_a_seed() {
+    let notes = [60, 60, 60, 67];
+
+    for phase_mode in [PhaseMode::Reset, PhaseMode::FreeRunning, PhaseMode::Random] {
+        let first = render_notes(&mut create_osc_with_phase_mode(phase_mode, 42), &notes);
+        let second = render_notes(&mut create_osc_with_phase_mode(phase_mode, 42), &notes);
+        assert_eq!(first, second, "{:?} should be deterministic", phase_mode);
+    }
+
+    let random = render_notes(
+        &mut create_osc_with_phase_mode(PhaseMode::Random, 42),
+        &notes,
+    );
+    let other_seed = render_notes(
+        &mut create_osc_with_phase_mode(PhaseMode::Random, 43),
+        &notes,
+    );
+    assert_ne!(
+        random, other_seed,
+        "Different seeds should give different phases"
+    );
+
+    // Every note starts somewhere else
+    assert_ne!(random[0], random[1]);
+    assert_ne!(random[1], random[2]);
+}
+
+/// Power of everything but the fundamental, relative to the fundamental, in dB.
+///
+/// The fundamental and DC are fitted with least squares at the exact frequency of
+/// the oscillator, so this counts harmonics, aliases and noise alike.
+fn thd_plus_noise_db(samples: &[Q15], cycles_per_sample: f64) -> f64 {
+    let basis = |n: usize| {
+        let angle = 2.0 * core::f64::consts::PI * cycles_per_sample * n as f64;
+        [1.0, angle.cos(), angle.sin()]
+    };
+
+    // Normal equations of the fit, solved with Gaussian elimination
+    let mut system = [[0.0; 4]; 3];
+    for (n, sample) in samples.iter().enumerate() {
+        let b = basis(n);
+        for row in 0..3 {
+            for column in 0..3 {
+                system[row][column] += b[row] * b[column];
+            }
+            system[row][3] += b[row] * sample.to_num::<f64>();
+        }
+    }
+    for pivot in 0..3 {
+        for row in (pivot + 1)..3 {
+            let factor = system[row][pivot] / system[pivot][pivot];
+            let pivot_row = system[pivot];
+            for (value, pivot_value) in system[row].iter_mut().zip(pivot_row.iter()).skip(pivot) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    let mut fit = [0.0; 3];
+    for row in (0..3).rev() {
+        let known: f64 = ((row + 1)..3).map(|c| system[row][c] * fit[c]).sum();
+        fit[row] = (system[row][3] - known) / system[row][row];
+    }
+
+    let mut residual_power = 0.0;
+    let mut fundamental_power = 0.0;
+    for (n, sample) in samples.iter().enumerate() {
+        let b = basis(n);
+        let fundamental = fit[1] * b[1] + fit[2] * b[2];
+        residual_power += (sample.to_num::<f64>() - fit[0] - fundamental).powi(2);
+        fundamental_power += fundamental.powi(2);
+    }
+
+    10.0 * (residual_power / fundamental_power).log10()
+}
+
+#[test]
+fn test_drift_scales_the_phase_increment() {
+    let tuning = Tuning::default();
+    let note = Note::new(69);
+    let base = MIDI_TO_PHASE_INCREMENT[note.as_u8() as usize];
+    let drift = U8F24::from_num(1.01);
+
+    let mut osc = utils::create_osc(&SINE_WAVETABLE, note);
+    osc.set_drift(drift);
+    assert_eq!(osc.phase_increment, base.saturating_mul(drift));
+
+    // It holds over new notes and pitch changes
+    osc.set_note(&Note::new(57), &tuning);
+    assert_eq!(
+        osc.phase_increment,
+        MIDI_TO_PHASE_INCREMENT[57].saturating_mul(drift)
+    );
+    osc.retune(&note, &tuning);
+    assert_eq!(osc.phase_increment, base.saturating_mul(drift));
+
+    osc.set_drift(U8F24::ONE);
+    assert_eq!(osc.phase_increment, base);
+}
+
+#[test]
+fn test_hermite_interpolation_lowers_distortion() {
+    for note in [24, 36, 48, 60, 72, 84, 96, 108] {
+        let cycles_per_sample = MIDI_TO_PHASE_INCREMENT[note as usize].to_num::<f64>() / 256.0;
+
+        let thd = |interpolation| {
+            let mut osc = utils::create_osc(&SINE_WAVETABLE, Note::new(note));
+            osc.set_interpolation(interpolation);
+
+            let mut samples = Vec::new();
+            for _ in 0..64 {
+                let mut buffer = [Q15::ZERO; 128];
+                osc.get_samples::<TestOps, 128>(&mut buffer);
+                samples.extend_from_slice(&buffer);
+            }
+            thd_plus_noise_db(&samples, cycles_per_sample)
+        };
+
+        let linear = thd(Interpolation::Linear);
+        let hermite = thd(Interpolation::Hermite);
+
+        // A 256 sample sine is already close to the limits of Q15 with linear
+        // interpolation, so there isn't much room left to improve
+        assert!(
+            hermite < linear - 1.0,
+            "Hermite should distort less than linear on note {}, got {:.1} dB vs {:.1} dB",
+            note,
+            hermite,
+            linear
+        );
+    }
+}
+
+#[test]
+fn test_hermite_interpolation_passes_through_samples() {
+    for wavetable in [&SINE_WAVETABLE, &SAW_WAVETABLE, &SQUARE_WAVETABLE] {
+        let mut osc = utils::create_osc(wavetable, Note::new(60));
+        osc.set_interpolation(Interpolation::Hermite);
+        // One wavetable sample per output sample
+        osc.phase_increment = U8F24::ONE;
+
+        let mut buffer = [Q15::ZERO; 256];
+        osc.get_samples::<TestOps, 256>(&mut buffer);
+        assert_eq!(&buffer, wavetable);
+    }
+}
+
+// ===== Additive Wavetable Tests =====
+
+/// Magnitude and phase (in cycles) of `harmonic` in a single cycle table
+fn table_harmonic(table: &[Q15], harmonic: usize) -> (f64, f64) {
+    let (re, im) = table
+        .iter()
+        .enumerate()
+        .fold((0., 0.), |(re, im), (i, sample)| {
+            let angle = 2. * std::f64::consts::PI * (harmonic * i) as f64 / table.len() as f64;
+            let value = sample.to_num::<f64>();
+            (re + value * angle.cos(), im - value * angle.sin())
+        });
+
+    let magnitude = 2. * f64::hypot(re, im) / table.len() as f64;
+    // A sine starting at 0 has a phase of -90 degrees with respect to a cosine
+    let phase = (f64::atan2(im, re) / (2. * std::f64::consts::PI) + 0.25).rem_euclid(1.);
+    (magnitude, phase)
+}
+
+#[test]
+fn test_additive_single_harmonic_is_the_sine() {
+    for normalization in [Normalization::Peak, Normalization::Sum] {
+        let table: [Q15; 256] =
+            additive_wavetable(&[Harmonic::new(Q15::from_num(0.5))], normalization);
+
+        for (i, (built, sine)) in table.iter().zip(SINE_WAVETABLE.iter()).enumerate() {
+            assert!(
+                (built.to_bits() as i32 - sine.to_bits() as i32).abs() <= 1,
+                "{:?}, sample {}: expected {}, got {}",
+                normalization,
+                i,
+                sine,
+                built
+            );
+        }
+    }
+
+    // Smaller tables pick every few samples of the sine
+    let table: [Q15; 64] = additive_wavetable(&[Harmonic::new(Q15::MAX)], Normalization::Peak);
+    for (built, sine) in table.iter().zip(SINE_WAVETABLE.iter().step_by(4)) {
+        assert!((built.to_bits() as i32 - sine.to_bits() as i32).abs() <= 1);
+    }
+}
+
+#[test]
+fn test_additive_harmonics_have_their_amplitude_and_phase() {
+    let harmonics = [
+        Harmonic::new(Q15::from_num(0.5)),
+        Harmonic::SILENT,
+        Harmonic::with_phase(Q15::from_num(0.25), U8F24::from_num(64)),
+        Harmonic::new(Q15::from_num(0.125)),
+    ];
+    let table: [Q15; 256] = additive_wavetable(&harmonics, Normalization::Sum);
+
+    // The sum of the amplitudes is 0.875
+    let expected = [
+        (0.5 / 0.875, 0.),
+        (0., 0.),
+        (0.25 / 0.875, 0.25),
+        (0.125 / 0.875, 0.),
+        (0., 0.),
+    ];
+    for (index, (magnitude, phase)) in expected.iter().enumerate() {
+        let (actual_magnitude, actual_phase) = table_harmonic(&table, index + 1);
+
+        assert!(
+            (actual_magnitude - magnitude).abs() < 0.001,
+            "Harmonic {}: expected a magnitude of {}, got {}",
+            index + 1,
+            magnitude,
+            actual_magnitude
+        );
+        if *magnitude > 0. {
+            let error = (actual_phase - phase + 0.5).rem_euclid(1.) - 0.5;
+            assert!(
+                error.abs() < 0.001,
+                "Harmonic {}: expected a phase of {}, got {}",
+                index + 1,
+                phase,
+                actual_phase
+            );
+        }
+    }
+}
+
+#[test]
+fn test_additive_normalization() {
+    // Drawbar organ with every drawbar out
+    let harmonics = [Harmonic::new(Q15::MAX); 9];
+
+    let peak: [Q15; 256] = additive_wavetable(&harmonics, Normalization::Peak);
+    let max = peak.iter().map(|s| s.to_bits().saturating_abs()).max();
+    assert_eq!(max, Some(i16::MAX), "The peak should be full scale");
+
+    let sum: [Q15; 256] = additive_wavetable(&harmonics, Normalization::Sum);
+    let max = sum
+        .iter()
+        .map(|s| s.to_bits().saturating_abs())
+        .max()
+        .unwrap();
+    assert!(max < i16::MAX, "Summing can't reach full scale here");
+    assert!(max > i16::MAX / 4);
+
+    // Nothing to normalize
+    let silent: [Q15; 256] = additive_wavetable(&[Harmonic::SILENT; 4], Normalization::Peak);
+    assert_eq!(silent, [Q15::ZERO; 256]);
+}
+
+#[test]
+fn test_additive_skips_harmonics_past_nyquist() {
+    let mut harmonics = [Harmonic::SILENT; 40];
+    harmonics[0] = Harmonic::new(Q15::from_num(0.5));
+    harmonics[39] = Harmonic::new(Q15::MAX);
+
+    let small: [Q15; 64] = additive_wavetable(&harmonics, Normalization::Sum);
+    let only_fundamental: [Q15; 64] = additive_wavetable(&harmonics[..1], Normalization::Sum);
+    assert_eq!(small, only_fundamental);
+
+    let large: [Q15; 256] = additive_wavetable(&harmonics, Normalization::Sum);
+    assert!(table_harmonic(&large, 40).0 > 0.5);
+}
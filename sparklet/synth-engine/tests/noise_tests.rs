@@ -0,0 +1,138 @@
+use cmsis_rust::CmsisRustOperations as Ops;
+use synth_engine::{
+    Q15,
+    noise::{NoiseColor, NoiseOscillator},
+};
+
+const WINDOW_SIZE: usize = 128;
+const SEGMENT_SIZE: usize = 1024;
+const SEGMENT_AMOUNT: usize = 64;
+const SEED: u32 = 0xC0FF_EE00;
+
+/// Render `SEGMENT_SIZE * SEGMENT_AMOUNT` samples of noise
+fn render_noise(color: NoiseColor, seed: u32) -> Vec<Q15> {
+    let mut osc = NoiseOscillator::new(color, seed);
+    let mut samples = Vec::with_capacity(SEGMENT_SIZE * SEGMENT_AMOUNT);
+
+    while samples.len() < SEGMENT_SIZE * SEGMENT_AMOUNT {
+        let mut buffer = [Q15::ZERO; WINDOW_SIZE];
+        osc.get_samples::<Ops, WINDOW_SIZE>(&mut buffer);
+        samples.extend_from_slice(&buffer);
+    }
+
+    samples
+}
+
+/// Average power per DFT bin in each octave band, from bins 2-4 up to 256-512
+fn octave_band_powers(samples: &[Q15]) -> Vec<f64> {
+    let twiddles: Vec<(f64, f64)> = (0..SEGMENT_SIZE)
+        .map(|n| {
+            let angle = 2.0 * std::f64::consts::PI * n as f64 / SEGMENT_SIZE as f64;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+
+    let mut bin_powers = vec![0.0; SEGMENT_SIZE / 2];
+
+    for segment in samples.chunks(SEGMENT_SIZE) {
+        let segment: Vec<f64> = segment.iter().map(|s| s.to_num::<f64>()).collect();
+
+        for (k, power) in bin_powers.iter_mut().enumerate().skip(2) {
+            let (mut re, mut im) = (0.0, 0.0);
+            for (n, sample) in segment.iter().enumerate() {
+                let (cos, sin) = twiddles[(k * n) % SEGMENT_SIZE];
+                re += sample * cos;
+                im -= sample * sin;
+            }
+            *power += re * re + im * im;
+        }
+    }
+
+    let mut bands = Vec::new();
+    let mut low = 2;
+    while low < SEGMENT_SIZE / 2 {
+        let high = low * 2;
+        bands.push(bin_powers[low..high].iter().sum::<f64>() / (high - low) as f64);
+        low = high;
+    }
+
+    bands
+}
+
+/// Least squares slope of the band powers, in dB per octave
+fn slope_db_per_octave(band_powers: &[f64]) -> f64 {
+    let points: Vec<(f64, f64)> = band_powers
+        .iter()
+        .enumerate()
+        .map(|(octave, power)| (octave as f64, 10.0 * power.log10()))
+        .collect();
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let covariance: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    covariance / variance
+}
+
+#[test]
+fn test_white_noise_is_flat() {
+    let samples = render_noise(NoiseColor::White, SEED);
+    let slope = slope_db_per_octave(&octave_band_powers(&samples));
+
+    assert!(
+        slope.abs() < 0.5,
+        "White noise should be flat, got {:.2} dB/octave",
+        slope
+    );
+}
+
+#[test]
+fn test_pink_noise_falls_3db_per_octave() {
+    let samples = render_noise(NoiseColor::Pink, SEED);
+    let slope = slope_db_per_octave(&octave_band_powers(&samples));
+
+    assert!(
+        (-4.0..-2.0).contains(&slope),
+        "Pink noise should fall ~3 dB/octave, got {:.2} dB/octave",
+        slope
+    );
+}
+
+#[test]
+fn test_noise_is_deterministic_for_a_seed() {
+    for color in [NoiseColor::White, NoiseColor::Pink] {
+        assert_eq!(render_noise(color, SEED), render_noise(color, SEED));
+        assert_ne!(render_noise(color, SEED), render_noise(color, SEED + 1));
+    }
+}
+
+#[test]
+fn test_noise_uses_the_full_range() {
+    for color in [NoiseColor::White, NoiseColor::Pink] {
+        let samples = render_noise(color, SEED);
+
+        let max = samples.iter().max().unwrap();
+        let min = samples.iter().min().unwrap();
+        let mean = samples.iter().map(|s| s.to_num::<f64>()).sum::<f64>() / samples.len() as f64;
+
+        assert!(
+            *max > Q15::from_num(0.5) && *min < Q15::from_num(-0.5),
+            "{:?} noise should be loud, got range {} to {}",
+            color,
+            min,
+            max
+        );
+        assert!(
+            mean.abs() < 0.05,
+            "{:?} noise should have no DC offset, got {}",
+            color,
+            mean
+        );
+    }
+}
@@ -1,11 +1,26 @@
 use fixed::{traits::ToFixed, types::I1F31};
 
+/// Every sample the envelope moves by `level * coefficient + base`
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct BaseAndCoefficient {
     pub base: I1F31,
     pub coefficient: I1F31,
 }
 
+/// How a curve bends, as the engine stores it
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct CurveShape {
+    /// Natural log of how much further the asymptote is from the end of the stage than
+    /// from its start, 0 for a straight line
+    pub bend: f64,
+    /// Slope at the slow end of the stage, in full scales per stage length
+    pub slow: f64,
+}
+
+/// Curve index of a straight line. Lower indices bend towards the RC curve, and higher
+/// ones towards the RC curve mirrored in time.
+pub const LINEAR_CURVE: usize = 128;
+
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct DecayConfig {
     pub rate: f64,
@@ -41,6 +56,8 @@ pub fn get_base_and_coefficient<const SAMPLE_RATE: u32>(
     let coefficient = f64::exp(f64::ln(target_ratio / (1. + target_ratio)) / rate);
     let r#final = initial + (target - initial) * (1. + target_ratio);
     let base = r#final * (1. - coefficient);
+    // The level is added back every sample, so only the change is kept
+    let coefficient = coefficient - 1.;
 
     let base = base.to_fixed();
     let coefficient = coefficient.to_fixed();
@@ -92,7 +109,9 @@ pub fn iterate_envelope(
     let mut output = initial;
 
     for iteration in 1..iterations {
-        output = output.saturating_mul_add(coefficient, base);
+        output = output
+            .saturating_add(output.saturating_mul(coefficient))
+            .saturating_add(base);
         if output == I1F31::MAX {
             return (iteration + 1, I1F31::MAX);
         } else if output < I1F31::ZERO {
@@ -102,3 +121,55 @@ pub fn iterate_envelope(
 
     (iterations, output)
 }
+
+/// Index 0 is the RC curve that overshoots by `target_ratio`, and the bend goes down to a
+/// straight line at LINEAR_CURVE and back up to the mirrored RC curve at 255
+pub fn get_curve_shape(index: usize, target_ratio: f64) -> CurveShape {
+    let max_bend = f64::ln((1. + target_ratio) / target_ratio);
+    let bend = if index <= LINEAR_CURVE {
+        max_bend * (LINEAR_CURVE - index) as f64 / LINEAR_CURVE as f64
+    } else {
+        max_bend * (index - LINEAR_CURVE) as f64 / (255 - LINEAR_CURVE) as f64
+    };
+
+    // The slow end is target_ratio * bend from the asymptote, which tends to 1 when straight
+    let slow = if bend == 0. {
+        1.
+    } else {
+        bend / (f64::exp(bend) - 1.)
+    };
+
+    CurveShape { bend, slow }
+}
+
+/// Exact base and coefficient of a stage lasting `samples` over the full scale, rising
+/// from 0 to 1 or falling from 1 to 0
+pub fn get_curve_base_and_coefficient(
+    index: usize,
+    shape: CurveShape,
+    samples: u32,
+    rising: bool,
+) -> BaseAndCoefficient {
+    let x = shape.bend / samples as f64;
+    let fast = shape.slow + shape.bend;
+
+    // Towards an asymptote past the end, or away from one behind the start
+    let (coefficient, scale) = if index <= LINEAR_CURVE {
+        (f64::exp(-x) - 1., (1. - f64::exp(-x)) / x)
+    } else {
+        (f64::exp(x) - 1., (f64::exp(x) - 1.) / x)
+    };
+    let scale = if x == 0. { 1. } else { scale };
+
+    let slope = match (index <= LINEAR_CURVE, rising) {
+        (true, true) => fast,
+        (true, false) => -shape.slow,
+        (false, true) => shape.slow,
+        (false, false) => -fast,
+    };
+
+    BaseAndCoefficient {
+        base: (slope / samples as f64 * scale).to_fixed(),
+        coefficient: coefficient.to_fixed(),
+    }
+}
@@ -0,0 +1,61 @@
+use fixed::types::U8F24;
+use synth_engine::wavetable::additive::{Harmonic, Normalization, additive_wavetable};
+use table_generators::wavetable_utils::{print_wavetable, to_q15};
+
+const TABLE_SIZE: usize = 256;
+
+const USAGE: &str = "Usage: generate_additive_wavetable [--sum] NAME AMPLITUDE[:PHASE]...
+
+Builds a wavetable called NAME out of harmonics, starting from the fundamental.
+Amplitudes go from 0 to 1, and phases are fractions of a cycle of the harmonic.
+The loudest sample is full scale, unless --sum is given, which divides by the sum
+of the amplitudes instead.
+
+Example, a drawbar organ with the first three drawbars out:
+    generate_additive_wavetable ORGAN_WAVETABLE 1 1 0 1";
+
+fn parse_harmonic(arg: &str) -> Harmonic {
+    let (amplitude, phase) = arg.split_once(':').unwrap_or((arg, "0"));
+    let amplitude: f64 = amplitude.parse().expect(USAGE);
+    let phase: f64 = phase.parse().expect(USAGE);
+
+    Harmonic::with_phase(
+        to_q15(amplitude),
+        U8F24::from_num(phase.rem_euclid(1.0) * 256.0),
+    )
+}
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let normalization = if let Some(index) = args.iter().position(|arg| arg == "--sum") {
+        args.remove(index);
+        Normalization::Sum
+    } else {
+        Normalization::Peak
+    };
+
+    if args.len() < 2 {
+        eprintln!("{}", USAGE);
+        std::process::exit(1);
+    }
+
+    let name = &args[0];
+    let harmonics: Vec<Harmonic> = args[1..].iter().map(|arg| parse_harmonic(arg)).collect();
+
+    eprintln!("Generating additive wavetable:");
+    eprintln!("  NAME: {}", name);
+    eprintln!("  TABLE_SIZE: {}", TABLE_SIZE);
+    eprintln!("  HARMONICS: {}", harmonics.len());
+    eprintln!("  NORMALIZATION: {:?}", normalization);
+    eprintln!();
+
+    let samples: [_; TABLE_SIZE] = additive_wavetable(&harmonics, normalization);
+
+    print_wavetable(name, &samples);
+
+    let peak = samples.iter().map(|s| s.abs()).max().unwrap();
+    eprintln!();
+    eprintln!("Sanity checks:");
+    eprintln!("  Peak: {} (expected: ~1.0 unless using --sum)", peak);
+}
@@ -1,19 +1,17 @@
 use fixed::types::I1F31;
 use table_generators::adsr_utils::{
-    BaseAndCoefficient, DecayConfig, ParamConfig, TimeConfig, get_base_and_coefficient,
-    get_base_and_coefficient_for_index, get_time_for_index, iterate_envelope,
+    BaseAndCoefficient, CurveShape, DecayConfig, ParamConfig, TimeConfig, get_base_and_coefficient,
+    get_curve_base_and_coefficient, get_curve_shape, get_time_for_index, iterate_envelope,
 };
 
 const SAMPLE_RATE: u32 = 48000;
 const TABLE_SIZE: usize = 256;
 
 fn main() {
-    eprintln!("Generating attack base and coefficient table for:");
+    eprintln!("Generating envelope time and curve tables for:");
     eprintln!("  SAMPLE_RATE: {} Hz", SAMPLE_RATE);
     eprintln!("  TABLE_SIZE: {}", TABLE_SIZE);
 
-    let mut attack_table: [BaseAndCoefficient; TABLE_SIZE] = [Default::default(); TABLE_SIZE];
-
     let attack_config = ParamConfig {
         target_ratio: 0.1,
         initial: 0.,
@@ -26,9 +24,6 @@ fn main() {
         },
     };
 
-    let mut decay_release_table: [BaseAndCoefficient; TABLE_SIZE] =
-        [Default::default(); TABLE_SIZE];
-
     let decay_release_config = ParamConfig {
         target_ratio: 0.2,
         initial: 1.,
@@ -41,7 +36,20 @@ fn main() {
         },
     };
 
-    let mut sanity_check_data = vec![];
+    // Index 0 turns the stage off, so the first time is at index 1
+    let delay_time_config = TimeConfig {
+        rate: TABLE_SIZE as f64 - 2.,
+        ratio: -4.8,
+        initial: 0.001,
+        target: 2.,
+    };
+
+    let hold_time_config = TimeConfig {
+        rate: TABLE_SIZE as f64 - 2.,
+        ratio: -4.8,
+        initial: 0.001,
+        target: 5.,
+    };
 
     let quick_release_base_coefficient = get_base_and_coefficient::<SAMPLE_RATE>(DecayConfig {
         target_ratio: 2.,
@@ -50,116 +58,170 @@ fn main() {
         rate: 0.01 * SAMPLE_RATE as f64,
     });
 
-    for (index, (attack_base_coefficient, decay_release_base_coefficient)) in attack_table
-        .iter_mut()
-        .zip(decay_release_table.iter_mut())
-        .enumerate()
-    {
-        let attack_time = get_time_for_index::<SAMPLE_RATE>(index, attack_config.time_config);
-        let decay_release_time: f64 =
-            get_time_for_index::<SAMPLE_RATE>(index, decay_release_config.time_config);
-
-        *attack_base_coefficient =
-            get_base_and_coefficient_for_index::<SAMPLE_RATE>(index, attack_config);
-        *decay_release_base_coefficient =
-            get_base_and_coefficient_for_index::<SAMPLE_RATE>(index, decay_release_config);
-
-        // Collect sanity check data for selected indices
-        if index <= 3 || (126..=128).contains(&index) || 253 <= index {
-            let attack_iterations = (attack_time * SAMPLE_RATE as f64 * 1.5) as usize;
-            let decay_release_iterations = (decay_release_time * SAMPLE_RATE as f64 * 1.5) as usize;
-
-            let (attack_iterations, attack_final) = iterate_envelope(
-                attack_base_coefficient.base,
-                attack_base_coefficient.coefficient,
-                I1F31::ZERO,
-                attack_iterations,
-            );
-
-            let (decay_release_iterations, decay_release_final) = iterate_envelope(
-                decay_release_base_coefficient.base,
-                decay_release_base_coefficient.coefficient,
-                I1F31::MAX,
-                decay_release_iterations,
-            );
-
-            sanity_check_data.push((
-                index,
-                attack_time,
-                attack_iterations as f64 / SAMPLE_RATE as f64,
-                attack_final,
-                decay_release_iterations as f64 / SAMPLE_RATE as f64,
-                decay_release_time,
-                decay_release_final,
-            ));
-        }
-    }
+    let rise_time_table = time_table(attack_config.time_config);
+    let fall_time_table = time_table(decay_release_config.time_config);
+    let rise_curve_table = curve_table(attack_config.target_ratio);
+    let fall_curve_table = curve_table(decay_release_config.target_ratio);
+    let delay_table = stage_time_table(delay_time_config);
+    let hold_table = stage_time_table(hold_time_config);
+    let sustain_table = sustain_level_table();
 
     let contents = format!(
         "// Autogenerated by generate_adsr_coefficient_tables
 
 use crate::adsr::BaseAndCoefficient;
+use crate::adsr::curve::CurveShape;
 
-use fixed::types::I1F31;
+use fixed::types::{{I1F31, U4F28}};
 
-// The first element from the fall_base_coefficient table
+// Falls from full scale in ~10 ms, to free a voice whatever its release is
 pub static QUICK_FALL_BASE_COEFFICIENT: BaseAndCoefficient = {};
 
-pub static RISE_BASE_COEFFICIENT_TABLE: [BaseAndCoefficient; {}] = [
+// Samples the attack takes to rise from silence to full scale
+pub static RISE_TIME_TABLE: [u32; {}] = {:?};
+
+// Samples the decay and release take to fall from full scale to silence
+pub static FALL_TIME_TABLE: [u32; {}] = {:?};
+
+// From the RC curve at 0, through a straight line at 128, to the RC curve mirrored at 255
+pub static RISE_CURVE_TABLE: [CurveShape; {}] = [
     {}
 ];
 
-pub static FALL_BASE_COEFFICIENT_TABLE: [BaseAndCoefficient; {}] = [
+pub static FALL_CURVE_TABLE: [CurveShape; {}] = [
     {}
 ];
+
+// Samples spent in the delay stage, where 0 skips it
+pub static DELAY_TIME_TABLE: [u32; {}] = {:?};
+
+// Samples spent in the hold stage, where 0 skips it
+pub static HOLD_TIME_TABLE: [u32; {}] = {:?};
+
+// Sustain level in tenths of a decibel below full scale, where 0 is silent
+pub static SUSTAIN_LEVEL_TABLE: [i16; {}] = {:?};
 ",
         base_coefficient_to_string(&quick_release_base_coefficient),
         TABLE_SIZE,
-        base_coefficient_table_to_string(&attack_table),
+        rise_time_table,
+        TABLE_SIZE,
+        fall_time_table,
+        TABLE_SIZE,
+        curve_table_to_string(&rise_curve_table),
+        TABLE_SIZE,
+        curve_table_to_string(&fall_curve_table),
+        TABLE_SIZE,
+        delay_table,
+        TABLE_SIZE,
+        hold_table,
         TABLE_SIZE,
-        base_coefficient_table_to_string(&decay_release_table)
+        sustain_table
     );
 
     println!("{}", contents);
 
     eprintln!();
-    eprintln!("Sanity check (index, time, final_value):");
-    eprintln!("  Attack envelopes:");
-    for (index, attack_time_expected, attack_time_real, attack_final, _, _, _) in &sanity_check_data
-    {
+    eprintln!("Sanity check (index, curve, time, final_value):");
+    for (name, time_table, curve_table, rising) in [
+        ("Attack", &rise_time_table, &rise_curve_table, true),
+        ("Decay/Release", &fall_time_table, &fall_curve_table, false),
+    ] {
+        eprintln!("  {} envelopes:", name);
+        for index in [0, 1, 127, 128, 254, 255] {
+            for curve in [0, 64, 128, 192, 255] {
+                let samples = time_table[index];
+                let BaseAndCoefficient { base, coefficient } =
+                    get_curve_base_and_coefficient(curve, curve_table[curve], samples, rising);
+                let (initial, target) = if rising {
+                    (I1F31::ZERO, I1F31::MAX)
+                } else {
+                    (I1F31::MAX, I1F31::ZERO)
+                };
+
+                let (iterations, r#final) =
+                    iterate_envelope(base, coefficient, initial, samples as usize * 3 / 2);
+                let expected_time = samples as f64 / SAMPLE_RATE as f64;
+                let time = iterations as f64 / SAMPLE_RATE as f64;
+
+                eprintln!(
+                    "    Index {:3}, curve {:3}: time={:.3}s (target={:.3}s, error={:.3}s), final={:.6} (error={:.6})",
+                    index,
+                    curve,
+                    time,
+                    expected_time,
+                    (expected_time - time).abs(),
+                    r#final,
+                    (target - r#final).abs()
+                );
+            }
+        }
+    }
+    eprintln!("  Delay and hold stages:");
+    for index in [1, 2, 127, 128, TABLE_SIZE - 1] {
         eprintln!(
-            "    Index {:3}: time={:.3}s (target={:.3}s, error={:.3}s), final={:.6} (target=1.0, error={:.6})",
+            "    Index {:3}: delay={:.3}s, hold={:.3}s",
             index,
-            attack_time_real,
-            attack_time_expected,
-            (attack_time_expected - attack_time_real).abs(),
-            attack_final,
-            (I1F31::MAX - attack_final).abs()
+            delay_table[index] as f64 / SAMPLE_RATE as f64,
+            hold_table[index] as f64 / SAMPLE_RATE as f64
         );
     }
-    eprintln!("  Decay/Release envelopes:");
-    for (
-        index,
-        _,
-        _,
-        _,
-        decay_release_time_expected,
-        decay_release_time_real,
-        decay_release_final,
-    ) in &sanity_check_data
-    {
+    eprintln!("  Sustain levels:");
+    for index in [1, 2, 64, 128, TABLE_SIZE - 1] {
         eprintln!(
-            "    Index {:3}: time={:.3}s (target={:.3}s, error={:.3}s), final={:.6} (target=0.0, error={:.6})",
+            "    Index {:3}: level={:.1} db",
             index,
-            decay_release_time_real,
-            decay_release_time_expected,
-            (decay_release_time_expected - decay_release_time_real).abs(),
-            decay_release_final,
-            decay_release_final.abs()
+            sustain_table[index] as f64 / 10.
         );
     }
 }
 
+/// Samples each index of a stage lasts
+fn time_table(time_config: TimeConfig) -> [u32; TABLE_SIZE] {
+    let mut table = [0; TABLE_SIZE];
+
+    for (index, samples) in table.iter_mut().enumerate() {
+        let time = get_time_for_index::<SAMPLE_RATE>(index, time_config);
+        *samples = (time * SAMPLE_RATE as f64).round() as u32;
+    }
+
+    table
+}
+
+/// Samples each index of a delay or hold stage lasts, with index 0 lasting none
+fn stage_time_table(time_config: TimeConfig) -> [u32; TABLE_SIZE] {
+    let mut table = [0; TABLE_SIZE];
+
+    for (index, samples) in table.iter_mut().enumerate().skip(1) {
+        let time = get_time_for_index::<SAMPLE_RATE>(index - 1, time_config);
+        *samples = (time * SAMPLE_RATE as f64).round() as u32;
+    }
+
+    table
+}
+
+/// Tenths of a decibel each sustain index is at, with index 0 being silent
+fn sustain_level_table() -> [i16; TABLE_SIZE] {
+    let mut table = [i16::MIN; TABLE_SIZE];
+
+    for (index, level) in table.iter_mut().enumerate().skip(1) {
+        // The sustain level is the index over the table size of full scale
+        let amplitude = index as f64 / TABLE_SIZE as f64;
+        *level = (200. * amplitude.log10()).round() as i16;
+    }
+
+    table
+}
+
+fn curve_table(target_ratio: f64) -> [CurveShape; TABLE_SIZE] {
+    let mut table = [Default::default(); TABLE_SIZE];
+
+    for (index, shape) in table.iter_mut().enumerate() {
+        *shape = get_curve_shape(index, target_ratio);
+    }
+
+    table
+}
+
 pub fn base_coefficient_to_string(
     BaseAndCoefficient { base, coefficient }: &BaseAndCoefficient,
 ) -> String {
@@ -170,10 +232,18 @@ pub fn base_coefficient_to_string(
     )
 }
 
-pub fn base_coefficient_table_to_string(table: &[BaseAndCoefficient]) -> String {
+pub fn curve_to_string(CurveShape { bend, slow }: &CurveShape) -> String {
+    std::format!(
+        "CurveShape{{ bend: U4F28::from_bits({:#010x}), slow: U4F28::from_bits({:#010x}) }}",
+        fixed::types::U4F28::from_num(*bend).to_bits(),
+        fixed::types::U4F28::from_num(*slow).to_bits()
+    )
+}
+
+pub fn curve_table_to_string(table: &[CurveShape]) -> String {
     table
         .iter()
-        .map(base_coefficient_to_string)
+        .map(curve_to_string)
         .reduce(|acc, s| std::format!("{},\n{}", acc, s))
         .unwrap_or("".to_string())
 }
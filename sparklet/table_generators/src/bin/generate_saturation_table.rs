@@ -0,0 +1,42 @@
+use table_generators::wavetable_utils::{print_wavetable, to_q15};
+
+/// One entry per 1/128 of the input range, plus one so that +1.0 has its own entry
+const TABLE_SIZE: usize = 257;
+
+/// How hard the curve bends. tanh(3) is already 99.5% of the way to the rails.
+const STEEPNESS: f64 = 3.0;
+
+fn main() {
+    eprintln!("Generating saturation shaper table:");
+    eprintln!("  TABLE_SIZE: {}", TABLE_SIZE);
+    eprintln!("  STEEPNESS: {}", STEEPNESS);
+    eprintln!();
+
+    let mut samples = Vec::new();
+
+    for i in 0..TABLE_SIZE {
+        // Input goes from -1.0 to 1.0
+        let input = 2.0 * (i as f64) / ((TABLE_SIZE - 1) as f64) - 1.0;
+
+        // Normalized so that full scale in is full scale out
+        let value = (STEEPNESS * input).tanh() / STEEPNESS.tanh();
+
+        samples.push(to_q15(value));
+    }
+
+    print_wavetable("SATURATION_TABLE", &samples);
+
+    eprintln!();
+    eprintln!("Sanity checks:");
+    eprintln!("  Input -1.0 (i=0): {} (expected: -1.0)", samples[0]);
+    eprintln!(
+        "  Input 0.0 (i={}): {} (expected: 0.0)",
+        TABLE_SIZE / 2,
+        samples[TABLE_SIZE / 2]
+    );
+    eprintln!(
+        "  Input 1.0 (i={}): {} (expected: ~1.0)",
+        TABLE_SIZE - 1,
+        samples[TABLE_SIZE - 1]
+    );
+}
@@ -1,4 +1,4 @@
-use cmsis_interface::Q15;
+use table_generators::wavetable_utils::{print_wavetable, to_q15};
 
 const TABLE_SIZE: usize = 256;
 
@@ -7,10 +7,6 @@ fn main() {
     eprintln!("  WAVETABLE_SIZE: {}", TABLE_SIZE);
     eprintln!();
 
-    println!("use cmsis_interface::Q15;");
-    println!();
-    print!("pub static SAW_WAVETABLE: [Q15; {}] = [", TABLE_SIZE);
-
     let mut samples = Vec::new();
 
     for i in 0..TABLE_SIZE {
@@ -19,20 +15,10 @@ fn main() {
         let value = -1.0 + 2.0 * (i as f64) / (TABLE_SIZE as f64);
 
         // Convert to Q15 fixed-point format (1 sign bit, 15 fractional bits)
-        // Range: [-1.0, 1.0) maps to [-32768, 32767]
-        let fixed_value = (value * 32768.0).round() as i16;
-
-        samples.push(Q15::from_bits(fixed_value));
-
-        println!();
-        print!(
-            "    Q15::from_bits({:#06x}_u16 as i16),",
-            fixed_value as u16
-        );
+        samples.push(to_q15(value));
     }
 
-    println!();
-    println!("];");
+    print_wavetable("SAW_WAVETABLE", &samples);
 
     eprintln!();
     eprintln!("Sanity checks:");
@@ -1,6 +1,6 @@
 use std::f64::consts::PI;
 
-use cmsis_interface::Q15;
+use table_generators::wavetable_utils::{print_wavetable, to_q15};
 
 const TABLE_SIZE: usize = 256;
 
@@ -9,10 +9,6 @@ fn main() {
     eprintln!("  TABLE_SIZE: {}", TABLE_SIZE);
     eprintln!();
 
-    println!("use cmsis_interface::Q15;");
-    println!();
-    print!("pub static SINE_WAVETABLE: [Q15; {}] = [", TABLE_SIZE);
-
     let mut samples = Vec::new();
 
     for i in 0..TABLE_SIZE {
@@ -21,20 +17,10 @@ fn main() {
         let value = phase.sin();
 
         // Convert to Q15 fixed-point format (1 sign bit, 15 fractional bits)
-        // Range: [-1.0, 1.0) maps to [-32768, 32767]
-        let fixed_value = (value * 32768.0).round() as i16;
-
-        samples.push(Q15::from_bits(fixed_value));
-
-        println!();
-        print!(
-            "    Q15::from_bits({:#06x}_u16 as i16),",
-            fixed_value as u16
-        );
+        samples.push(to_q15(value));
     }
 
-    println!();
-    println!("];");
+    print_wavetable("SINE_WAVETABLE", &samples);
 
     eprintln!();
     eprintln!("Sanity checks:");
@@ -1 +1,2 @@
 pub mod adsr_utils;
+pub mod wavetable_utils;
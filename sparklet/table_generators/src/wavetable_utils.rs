@@ -0,0 +1,29 @@
+use cmsis_interface::Q15;
+
+/// Converts a value in [-1.0, 1.0] to Q15, saturating 1.0 to the largest value
+pub fn to_q15(value: f64) -> Q15 {
+    // Range: [-1.0, 1.0) maps to [-32768, 32767]
+    let fixed_value = (value * 32768.0)
+        .round()
+        .clamp(i16::MIN as f64, i16::MAX as f64) as i16;
+
+    Q15::from_bits(fixed_value)
+}
+
+/// Prints `samples` as a Rust source file with a single static wavetable called `name`
+pub fn print_wavetable(name: &str, samples: &[Q15]) {
+    println!("use cmsis_interface::Q15;");
+    println!();
+    print!("pub static {}: [Q15; {}] = [", name, samples.len());
+
+    for sample in samples {
+        println!();
+        print!(
+            "    Q15::from_bits({:#06x}_u16 as i16),",
+            sample.to_bits() as u16
+        );
+    }
+
+    println!();
+    println!("];");
+}